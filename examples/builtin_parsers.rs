@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use inquire::{
+    formatter::{format_list, BYTE_SIZE_FORMATTER, DURATION_FORMATTER},
+    parser::{parse_list, BYTE_SIZE_PARSER, DURATION_PARSER, HOST_PORT_PARSER, VERSION_PARSER},
+    ui::{PreviewPosition, RenderConfig},
+    CustomType,
+};
+
+fn main() {
+    let address = CustomType::new("Where is the server?")
+        .with_parser(HOST_PORT_PARSER)
+        .with_placeholder("example.com:443")
+        .prompt()
+        .unwrap();
+
+    let version = CustomType::new("Which version should be deployed?")
+        .with_parser(VERSION_PARSER)
+        .with_placeholder("1.2.3")
+        .prompt()
+        .unwrap();
+
+    let timeout = CustomType::<Duration> {
+        message: "How long may the deployment take?",
+        default: Some((Duration::from_secs(600), DURATION_FORMATTER)),
+        placeholder: None,
+        help_message: Some("e.g. 1h30m, 90s or 1.5d"),
+        formatter: DURATION_FORMATTER,
+        parser: DURATION_PARSER,
+        error_message: "Invalid duration".into(),
+        validators: vec![],
+        previewer: Some(DURATION_FORMATTER),
+        preview_position: PreviewPosition::Beside,
+        timeout: None,
+        render_config: RenderConfig::default(),
+    }
+    .prompt()
+    .unwrap();
+
+    let memory = CustomType::<u64>::new("How much memory should each instance have?")
+        .with_parser(BYTE_SIZE_PARSER)
+        .with_formatter(BYTE_SIZE_FORMATTER)
+        .with_previewer(BYTE_SIZE_FORMATTER)
+        .with_placeholder("512MiB")
+        .prompt()
+        .unwrap();
+
+    let ports = CustomType::<Vec<u16>> {
+        message: "Which ports should be opened?",
+        default: None,
+        placeholder: Some("80, 443"),
+        help_message: Some("Separate ports with commas"),
+        formatter: &format_list,
+        parser: &parse_list,
+        error_message: "Invalid list of ports".into(),
+        validators: vec![],
+        previewer: None,
+        preview_position: PreviewPosition::Beside,
+        timeout: None,
+        render_config: RenderConfig::default(),
+    }
+    .prompt()
+    .unwrap();
+
+    println!(
+        "Deploying {} to {} with {} of memory, opening {} and timing out after {}",
+        version,
+        address,
+        BYTE_SIZE_FORMATTER(memory),
+        format_list(ports),
+        DURATION_FORMATTER(timeout),
+    );
+}
@@ -1,4 +1,4 @@
-use inquire::{ui::RenderConfig, Confirm};
+use inquire::{parser::ParseError, ui::RenderConfig, Confirm, ConfirmMode};
 
 fn main() {
     let ans = Confirm::new("Do you live in Brazil?")
@@ -18,23 +18,43 @@ fn main() {
     let ans = Confirm {
         message: "Are you happy?",
         default: Some(false),
+        mode: ConfirmMode::Input,
         placeholder: Some("si|no"),
         help_message: Some("It's alright if you're not"),
         formatter: Confirm::DEFAULT_FORMATTER,
         parser: &|ans| match ans {
             "si" => Ok(true),
             "no" => Ok(false),
-            _ => Err(()),
+            _ => Err(ParseError::default()),
         },
         error_message: "Reply with 'si' or 'no'".into(),
         default_value_formatter: &|def| match def {
             true => String::from("si"),
             false => String::from("no"),
         },
+        validators: vec![],
+        timeout: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
     .unwrap();
 
     println!("Your answer: {}", ans);
+
+    let ans = Confirm::new("Delete the temporary files?")
+        .with_mode(ConfirmMode::Instant)
+        .with_default(true)
+        .with_help_message("Press y or n, enter keeps the default")
+        .prompt()
+        .unwrap();
+
+    println!("Your answer: {}", ans);
+
+    let ans = Confirm::new("Enable telemetry?")
+        .with_mode(ConfirmMode::Toggle)
+        .with_help_message("←→ or tab to switch, enter to confirm")
+        .prompt()
+        .unwrap();
+
+    println!("Your answer: {}", ans);
 }
@@ -1,9 +1,14 @@
-use inquire::CustomType;
+use inquire::{validator::Validation, CustomType};
 
 fn main() {
     let amount = CustomType::<f64>::new("How much do you want to donate?")
         .with_formatter(&|i| format!("${:.2}", i))
+        .with_previewer(&|i| format!("${:.2}", i))
         .with_error_message("Please type a valid number")
+        .with_validator(&|amount| match *amount > 0.0 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("The amount must be positive".into())),
+        })
         .with_help_message("Type the amount in US dollars using a decimal point as a separator")
         .prompt();
 
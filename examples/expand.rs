@@ -0,0 +1,29 @@
+use inquire::Expand;
+
+fn main() {
+    let files = vec!["config.toml", "README.md", "Cargo.lock"];
+
+    for file in files {
+        let message = format!("Overwrite {}?", file);
+        let options = vec!["yes", "no", "all", "quit"];
+
+        let ans = Expand::new(&message, options)
+            .with_default(1)
+            .with_help_message("Press ? to list the options")
+            .prompt();
+
+        match ans {
+            Ok("yes") => println!("Overwriting {}", file),
+            Ok("no") => println!("Skipping {}", file),
+            Ok("all") => {
+                println!("Overwriting all remaining files");
+                break;
+            }
+            Ok(_) => break,
+            Err(_) => {
+                println!("There was an error, please try again");
+                break;
+            }
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use chrono::NaiveDate;
-use inquire::{formatter::DEFAULT_DATE_FORMATTER, CustomType};
+use inquire::{formatter::DEFAULT_DATE_FORMATTER, parser::ParseError, CustomType};
 
 fn main() {
     let amount = CustomType::<NaiveDate>::new("When are you going to visit the office?")
         .with_placeholder("dd/mm/yyyy")
-        .with_parser(&|i| NaiveDate::parse_from_str(i, "%d/%m/%Y").map_err(|_| ()))
+        .with_parser(&|i| {
+            NaiveDate::parse_from_str(i, "%d/%m/%Y").map_err(|_| ParseError::default())
+        })
         .with_formatter(DEFAULT_DATE_FORMATTER)
         .with_error_message("Please type a valid date.")
         .with_help_message("The necessary arrangements will be made")
@@ -20,10 +20,6 @@ fn main() {
     ];
 
     let validator: MultiOptionValidator<&str> = &|a| {
-        if a.len() < 2 {
-            return Ok(Validation::Invalid("This list is too small!".into()));
-        }
-
         let x = a.iter().any(|o| *o.value == "Pineapple");
 
         match x {
@@ -35,6 +31,8 @@ fn main() {
     let formatter: MultiOptionFormatter<&str> = &|a| format!("{} different fruits", a.len());
 
     let ans = MultiSelect::new("Select the fruits for your shopping list:", options)
+        .with_min_selections(2)
+        .with_max_selections(5)
         .with_validator(validator)
         .with_formatter(formatter)
         .prompt();
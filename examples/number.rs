@@ -0,0 +1,25 @@
+use inquire::Number;
+
+fn main() {
+    let replicas = Number::<u32>::new("How many replicas should be deployed?")
+        .with_default(3)
+        .with_bounds(1, 20)
+        .with_slider(20)
+        .prompt();
+
+    let canary = Number::<u8>::new("Which share of the traffic should the canary get?")
+        .with_default(10)
+        .with_bounds(0, 100)
+        .with_step(5)
+        .with_page_step(25)
+        .with_formatter(&|share| format!("{}%", share))
+        .prompt();
+
+    match (replicas, canary) {
+        (Ok(replicas), Ok(canary)) => println!(
+            "Deploying {} replicas with {}% of the traffic on the canary",
+            replicas, canary
+        ),
+        _ => println!("There was an error, please try again"),
+    }
+}
@@ -5,6 +5,7 @@ fn main() {
         .with_display_toggle_enabled()
         .with_display_mode(PasswordDisplayMode::Masked)
         .with_validator(min_length!(10))
+        .with_confirmation("Confirm the key:", "The keys do not match")
         .with_formatter(&|_| String::from("Input received"))
         .with_help_message("It is recommended to generate a new one only for this purpose")
         .prompt();
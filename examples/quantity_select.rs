@@ -0,0 +1,21 @@
+use inquire::QuantitySelect;
+
+fn main() {
+    let runners = vec!["ubuntu-latest", "windows-latest", "macos-latest"];
+
+    let ans = QuantitySelect::new("How many jobs should run on each runner?", runners)
+        .with_default(&[(0, 2)])
+        .with_limits(&|runner, _| match *runner {
+            "macos-latest" => 0..=2,
+            _ => 0..=8,
+        })
+        .prompt();
+
+    match ans {
+        Ok(runners) => {
+            let jobs: u32 = runners.iter().map(|(_, count)| count).sum();
+            println!("Scheduling {} jobs", jobs);
+        }
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,17 @@
+use inquire::{validator::Validation, Reorder};
+
+fn main() {
+    let steps = vec!["lint", "build", "test", "package", "deploy"];
+
+    let ans = Reorder::new("In which order should the pipeline run?", steps)
+        .with_validator(&|steps| match steps.last().map(|step| *step.value) {
+            Some("deploy") => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Deploy must be the last step".into())),
+        })
+        .prompt();
+
+    match ans {
+        Ok(steps) => println!("Pipeline: {}", steps.join(" -> ")),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,20 @@
+use inquire::{list_option::SelectOrCreate, validator::Validation, Select};
+
+fn main() {
+    let labels = vec!["bug", "documentation", "enhancement", "good first issue"];
+
+    let ans = Select::new("Which label should be added?", labels)
+        .with_creation_validator(&|label| {
+            Ok(match label.chars().any(char::is_uppercase) {
+                true => Validation::Invalid("Labels must be lowercase".into()),
+                false => Validation::Valid,
+            })
+        })
+        .prompt_or_create();
+
+    match ans {
+        Ok(SelectOrCreate::Existing(label)) => println!("Adding label '{}'", label),
+        Ok(SelectOrCreate::Created(label)) => println!("Creating and adding label '{}'", label),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
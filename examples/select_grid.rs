@@ -0,0 +1,43 @@
+use inquire::{ui::OptionLayout, MultiSelect, Select};
+
+fn main() {
+    let sizes = vec!["XS", "S", "M", "L", "XL", "XXL"];
+
+    let size = Select::new("Which size?", sizes)
+        .with_layout(OptionLayout::Grid)
+        .prompt();
+
+    match size {
+        Ok(size) => println!("Size {} it is", size),
+        Err(_) => println!("There was an error, please try again"),
+    }
+
+    let regions = vec![
+        "us-east-1",
+        "us-east-2",
+        "us-west-1",
+        "us-west-2",
+        "ca-central-1",
+        "eu-west-1",
+        "eu-west-2",
+        "eu-west-3",
+        "eu-central-1",
+        "eu-north-1",
+        "ap-south-1",
+        "ap-northeast-1",
+        "ap-northeast-2",
+        "ap-southeast-1",
+        "ap-southeast-2",
+        "sa-east-1",
+    ];
+
+    let regions = MultiSelect::new("Which regions should be deployed?", regions)
+        .with_layout(OptionLayout::Grid)
+        .with_page_size(4)
+        .prompt();
+
+    match regions {
+        Ok(regions) => println!("Deploying to {} regions", regions.len()),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,39 @@
+use inquire::{list_option::OptionGroup, MultiSelect, Select};
+
+fn main() {
+    let options = vec![
+        "Banana",
+        "Apple",
+        "Strawberry",
+        "Grapes",
+        "Lemon",
+        "Tangerine",
+        "Orange",
+        "Avocado",
+        "Pineapple",
+    ];
+
+    let groups = vec![
+        OptionGroup::new("Everyday", 0..4),
+        OptionGroup::new("Citrus", 4..7),
+        OptionGroup::separator(7..9),
+    ];
+
+    let ans = Select::new("What's your favorite fruit?", options.clone())
+        .with_groups(groups.clone())
+        .prompt();
+
+    match ans {
+        Ok(choice) => println!("{}! That's mine too!", choice),
+        Err(_) => println!("There was an error, please try again"),
+    }
+
+    let ans = MultiSelect::new("Select the fruits for your shopping list:", options)
+        .with_groups(groups)
+        .prompt();
+
+    match ans {
+        Ok(choices) => println!("{} fruits to buy", choices.len()),
+        Err(_) => println!("The shopping list could not be processed"),
+    }
+}
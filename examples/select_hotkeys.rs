@@ -0,0 +1,15 @@
+use inquire::Select;
+
+fn main() {
+    let actions = vec!["yes", "no", "all", "diff", "quit"];
+
+    let ans = Select::new("Stage this hunk?", actions)
+        .with_hotkeys(&|action, _| action.chars().next())
+        .with_help_message("press the highlighted letter of an action to pick it")
+        .prompt();
+
+    match ans {
+        Ok(action) => println!("You picked '{}'", action),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,53 @@
+use inquire::{
+    ui::{Color, Preview, PreviewPosition, Styled},
+    Select,
+};
+
+struct Commit {
+    hash: &'static str,
+    message: &'static str,
+    diff: &'static str,
+}
+
+impl std::fmt::Display for Commit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.hash, self.message)
+    }
+}
+
+fn main() {
+    let commits = vec![
+        Commit {
+            hash: "4f2a1c9",
+            message: "Fix typo in README",
+            diff: "-Inquire is a libary\n+Inquire is a library",
+        },
+        Commit {
+            hash: "8b03de1",
+            message: "Bump version",
+            diff: "-version = \"0.2.0\"\n+version = \"0.2.1\"",
+        },
+    ];
+
+    let ans = Select::new("Which commit do you want to revert?", commits)
+        .with_previewer(&|commit| {
+            let mut preview = Preview::from(format!("commit {}\n", commit.hash));
+
+            for line in commit.diff.lines() {
+                let color = match line.starts_with('+') {
+                    true => Color::DarkGreen,
+                    false => Color::DarkRed,
+                };
+                preview = preview.with_line(vec![Styled::new(line.to_string()).with_fg(color)]);
+            }
+
+            Ok(preview)
+        })
+        .with_preview_position(PreviewPosition::Beside)
+        .prompt();
+
+    match ans {
+        Ok(commit) => println!("Reverting {}", commit.hash),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,45 @@
+use std::{sync::mpsc::channel, thread, time::Duration};
+
+use inquire::{option_source::OptionSource, MultiSelect, Select};
+
+fn main() {
+    let orders = OptionSource::paged(|page| {
+        // simulates a request to an API returning 50 orders per page
+        Ok(match page {
+            0..=999 => (page * 50..(page + 1) * 50)
+                .map(|i| format!("Order #{:05}", i + 1))
+                .collect(),
+            _ => vec![],
+        })
+    });
+
+    let ans = Select::from_source("Which order do you want to refund?", orders).prompt();
+
+    match ans {
+        Ok(order) => println!("Refunding {}", order),
+        Err(_) => println!("There was an error, please try again"),
+    }
+
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        for i in 1..=20 {
+            thread::sleep(Duration::from_millis(150));
+            if sender.send(format!("server-{:02}.internal", i)).is_err() {
+                break;
+            }
+        }
+    });
+
+    let hosts = MultiSelect::from_source(
+        "Which hosts do you want to restart?",
+        OptionSource::channel(receiver),
+    )
+    .with_help_message("Hosts are discovered in the background, press any key to refresh")
+    .prompt();
+
+    match hosts {
+        Ok(hosts) => println!("Restarting {} hosts", hosts.len()),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -19,6 +19,8 @@ fn main() {
         validators: Vec::new(),
         page_size: Text::DEFAULT_PAGE_SIZE,
         suggester: None,
+        token_suggester: None,
+        timeout: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
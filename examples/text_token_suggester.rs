@@ -0,0 +1,37 @@
+use inquire::{
+    autocompletion::{current_token, TokenSuggestion},
+    error::CustomUserError,
+    Text,
+};
+
+fn main() {
+    let command = Text::new("Command:")
+        .with_placeholder("deploy --env prod --region us-east-1")
+        .with_token_suggester(&suggester)
+        .prompt();
+
+    match command {
+        Ok(command) => println!("Running `{}`", command),
+        Err(_) => println!("The command could not be processed"),
+    }
+}
+
+fn suggester(input: &str, cursor: usize) -> Result<Vec<TokenSuggestion>, CustomUserError> {
+    let range = current_token(input, cursor);
+    let token = &input[range.start..cursor];
+
+    let previous = input[..range.start].split_whitespace().last();
+
+    let candidates: &[&str] = match previous {
+        None => &["deploy", "rollback", "status"],
+        Some("--env") => &["dev", "staging", "prod"],
+        Some("--region") => &["us-east-1", "us-west-2", "eu-west-1", "sa-east-1"],
+        Some(_) => &["--env", "--region", "--dry-run"],
+    };
+
+    Ok(candidates
+        .iter()
+        .filter(|c| c.starts_with(token))
+        .map(|c| TokenSuggestion::new(*c, range.clone()))
+        .collect())
+}
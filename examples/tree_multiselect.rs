@@ -0,0 +1,30 @@
+use inquire::{tree::TreeNode, TreeMultiSelect};
+
+fn main() {
+    let flags = vec![
+        TreeNode::new("editor").with_children(vec![
+            TreeNode::new("editor.autosave"),
+            TreeNode::new("editor.minimap"),
+            TreeNode::new("editor.suggestions").with_children(vec![
+                TreeNode::new("editor.suggestions.inline"),
+                TreeNode::new("editor.suggestions.snippets"),
+            ]),
+        ]),
+        TreeNode::new("terminal").with_children(vec![
+            TreeNode::new("terminal.ligatures"),
+            TreeNode::new("terminal.gpu_acceleration"),
+        ]),
+        TreeNode::new("telemetry"),
+    ];
+
+    let ans = TreeMultiSelect::new("Which feature flags do you want to enable?", flags)
+        .with_default(&[3])
+        .with_starting_expanded(true)
+        .with_covering_nodes(true)
+        .prompt();
+
+    match ans {
+        Ok(flags) => println!("Enabling {}", flags.join(", ")),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
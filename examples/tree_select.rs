@@ -0,0 +1,29 @@
+use inquire::{tree::TreeNode, TreeSelect};
+
+fn main() {
+    let tree = vec![
+        TreeNode::new("Fruits").with_children(vec![
+            TreeNode::new("Citrus").with_children(vec![
+                TreeNode::new("Lemon"),
+                TreeNode::new("Orange"),
+                TreeNode::new("Tangerine"),
+            ]),
+            TreeNode::new("Berries").with_children(vec![
+                TreeNode::new("Strawberry"),
+                TreeNode::new("Blueberry"),
+            ]),
+            TreeNode::new("Banana"),
+        ]),
+        TreeNode::new("Vegetables")
+            .with_children(vec![TreeNode::new("Carrot"), TreeNode::new("Lettuce")]),
+    ];
+
+    let ans = TreeSelect::new("What do you want to buy?", tree)
+        .with_branch_selection(true)
+        .prompt();
+
+    match ans {
+        Ok(path) => println!("Adding {} to the cart", path.join(" > ")),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
@@ -0,0 +1,185 @@
+//! Definitions used by auto-completion features of [Text](crate::Text) prompts.
+//!
+//! Besides the simple [`Suggester`](crate::type_aliases::Suggester), which
+//! replaces the whole text input with the selected suggestion, `Text` prompts
+//! also accept a [`TokenSuggester`](crate::type_aliases::TokenSuggester).
+//!
+//! Token suggesters receive the full text input and the cursor position, and
+//! return [`TokenSuggestion`]s, each one containing the byte range of the input
+//! it should replace. When a suggestion is accepted, only that range is
+//! replaced, keeping the rest of the line intact.
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::autocompletion::{current_token, TokenSuggestion};
+//! use inquire::type_aliases::TokenSuggester;
+//!
+//! let suggester: TokenSuggester = &|input, cursor| {
+//!     let range = current_token(input, cursor);
+//!     let token = &input[range.clone()];
+//!
+//!     Ok(["--env", "--region"]
+//!         .iter()
+//!         .filter(|flag| flag.starts_with(token))
+//!         .map(|flag| TokenSuggestion::new(*flag, range.clone()))
+//!         .collect())
+//! };
+//!
+//! let suggestions = suggester("deploy --re", 11)?;
+//! assert_eq!(vec![TokenSuggestion::new("--region", 7..11)], suggestions);
+//! # Ok::<(), inquire::error::CustomUserError>(())
+//! ```
+
+use std::ops::Range;
+
+/// Suggestion returned by a [`TokenSuggester`](crate::type_aliases::TokenSuggester),
+/// containing the value to be inserted and the byte range of the current input
+/// that it replaces.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenSuggestion {
+    /// Value displayed to the user and inserted in the input when the suggestion
+    /// is accepted.
+    pub value: String,
+
+    /// Byte range of the current input replaced by the suggestion.
+    ///
+    /// Ranges that are out-of-bounds or that do not lie on `char` boundaries
+    /// are ignored when the suggestion is accepted.
+    pub range: Range<usize>,
+}
+
+impl TokenSuggestion {
+    /// Constructor for `TokenSuggestion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to be inserted in the input.
+    /// * `range` - Byte range of the input replaced by the value.
+    pub fn new<T>(value: T, range: Range<usize>) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            value: value.into(),
+            range,
+        }
+    }
+
+    /// Returns the given input with the suggestion's range replaced by its value,
+    /// along with the byte index right after the inserted value.
+    ///
+    /// Returns `None` if the range is not valid for the given input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::autocompletion::TokenSuggestion;
+    ///
+    /// let suggestion = TokenSuggestion::new("prod", 13..15);
+    /// let (content, cursor) = suggestion.apply("deploy --env pr --region").unwrap();
+    ///
+    /// assert_eq!("deploy --env prod --region", content);
+    /// assert_eq!(17, cursor);
+    /// ```
+    pub fn apply(&self, input: &str) -> Option<(String, usize)> {
+        let Range { start, end } = self.range;
+
+        if start > end
+            || end > input.len()
+            || !input.is_char_boundary(start)
+            || !input.is_char_boundary(end)
+        {
+            return None;
+        }
+
+        let mut content = String::with_capacity(input.len() + self.value.len());
+        content.push_str(&input[..start]);
+        content.push_str(&self.value);
+        content.push_str(&input[end..]);
+
+        Some((content, start + self.value.len()))
+    }
+}
+
+/// Returns the byte range of the whitespace-delimited token that ends at,
+/// or contains, the given cursor position.
+///
+/// The range starts at the beginning of the token and ends at the first
+/// whitespace character after the cursor, or at the end of the input.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::autocompletion::current_token;
+///
+/// assert_eq!(7..12, current_token("deploy --env prod", 12));
+/// assert_eq!(7..12, current_token("deploy --env prod", 9));
+/// assert_eq!(13..17, current_token("deploy --env prod", 17));
+/// assert_eq!(13..13, current_token("deploy --env ", 13));
+/// ```
+pub fn current_token(input: &str, cursor: usize) -> Range<usize> {
+    let mut cursor = cursor.min(input.len());
+    while !input.is_char_boundary(cursor) {
+        cursor -= 1;
+    }
+
+    let start = input[..cursor]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map(|(idx, c)| idx + c.len_utf8())
+        .unwrap_or(0);
+
+    let end = input[cursor..]
+        .char_indices()
+        .find(|(_, c)| c.is_whitespace())
+        .map(|(idx, _)| cursor + idx)
+        .unwrap_or_else(|| input.len());
+
+    start..end
+}
+
+#[cfg(test)]
+mod test {
+    use super::{current_token, TokenSuggestion};
+
+    #[test]
+    fn current_token_at_boundaries() {
+        assert_eq!(0..0, current_token("", 0));
+        assert_eq!(0..6, current_token("deploy", 0));
+        assert_eq!(0..6, current_token("deploy", 6));
+        assert_eq!(0..6, current_token("deploy --env", 6));
+        assert_eq!(7..12, current_token("deploy --env", 7));
+        assert_eq!(7..12, current_token("deploy --env", 100));
+    }
+
+    #[test]
+    fn current_token_handles_multibyte_chars() {
+        let input = "café --régión";
+
+        assert_eq!(0..5, current_token(input, 5));
+        assert_eq!(0..5, current_token(input, 4));
+        assert_eq!(6..16, current_token(input, 10));
+    }
+
+    #[test]
+    fn apply_replaces_only_the_range() {
+        let suggestion = TokenSuggestion::new("--region", 7..11);
+
+        assert_eq!(
+            Some((String::from("deploy --region eu"), 15)),
+            suggestion.apply("deploy --re eu")
+        );
+    }
+
+    #[test]
+    fn apply_rejects_invalid_ranges() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 3..2;
+
+        assert_eq!(None, TokenSuggestion::new("x", reversed).apply("abcd"));
+        assert_eq!(None, TokenSuggestion::new("x", 2..5).apply("abcd"));
+        assert_eq!(None, TokenSuggestion::new("x", 1..2).apply("éa"));
+    }
+}
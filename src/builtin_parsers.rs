@@ -0,0 +1,1232 @@
+//! Built-in parsers and formatters for common types, re-exported by the
+//! [parser](crate::parser) and [formatter](crate::formatter) modules when
+//! the `builtin_parsers` feature is enabled.
+//!
+//! Error positions are counted in characters, as expected by [`ParseError`].
+
+use std::{
+    fmt::{self, Display},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    formatter::CustomTypeFormatter,
+    parser::{CustomTypeParser, ParseError},
+};
+
+/// Units accepted by [`DURATION_PARSER`], along with their length in nanoseconds.
+const DURATION_UNITS: &[(&str, f64)] = &[
+    ("ms", 1e6),
+    ("s", 1e9),
+    ("m", 6e10),
+    ("h", 3.6e12),
+    ("d", 8.64e13),
+];
+
+/// Units accepted by [`BYTE_SIZE_PARSER`], along with their size in bytes.
+///
+/// `KB` is accepted as an alias of `kB`, but not suggested in error messages.
+const BYTE_UNITS: &[(&str, u64)] = &[
+    ("B", 1),
+    ("kB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("PB", 1_000_000_000_000_000),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+    ("PiB", 1 << 50),
+    ("KB", 1_000),
+];
+
+/// Parser of human durations, such as `1h30m`, `90s` or `1.5d`.
+///
+/// Durations are composed of one or more numbers followed by a unit, `ms`,
+/// `s`, `m`, `h` or `d`, optionally separated by spaces. Units written with
+/// the wrong case are reported with a suggestion, e.g. "unknown unit 'H',
+/// did you mean 'h'?".
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::parser::{ParseError, DURATION_PARSER};
+///
+/// assert_eq!(Ok(Duration::from_secs(5400)), DURATION_PARSER("1h30m"));
+/// assert_eq!(Ok(Duration::from_millis(1500)), DURATION_PARSER("1.5s"));
+/// assert_eq!(
+///     Err(ParseError::new("unknown unit 'H', did you mean 'h'?").with_position(1)),
+///     DURATION_PARSER("2H")
+/// );
+/// ```
+pub const DURATION_PARSER: CustomTypeParser<Duration> = &|input| parse_duration(input);
+
+/// Formatter of durations in words, such as `2 hours 30 minutes`, matching
+/// [`DURATION_PARSER`]. Durations are truncated to milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::formatter::DURATION_FORMATTER;
+///
+/// assert_eq!("2 hours 30 minutes", DURATION_FORMATTER(Duration::from_secs(9000)));
+/// assert_eq!("1 second 500 milliseconds", DURATION_FORMATTER(Duration::from_millis(1500)));
+/// assert_eq!("0 seconds", DURATION_FORMATTER(Duration::from_secs(0)));
+/// ```
+pub const DURATION_FORMATTER: CustomTypeFormatter<Duration> = &|val| {
+    let parts: &[(u128, &str)] = &[
+        (86_400_000, "day"),
+        (3_600_000, "hour"),
+        (60_000, "minute"),
+        (1_000, "second"),
+        (1, "millisecond"),
+    ];
+
+    let mut rest = val.as_millis();
+    let mut words = vec![];
+
+    for &(size, name) in parts {
+        let count = rest / size;
+        rest %= size;
+
+        match count {
+            0 => {}
+            1 => words.push(format!("1 {}", name)),
+            _ => words.push(format!("{} {}s", count, name)),
+        }
+    }
+
+    match words.is_empty() {
+        true => String::from("0 seconds"),
+        false => words.join(" "),
+    }
+};
+
+/// Parser of byte sizes, such as `512`, `10MB` or `1.5GiB`, returning the
+/// number of bytes.
+///
+/// Sizes are composed of a number optionally followed by a unit, `B` by
+/// default. Decimal units (`kB`, `MB`, `GB`, `TB` and `PB`) are powers of
+/// 1000, while binary ones (`KiB`, `MiB`, `GiB`, `TiB` and `PiB`) are powers
+/// of 1024. Fractional sizes are rounded to the nearest byte.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{ParseError, BYTE_SIZE_PARSER};
+///
+/// assert_eq!(Ok(1_610_612_736), BYTE_SIZE_PARSER("1.5GiB"));
+/// assert_eq!(Ok(10_000_000), BYTE_SIZE_PARSER("10 MB"));
+/// assert_eq!(
+///     Err(ParseError::new("unknown unit 'kb', did you mean 'kB'?").with_position(2)),
+///     BYTE_SIZE_PARSER("10kb")
+/// );
+/// ```
+pub const BYTE_SIZE_PARSER: CustomTypeParser<u64> = &|input| parse_byte_size(input);
+
+/// Formatter of byte sizes with binary units, such as `1.5 GiB`, matching
+/// [`BYTE_SIZE_PARSER`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::BYTE_SIZE_FORMATTER;
+///
+/// assert_eq!("512 B", BYTE_SIZE_FORMATTER(512));
+/// assert_eq!("1.5 GiB", BYTE_SIZE_FORMATTER(1_610_612_736));
+/// ```
+pub const BYTE_SIZE_FORMATTER: CustomTypeFormatter<u64> = &|val| {
+    let units = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    let mut value = val as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} B", val),
+        _ => format!("{} {}", format_decimal(value), units[unit]),
+    }
+};
+
+/// Parser of percentages, such as `42%`, `12.5 %` or `42`, returning the
+/// percentage as a fraction, e.g. `0.42`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::PERCENTAGE_PARSER;
+///
+/// assert_eq!(Ok(0.42), PERCENTAGE_PARSER("42%"));
+/// assert_eq!(Ok(0.125), PERCENTAGE_PARSER("12.5"));
+/// assert!(PERCENTAGE_PARSER("half").is_err());
+/// ```
+pub const PERCENTAGE_PARSER: CustomTypeParser<f64> = &|input| {
+    let body = input.trim();
+    let number = body.strip_suffix('%').unwrap_or(body).trim_end();
+
+    if number.is_empty() {
+        return Err(ParseError::new("expected a percentage, such as 42%"));
+    }
+
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value / 100.0),
+        _ => Err(ParseError::new(format!(
+            "'{}' is not a valid percentage, such as 42%",
+            body
+        ))),
+    }
+};
+
+/// Formatter of fractions as percentages, such as `12.5%`, matching
+/// [`PERCENTAGE_PARSER`].
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::PERCENTAGE_FORMATTER;
+///
+/// assert_eq!("42%", PERCENTAGE_FORMATTER(0.42));
+/// assert_eq!("12.5%", PERCENTAGE_FORMATTER(0.125));
+/// ```
+pub const PERCENTAGE_FORMATTER: CustomTypeFormatter<f64> =
+    &|val| format!("{}%", format_decimal(val * 100.0));
+
+/// Parser of IPv4 addresses, such as `192.168.0.1`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use inquire::parser::{ParseError, IPV4_PARSER};
+///
+/// assert_eq!(Ok(Ipv4Addr::new(192, 168, 0, 1)), IPV4_PARSER("192.168.0.1"));
+/// assert_eq!(
+///     Err(ParseError::new("'256' is not a number between 0 and 255").with_position(8)),
+///     IPV4_PARSER("192.168.256.1")
+/// );
+/// ```
+pub const IPV4_PARSER: CustomTypeParser<Ipv4Addr> = &|input| {
+    let (body, offset) = trim(input);
+    parse_ipv4(body, offset)
+};
+
+/// Parser of IPv6 addresses, such as `2001:db8::1`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv6Addr;
+/// use inquire::parser::IPV6_PARSER;
+///
+/// assert_eq!(Ok(Ipv6Addr::LOCALHOST), IPV6_PARSER("::1"));
+/// assert!(IPV6_PARSER("2001:db8::g").is_err());
+/// ```
+pub const IPV6_PARSER: CustomTypeParser<Ipv6Addr> = &|input| {
+    let (body, offset) = trim(input);
+    parse_ipv6(body, offset)
+};
+
+/// Parser of IP addresses, either IPv4 or IPv6.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// use inquire::parser::IP_ADDR_PARSER;
+///
+/// assert_eq!(Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)), IP_ADDR_PARSER("127.0.0.1"));
+/// assert_eq!(Ok(IpAddr::V6(Ipv6Addr::LOCALHOST)), IP_ADDR_PARSER("::1"));
+/// ```
+pub const IP_ADDR_PARSER: CustomTypeParser<IpAddr> = &|input| {
+    let (body, offset) = trim(input);
+    parse_ip(body, offset)
+};
+
+/// Parser of blocks of IP addresses in the CIDR notation, such as `10.0.0.0/8`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{Cidr, CIDR_PARSER};
+///
+/// let block = CIDR_PARSER("10.0.0.0/8").unwrap();
+/// assert_eq!(8, block.prefix_len);
+/// assert_eq!("10.0.0.0/8", block.to_string());
+/// assert!(CIDR_PARSER("10.0.0.0/33").is_err());
+/// ```
+pub const CIDR_PARSER: CustomTypeParser<Cidr> = &|input| input.parse();
+
+/// Parser of hosts followed by a port, such as `example.com:443` or `[::1]:8080`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{HostPort, HOST_PORT_PARSER};
+///
+/// let address = HOST_PORT_PARSER("example.com:443").unwrap();
+/// assert_eq!("example.com", address.host);
+/// assert_eq!(443, address.port);
+/// assert!(HOST_PORT_PARSER("example.com").is_err());
+/// ```
+pub const HOST_PORT_PARSER: CustomTypeParser<HostPort> = &|input| input.parse();
+
+/// Parser of semantic versions, such as `1.2.3` or `v2.0.0-rc.1`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{Version, VERSION_PARSER};
+///
+/// let version = VERSION_PARSER("v2.0.0-rc.1").unwrap();
+/// assert_eq!(2, version.major);
+/// assert_eq!(Some(String::from("rc.1")), version.pre);
+/// assert!(VERSION_PARSER("1.2").is_err());
+/// ```
+pub const VERSION_PARSER: CustomTypeParser<Version> = &|input| input.parse();
+
+/// Parser of comma-separated lists of any type implementing `FromStr`, such
+/// as `80, 443, 8080`. Spaces around items are ignored and an empty input is
+/// parsed as an empty list.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{parse_list, ParseError};
+///
+/// assert_eq!(Ok(vec![80, 443]), parse_list::<u16>("80, 443"));
+/// assert_eq!(
+///     Err(ParseError::new("'http' is not a valid item").with_position(4)),
+///     parse_list::<u16>("80, http")
+/// );
+/// ```
+///
+/// Since `Vec<T>` does not implement `FromStr`, [`CustomType`](crate::CustomType)
+/// prompts of lists are instantiated manually:
+///
+/// ```no_run
+/// use inquire::{
+///     formatter::format_list,
+///     parser::parse_list,
+///     ui::{PreviewPosition, RenderConfig},
+///     CustomType,
+/// };
+///
+/// let ports = CustomType::<Vec<u16>> {
+///     message: "Which ports should be opened?",
+///     default: None,
+///     placeholder: Some("80, 443"),
+///     help_message: None,
+///     formatter: &format_list,
+///     parser: &parse_list,
+///     error_message: "Invalid list of ports".into(),
+///     validators: vec![],
+///     previewer: None,
+///     preview_position: PreviewPosition::Beside,
+///     timeout: None,
+///     render_config: RenderConfig::default(),
+/// }
+/// .prompt();
+/// ```
+pub fn parse_list<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    if input.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut values = vec![];
+    let mut start = 0;
+
+    for item in input.split(',') {
+        let value = item.trim();
+        let value_start = start + item.len() - item.trim_start().len();
+
+        if value.is_empty() {
+            return Err(ParseError::new("empty item in the list")
+                .with_position(position(input, value_start)));
+        }
+
+        match value.parse::<T>() {
+            Ok(value) => values.push(value),
+            Err(_) => {
+                return Err(ParseError::new(format!("'{}' is not a valid item", value))
+                    .with_position(position(input, value_start)))
+            }
+        }
+
+        start += item.len() + 1;
+    }
+
+    Ok(values)
+}
+
+/// Formatter of lists as comma-separated values, such as `80, 443, 8080`,
+/// matching [`parse_list`](crate::parser::parse_list).
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::format_list;
+///
+/// assert_eq!("80, 443", format_list(vec![80, 443]));
+/// ```
+pub fn format_list<T: Display>(values: Vec<T>) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Block of IP addresses in the CIDR notation, such as `10.0.0.0/8`, parsed
+/// by [`CIDR_PARSER`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cidr {
+    /// Address of the block.
+    pub address: IpAddr,
+
+    /// Number of leading bits of the address shared by the block.
+    pub prefix_len: u8,
+}
+
+impl Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (body, offset) = trim(input);
+
+        let slash = body.find('/').ok_or_else(|| {
+            ParseError::new("expected an address and a prefix length, such as 10.0.0.0/8")
+                .with_position(offset + body.chars().count())
+        })?;
+
+        let address = parse_ip(&body[..slash], offset)?;
+        let max = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        let prefix = &body[slash + 1..];
+        let prefix_len = parse_digits(prefix)
+            .filter(|len| *len <= max)
+            .ok_or_else(|| {
+                ParseError::new(format!(
+                    "prefix length '{}' is not a number between 0 and {}",
+                    prefix, max
+                ))
+                .with_position(offset + position(body, slash + 1))
+            })?;
+
+        Ok(Self {
+            address,
+            prefix_len: prefix_len as u8,
+        })
+    }
+}
+
+/// Host followed by a port, such as `example.com:443` or `[::1]:8080`, parsed
+/// by [`HOST_PORT_PARSER`].
+///
+/// Hosts are either IP addresses or names composed of letters, digits, dots
+/// and hyphens. IPv6 addresses are enclosed in brackets.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HostPort {
+    /// Host name or IP address, without brackets.
+    pub host: String,
+
+    /// Port number, between 1 and 65535.
+    pub port: u16,
+}
+
+impl Display for HostPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.host.contains(':') {
+            true => write!(f, "[{}]:{}", self.host, self.port),
+            false => write!(f, "{}:{}", self.host, self.port),
+        }
+    }
+}
+
+impl FromStr for HostPort {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (body, offset) = trim(input);
+
+        let (host, port_start) = match body.strip_prefix('[') {
+            Some(bracketed) => {
+                let end = bracketed.find(']').ok_or_else(|| {
+                    ParseError::new("missing ']' after the IPv6 address")
+                        .with_position(offset + body.chars().count())
+                })?;
+                let host = &bracketed[..end];
+
+                parse_ipv6(host, offset + 1)?;
+
+                (host, end + 2)
+            }
+            None => {
+                let colon = body.rfind(':').unwrap_or(body.len());
+                let host = &body[..colon];
+
+                if host.contains(':') {
+                    return Err(ParseError::new(
+                        "IPv6 addresses must be enclosed in brackets, such as [::1]:8080",
+                    )
+                    .with_position(offset));
+                }
+
+                validate_host_name(host, offset)?;
+
+                (host, colon)
+            }
+        };
+
+        let port = match body[port_start..].strip_prefix(':') {
+            Some(port) => port,
+            None => {
+                return Err(ParseError::new(format!(
+                    "expected a port after the host, such as {}:443",
+                    host
+                ))
+                .with_position(offset + body.chars().count()))
+            }
+        };
+
+        let port = parse_digits(port)
+            .filter(|port| (1..=65535).contains(port))
+            .ok_or_else(|| {
+                ParseError::new(format!(
+                    "port '{}' is not a number between 1 and 65535",
+                    port
+                ))
+                .with_position(offset + position(body, port_start + 1))
+            })?;
+
+        Ok(Self {
+            host: host.to_string(),
+            port: port as u16,
+        })
+    }
+}
+
+/// Semantic version, such as `1.2.3` or `2.0.0-rc.1+build.5`, parsed by
+/// [`VERSION_PARSER`].
+///
+/// A leading `v`, as in `v1.2.3`, is accepted when parsing and dropped.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Version {
+    /// Major version number.
+    pub major: u64,
+
+    /// Minor version number.
+    pub minor: u64,
+
+    /// Patch version number.
+    pub patch: u64,
+
+    /// Pre-release identifiers separated by dots, such as `rc.1`.
+    pub pre: Option<String>,
+
+    /// Build metadata identifiers separated by dots, such as `build.5`.
+    pub build: Option<String>,
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "+{}", build)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (body, offset) = trim(input);
+        let (body, offset) = match body.strip_prefix('v') {
+            Some(body) => (body, offset + 1),
+            None => (body, offset),
+        };
+
+        let (rest, build) = match body.find('+') {
+            Some(plus) => (&body[..plus], Some(plus + 1)),
+            None => (body, None),
+        };
+        let (core, pre) = match rest.find('-') {
+            Some(dash) => (&rest[..dash], Some(dash + 1)),
+            None => (rest, None),
+        };
+
+        let numbers: Vec<&str> = core.split('.').collect();
+        if numbers.len() != 3 {
+            return Err(
+                ParseError::new("expected major, minor and patch numbers, such as 1.2.3")
+                    .with_position(offset + core.chars().count()),
+            );
+        }
+
+        let mut parsed = [0u64; 3];
+        let mut start = 0;
+
+        for (i, (number, name)) in numbers.iter().zip(&["major", "minor", "patch"]).enumerate() {
+            let error = |message: String| {
+                ParseError::new(message).with_position(offset + position(body, start))
+            };
+
+            if number.len() > 1 && number.starts_with('0') {
+                return Err(error(format!(
+                    "{} version '{}' must not have leading zeros",
+                    name, number
+                )));
+            }
+
+            parsed[i] = parse_digits(number)
+                .ok_or_else(|| error(format!("'{}' is not a valid {} version", number, name)))?;
+            start += number.len() + 1;
+        }
+
+        let pre = match pre {
+            Some(start) => {
+                let pre = &rest[start..];
+                validate_identifiers(pre, "pre-release", true, offset + position(body, start))?;
+                Some(pre.to_string())
+            }
+            None => None,
+        };
+        let build = match build {
+            Some(start) => {
+                let build = &body[start..];
+                validate_identifiers(build, "build", false, offset + position(body, start))?;
+                Some(build.to_string())
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            major: parsed[0],
+            minor: parsed[1],
+            patch: parsed[2],
+            pre,
+            build,
+        })
+    }
+}
+
+/// Converts a byte index of the input into a position counted in characters.
+fn position(input: &str, byte_index: usize) -> usize {
+    input[..byte_index].chars().count()
+}
+
+/// Trims the input, returning it along with the position where it starts.
+fn trim(input: &str) -> (&str, usize) {
+    let body = input.trim();
+    let start = input.len() - input.trim_start().len();
+
+    (body, position(input, start))
+}
+
+/// Splits the leading number, composed of digits and dots, from the input.
+fn split_number(input: &str) -> (&str, &str) {
+    let end = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+
+    input.split_at(end)
+}
+
+/// Parses a non-empty sequence of ASCII digits, rejecting signs and spaces
+/// accepted by `str::parse`.
+fn parse_digits(input: &str) -> Option<u64> {
+    match !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        true => input.parse().ok(),
+        false => None,
+    }
+}
+
+/// Formats a number with up to two decimal places, without trailing zeros.
+fn format_decimal(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Error for a unit that is not accepted, suggesting the unit with the same
+/// letters in a different case, if any.
+fn unknown_unit(unit: &str, units: &[&str], position: usize) -> ParseError {
+    let message = match units.iter().find(|u| u.eq_ignore_ascii_case(unit)) {
+        Some(suggestion) => format!("unknown unit '{}', did you mean '{}'?", unit, suggestion),
+        None => format!(
+            "unknown unit '{}', expected one of {}",
+            unit,
+            units.join(", ")
+        ),
+    };
+
+    ParseError::new(message).with_position(position)
+}
+
+fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    let units: Vec<&str> = DURATION_UNITS.iter().map(|(unit, _)| *unit).collect();
+
+    let mut nanos = 0f64;
+    let mut offset = 0;
+
+    loop {
+        offset = input.len() - input[offset..].trim_start().len();
+        let rest = &input[offset..];
+
+        if rest.is_empty() {
+            break;
+        }
+
+        let (number, tail) = split_number(rest);
+        let value = number.parse::<f64>().map_err(|_| {
+            let message = match number {
+                "" => String::from("expected a number, such as 1h30m"),
+                number => format!("'{}' is not a valid number", number),
+            };
+            ParseError::new(message).with_position(position(input, offset))
+        })?;
+
+        let unit_start = input.len() - tail.trim_start().len();
+        let unit_tail = &input[unit_start..];
+        let unit_len = unit_tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(unit_tail.len());
+        let unit = &unit_tail[..unit_len];
+
+        if unit.is_empty() {
+            return Err(ParseError::new(format!(
+                "missing unit after '{}', such as {}m",
+                number, number
+            ))
+            .with_position(position(input, unit_start)));
+        }
+
+        let factor = DURATION_UNITS
+            .iter()
+            .find(|(name, _)| *name == unit)
+            .map(|(_, factor)| *factor)
+            .ok_or_else(|| unknown_unit(unit, &units, position(input, unit_start)))?;
+
+        nanos += value * factor;
+        offset = unit_start + unit_len;
+    }
+
+    if offset == 0 || input.trim().is_empty() {
+        return Err(ParseError::new("expected a duration, such as 1h30m"));
+    }
+
+    let nanos = nanos.round();
+    if nanos >= u64::MAX as f64 * 1e9 {
+        return Err(ParseError::new("the duration is too long"));
+    }
+
+    let secs = (nanos / 1e9).floor();
+    Ok(Duration::new(secs as u64, (nanos - secs * 1e9) as u32))
+}
+
+fn parse_byte_size(input: &str) -> Result<u64, ParseError> {
+    let (body, offset) = trim(input);
+
+    if body.is_empty() {
+        return Err(ParseError::new("expected a byte size, such as 1.5GiB"));
+    }
+
+    let (number, tail) = split_number(body);
+    if number.is_empty() {
+        return Err(ParseError::new("expected a number, such as 1.5GiB").with_position(offset));
+    }
+
+    let unit = tail.trim_start();
+    let unit_position = offset + position(body, body.len() - unit.len());
+
+    let multiplier = match unit {
+        "" => 1,
+        unit => {
+            let units: Vec<&str> = BYTE_UNITS[..BYTE_UNITS.len() - 1]
+                .iter()
+                .map(|(unit, _)| *unit)
+                .collect();
+
+            BYTE_UNITS
+                .iter()
+                .find(|(name, _)| *name == unit)
+                .map(|(_, multiplier)| *multiplier)
+                .ok_or_else(|| unknown_unit(unit, &units, unit_position))?
+        }
+    };
+
+    let too_large = || ParseError::new("the byte size is too large");
+
+    match parse_digits(number) {
+        Some(value) => value.checked_mul(multiplier).ok_or_else(too_large),
+        None if number.contains('.') => {
+            let value = number.parse::<f64>().map_err(|_| {
+                ParseError::new(format!("'{}' is not a valid number", number)).with_position(offset)
+            })?;
+            let bytes = (value * multiplier as f64).round();
+
+            match bytes < u64::MAX as f64 {
+                true => Ok(bytes as u64),
+                false => Err(too_large()),
+            }
+        }
+        None => Err(too_large()),
+    }
+}
+
+fn parse_ip(input: &str, offset: usize) -> Result<IpAddr, ParseError> {
+    match input.contains(':') {
+        true => parse_ipv6(input, offset).map(IpAddr::V6),
+        false => parse_ipv4(input, offset).map(IpAddr::V4),
+    }
+}
+
+fn parse_ipv4(input: &str, offset: usize) -> Result<Ipv4Addr, ParseError> {
+    let octets: Vec<&str> = input.split('.').collect();
+
+    if octets.len() != 4 {
+        return Err(
+            ParseError::new("expected 4 numbers separated by dots, such as 192.168.0.1")
+                .with_position(offset + input.chars().count()),
+        );
+    }
+
+    let mut parsed = [0u8; 4];
+    let mut start = 0;
+
+    for (i, octet) in octets.iter().enumerate() {
+        let error = |message: String| {
+            ParseError::new(message).with_position(offset + position(input, start))
+        };
+
+        if octet.len() > 1 && octet.starts_with('0') {
+            return Err(error(format!("'{}' must not have leading zeros", octet)));
+        }
+
+        parsed[i] = parse_digits(octet)
+            .filter(|value| *value <= 255)
+            .ok_or_else(|| error(format!("'{}' is not a number between 0 and 255", octet)))?
+            as u8;
+        start += octet.len() + 1;
+    }
+
+    Ok(Ipv4Addr::from(parsed))
+}
+
+fn parse_ipv6(input: &str, offset: usize) -> Result<Ipv6Addr, ParseError> {
+    if let Some(invalid) = input.find(|c: char| !(c.is_ascii_hexdigit() || c == ':' || c == '.')) {
+        let c = input[invalid..].chars().next().unwrap_or_default();
+
+        return Err(
+            ParseError::new(format!("invalid character '{}' in IPv6 address", c))
+                .with_position(offset + position(input, invalid)),
+        );
+    }
+
+    input.parse::<Ipv6Addr>().map_err(|_| {
+        let message = match input.contains(':') {
+            true => format!(
+                "'{}' is not a valid IPv6 address, such as 2001:db8::1",
+                input
+            ),
+            false => format!("'{}' is not an IPv6 address, such as 2001:db8::1", input),
+        };
+
+        ParseError::new(message).with_position(offset)
+    })
+}
+
+fn validate_host_name(host: &str, offset: usize) -> Result<(), ParseError> {
+    if host.is_empty() {
+        return Err(ParseError::new("expected a host before the port").with_position(offset));
+    }
+
+    match host.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '.')) {
+        Some(invalid) => {
+            let c = host[invalid..].chars().next().unwrap_or_default();
+
+            Err(
+                ParseError::new(format!("invalid character '{}' in host", c))
+                    .with_position(offset + position(host, invalid)),
+            )
+        }
+        None => Ok(()),
+    }
+}
+
+/// Validates the dot-separated identifiers of pre-release or build metadata
+/// of semantic versions.
+fn validate_identifiers(
+    input: &str,
+    kind: &str,
+    numeric_without_zeros: bool,
+    offset: usize,
+) -> Result<(), ParseError> {
+    let mut start = 0;
+
+    for identifier in input.split('.') {
+        let valid_chars = identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        let leading_zero = numeric_without_zeros
+            && identifier.len() > 1
+            && identifier.starts_with('0')
+            && identifier.chars().all(|c| c.is_ascii_digit());
+
+        if identifier.is_empty() || !valid_chars || leading_zero {
+            return Err(ParseError::new(format!(
+                "'{}' is not a valid {} identifier",
+                identifier, kind
+            ))
+            .with_position(offset + position(input, start)));
+        }
+
+        start += identifier.len() + 1;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        net::{IpAddr, Ipv4Addr, Ipv6Addr},
+        time::Duration,
+    };
+
+    use super::*;
+
+    fn error(message: &str, position: usize) -> ParseError {
+        ParseError::new(message).with_position(position)
+    }
+
+    #[test]
+    fn durations_are_parsed() {
+        assert_eq!(Ok(Duration::from_secs(5400)), DURATION_PARSER("1h30m"));
+        assert_eq!(Ok(Duration::from_secs(5400)), DURATION_PARSER(" 1h 30m "));
+        assert_eq!(Ok(Duration::from_secs(5400)), DURATION_PARSER("1.5 h"));
+        assert_eq!(Ok(Duration::from_secs(93_600)), DURATION_PARSER("1d2h"));
+        assert_eq!(Ok(Duration::from_millis(2250)), DURATION_PARSER("2s250ms"));
+        assert_eq!(Ok(Duration::from_millis(1100)), DURATION_PARSER("1.1s"));
+    }
+
+    #[test]
+    fn invalid_durations_are_reported() {
+        assert_eq!(
+            Err(ParseError::new("expected a duration, such as 1h30m")),
+            DURATION_PARSER("  ")
+        );
+        assert_eq!(
+            Err(error("missing unit after '90', such as 90m", 2)),
+            DURATION_PARSER("90")
+        );
+        assert_eq!(
+            Err(error("unknown unit 'MS', did you mean 'ms'?", 1)),
+            DURATION_PARSER("5MS")
+        );
+        assert_eq!(
+            Err(error("unknown unit 'w', expected one of ms, s, m, h, d", 4)),
+            DURATION_PARSER("1h 2w")
+        );
+        assert_eq!(
+            Err(error("expected a number, such as 1h30m", 3)),
+            DURATION_PARSER("1h ?")
+        );
+        assert_eq!(
+            Err(error("'1.2.3' is not a valid number", 0)),
+            DURATION_PARSER("1.2.3s")
+        );
+    }
+
+    #[test]
+    fn durations_are_formatted_in_words() {
+        assert_eq!(
+            "1 day 1 hour",
+            DURATION_FORMATTER(Duration::from_secs(90_000))
+        );
+        assert_eq!("2 minutes", DURATION_FORMATTER(Duration::from_secs(120)));
+        assert_eq!(
+            "250 milliseconds",
+            DURATION_FORMATTER(Duration::from_millis(250))
+        );
+        assert_eq!("0 seconds", DURATION_FORMATTER(Duration::from_micros(10)));
+    }
+
+    #[test]
+    fn byte_sizes_are_parsed() {
+        assert_eq!(Ok(512), BYTE_SIZE_PARSER("512"));
+        assert_eq!(Ok(512), BYTE_SIZE_PARSER("512 B"));
+        assert_eq!(Ok(1_500), BYTE_SIZE_PARSER("1.5kB"));
+        assert_eq!(Ok(1_500), BYTE_SIZE_PARSER("1.5KB"));
+        assert_eq!(Ok(1_536), BYTE_SIZE_PARSER("1.5KiB"));
+        assert_eq!(Ok(2 << 40), BYTE_SIZE_PARSER("2TiB"));
+    }
+
+    #[test]
+    fn invalid_byte_sizes_are_reported() {
+        assert_eq!(
+            Err(ParseError::new("expected a byte size, such as 1.5GiB")),
+            BYTE_SIZE_PARSER("")
+        );
+        assert_eq!(
+            Err(error("expected a number, such as 1.5GiB", 0)),
+            BYTE_SIZE_PARSER("GiB")
+        );
+        assert_eq!(
+            Err(error("unknown unit 'gib', did you mean 'GiB'?", 3)),
+            BYTE_SIZE_PARSER("10 gib")
+        );
+        assert_eq!(
+            Err(error(
+                "unknown unit 'XB', expected one of B, kB, MB, GB, TB, PB, KiB, MiB, GiB, TiB, PiB",
+                1
+            )),
+            BYTE_SIZE_PARSER("1XB")
+        );
+        assert_eq!(
+            Err(ParseError::new("the byte size is too large")),
+            BYTE_SIZE_PARSER("100000PB")
+        );
+    }
+
+    #[test]
+    fn byte_sizes_are_formatted_with_binary_units() {
+        assert_eq!("0 B", BYTE_SIZE_FORMATTER(0));
+        assert_eq!("1023 B", BYTE_SIZE_FORMATTER(1023));
+        assert_eq!("1 KiB", BYTE_SIZE_FORMATTER(1024));
+        assert_eq!("1.34 MiB", BYTE_SIZE_FORMATTER(1_400_000));
+    }
+
+    #[test]
+    fn percentages_are_parsed_and_formatted() {
+        assert_eq!(Ok(0.5), PERCENTAGE_PARSER("50 %"));
+        assert_eq!(Ok(1.5), PERCENTAGE_PARSER("150%"));
+        assert_eq!(
+            Err(ParseError::new("expected a percentage, such as 42%")),
+            PERCENTAGE_PARSER("%")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                "'ten%' is not a valid percentage, such as 42%"
+            )),
+            PERCENTAGE_PARSER("ten%")
+        );
+
+        assert_eq!("7%", PERCENTAGE_FORMATTER(0.07));
+        assert_eq!("33.33%", PERCENTAGE_FORMATTER(1.0 / 3.0));
+    }
+
+    #[test]
+    fn ip_addresses_are_parsed() {
+        assert_eq!(Ok(Ipv4Addr::new(10, 0, 0, 1)), IPV4_PARSER(" 10.0.0.1"));
+        assert_eq!(
+            Ok("2001:db8::1".parse::<Ipv6Addr>().unwrap()),
+            IPV6_PARSER("2001:0db8:0:0::1")
+        );
+        assert_eq!(
+            Ok(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1))),
+            IP_ADDR_PARSER("192.168.0.1")
+        );
+    }
+
+    #[test]
+    fn invalid_ip_addresses_are_reported() {
+        assert_eq!(
+            Err(error(
+                "expected 4 numbers separated by dots, such as 192.168.0.1",
+                6
+            )),
+            IPV4_PARSER("10.0.0")
+        );
+        assert_eq!(
+            Err(error("'01' must not have leading zeros", 4)),
+            IPV4_PARSER(" 10.01.0.1")
+        );
+        assert_eq!(
+            Err(error("'' is not a number between 0 and 255", 3)),
+            IPV4_PARSER("10..0.1")
+        );
+        assert_eq!(
+            Err(error("invalid character 'g' in IPv6 address", 10)),
+            IPV6_PARSER("2001:db8::g")
+        );
+        assert_eq!(
+            Err(error(
+                "'1:2' is not a valid IPv6 address, such as 2001:db8::1",
+                0
+            )),
+            IPV6_PARSER("1:2")
+        );
+    }
+
+    #[test]
+    fn cidr_blocks_are_parsed() {
+        let block = CIDR_PARSER("192.168.0.0/16").unwrap();
+        assert_eq!(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 0)), block.address);
+        assert_eq!(16, block.prefix_len);
+
+        assert_eq!("fd00::/8", CIDR_PARSER("fd00::/8").unwrap().to_string());
+
+        assert_eq!(
+            Err(error(
+                "expected an address and a prefix length, such as 10.0.0.0/8",
+                8
+            )),
+            CIDR_PARSER("10.0.0.0")
+        );
+        assert_eq!(
+            Err(error(
+                "prefix length '33' is not a number between 0 and 32",
+                9
+            )),
+            CIDR_PARSER("10.0.0.0/33")
+        );
+        assert_eq!(
+            Err(error("'300' is not a number between 0 and 255", 0)),
+            CIDR_PARSER("300.0.0.0/8")
+        );
+    }
+
+    #[test]
+    fn hosts_and_ports_are_parsed() {
+        let address = HOST_PORT_PARSER("example.com:443").unwrap();
+        assert_eq!("example.com", address.host);
+        assert_eq!(443, address.port);
+
+        let address = HOST_PORT_PARSER("[::1]:8080").unwrap();
+        assert_eq!("::1", address.host);
+        assert_eq!("[::1]:8080", address.to_string());
+
+        assert_eq!(
+            "10.0.0.1:22",
+            HOST_PORT_PARSER("10.0.0.1:22").unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_hosts_and_ports_are_reported() {
+        assert_eq!(
+            Err(error(
+                "expected a port after the host, such as example.com:443",
+                11
+            )),
+            HOST_PORT_PARSER("example.com")
+        );
+        assert_eq!(
+            Err(error("port '0' is not a number between 1 and 65535", 12)),
+            HOST_PORT_PARSER("example.com:0")
+        );
+        assert_eq!(
+            Err(error("invalid character '_' in host", 2)),
+            HOST_PORT_PARSER("my_host:80")
+        );
+        assert_eq!(
+            Err(error(
+                "IPv6 addresses must be enclosed in brackets, such as [::1]:8080",
+                0
+            )),
+            HOST_PORT_PARSER("::1:8080")
+        );
+        assert_eq!(
+            Err(error("missing ']' after the IPv6 address", 4)),
+            HOST_PORT_PARSER("[::1")
+        );
+        assert_eq!(
+            Err(error("expected a host before the port", 0)),
+            HOST_PORT_PARSER(":80")
+        );
+    }
+
+    #[test]
+    fn versions_are_parsed() {
+        let version = VERSION_PARSER("1.22.3-alpha.1+build.5").unwrap();
+        assert_eq!(
+            Version {
+                major: 1,
+                minor: 22,
+                patch: 3,
+                pre: Some(String::from("alpha.1")),
+                build: Some(String::from("build.5")),
+            },
+            version
+        );
+        assert_eq!("1.22.3-alpha.1+build.5", version.to_string());
+
+        assert_eq!("0.1.0", VERSION_PARSER("v0.1.0").unwrap().to_string());
+        assert_eq!(
+            "1.0.0+001",
+            VERSION_PARSER("1.0.0+001").unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_versions_are_reported() {
+        assert_eq!(
+            Err(error(
+                "expected major, minor and patch numbers, such as 1.2.3",
+                3
+            )),
+            VERSION_PARSER("1.2")
+        );
+        assert_eq!(
+            Err(error("minor version '01' must not have leading zeros", 2)),
+            VERSION_PARSER("1.01.0")
+        );
+        assert_eq!(
+            Err(error("'x' is not a valid patch version", 5)),
+            VERSION_PARSER("v1.2.x")
+        );
+        assert_eq!(
+            Err(error("'01' is not a valid pre-release identifier", 12)),
+            VERSION_PARSER("1.0.0-alpha.01")
+        );
+        assert_eq!(
+            Err(error("'' is not a valid build identifier", 6)),
+            VERSION_PARSER("1.0.0+")
+        );
+    }
+
+    #[test]
+    fn lists_are_parsed_and_formatted() {
+        assert_eq!(Ok(vec![80, 443, 8080]), parse_list::<u16>("80,443, 8080"));
+        assert_eq!(Ok(Vec::<u16>::new()), parse_list::<u16>(" "));
+        assert_eq!(
+            Err(error("empty item in the list", 3)),
+            parse_list::<u16>("80,,443")
+        );
+        assert_eq!(
+            Err(error("'99999' is not a valid item", 4)),
+            parse_list::<u16>("80, 99999")
+        );
+
+        assert_eq!("a, b", format_list(vec!["a", "b"]));
+        assert_eq!("", format_list(Vec::<u16>::new()));
+    }
+}
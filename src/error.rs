@@ -38,6 +38,12 @@ pub enum InquireError {
     #[error("Operation was interrupted by the user")]
     OperationInterrupted,
 
+    /// The timeout set on the prompt elapsed before the user pressed any key,
+    /// and the prompt has no starting value to resolve to or its validators
+    /// rejected it.
+    #[error("Prompt timed out before the user answered")]
+    Timeout,
+
     /// Error while executing IO operations.
     #[error("User-provided error: {0}")]
     Custom(#[from] CustomUserError),
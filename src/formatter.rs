@@ -45,9 +45,18 @@
 //! ```text
 //! ? What's your name? My name is Mikael
 //! ```
+//!
+//! With the opt-in `builtin_parsers` feature, this module also
+//! exports formatters matching the built-in parsers of the
+//! [parser](crate::parser) module.
 
 use crate::list_option::ListOption;
 
+#[cfg(feature = "builtin_parsers")]
+pub use crate::builtin_parsers::{
+    format_list, BYTE_SIZE_FORMATTER, DURATION_FORMATTER, PERCENTAGE_FORMATTER,
+};
+
 /// Type alias for formatters that receive a string slice as the input,
 /// required by [Text](crate::Text) and [Password](crate::Password) for example.
 ///
@@ -129,6 +138,27 @@ pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 /// ```
 pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 
+/// Type alias for formatters used in [`QuantitySelect`](crate::QuantitySelect) prompts.
+///
+/// Formatters receive the selected options along with their counts and return
+/// a [String] to be displayed to the user as the final answer.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::ListOption;
+/// use inquire::formatter::QuantityFormatter;
+///
+/// let formatter: QuantityFormatter<str> = &|opts| {
+///     let total: u32 = opts.iter().map(|(_, count)| count).sum();
+///     format!("{} runners", total)
+/// };
+///
+/// let ans = vec![(ListOption::new(0, "linux"), 4), (ListOption::new(2, "macos"), 1)];
+/// assert_eq!(String::from("5 runners"), formatter(&ans));
+/// ```
+pub type QuantityFormatter<'a, T> = &'a dyn Fn(&[(ListOption<&T>, u32)]) -> String;
+
 /// Type alias for formatters used in [`CustomType`](crate::CustomType) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
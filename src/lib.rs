@@ -8,8 +8,14 @@
 //! - [`DateSelect`]* to get a date input from the user, selected via an _interactive calendar_;
 //! - [`Select`] to ask the user to select one option from a given list;
 //! - [`MultiSelect`] to ask the user to select an arbitrary number of options from a given list;
+//! - [`TreeSelect`] to ask the user to select one option from a hierarchy, expanding and collapsing its branches;
+//! - [`TreeMultiSelect`] to ask the user to select many options from a hierarchy, with tri-state checkboxes on its branches;
+//! - [`Reorder`] to ask the user to sort a given list of options, such as ranking priorities;
+//! - [`QuantitySelect`] to ask the user how many of each option they want, such as the runners of a build matrix;
 //! - [`Confirm`] for simple yes/no confirmation prompts;
+//! - [`Expand`] to ask the user to pick one of a few options by pressing a single key, such as `Overwrite? (y,N,a,q,?)`;
 //! - [`CustomType`] for text prompts that you would like to parse to a custom type, such as numbers or UUIDs;
+//! - [`Number`] to get a number from the user, typed or changed in steps with the arrow keys, optionally displayed on a _slider_;
 //! - [`Password`] for secretive text prompts.
 //!
 //! Check out the [GitHub repository](https://github.com/mikaelmello/inquire) to see demos of what you can do with `inquire`.
@@ -23,10 +29,11 @@
 //!   - Default values;
 //!   - Input validators and formatters;
 //!   - Help messages;
-//!   - Auto-completion for [`Text`] prompts;
+//!   - Auto-completion for [`Text`] prompts, replacing either the whole input or just the token under the cursor;
 //!   - Custom list filters for Select and [`MultiSelect`] prompts;
-//!   - Custom parsers for [`Confirm`] and [`CustomType`] prompts;
+//!   - Custom parsers for [`Confirm`] and [`CustomType`] prompts, with built-in ones for durations, byte sizes, IP addresses and more;
 //!   - Custom extensions for files created by [`Editor`] prompts;
+//!   - Timeouts, answering prompts with their default or starting values when the user does not press any key in time;
 //!   - and many others!
 //!
 //! \* Date-related features are available by enabling the `date` feature.
@@ -58,14 +65,23 @@
 //! [`DateSelect`]: crate::DateSelect
 //! [`Select`]: crate::Select
 //! [`MultiSelect`]: crate::MultiSelect
+//! [`TreeSelect`]: crate::TreeSelect
+//! [`TreeMultiSelect`]: crate::TreeMultiSelect
+//! [`Reorder`]: crate::Reorder
+//! [`QuantitySelect`]: crate::QuantitySelect
 //! [`Confirm`]: crate::Confirm
+//! [`Expand`]: crate::Expand
 //! [`CustomType`]: crate::CustomType
+//! [`Number`]: crate::Number
 //! [`Password`]: crate::Password
 //! [`Editor`]: crate::Editor
 
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod autocompletion;
+#[cfg(feature = "builtin_parsers")]
+mod builtin_parsers;
 mod config;
 #[cfg(feature = "date")]
 mod date_utils;
@@ -73,9 +89,11 @@ pub mod error;
 pub mod formatter;
 mod input;
 pub mod list_option;
+pub mod option_source;
 pub mod parser;
 mod prompts;
 mod terminal;
+pub mod tree;
 pub mod type_aliases;
 pub mod ui;
 mod utils;
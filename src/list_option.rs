@@ -1,7 +1,10 @@
 //! Utilities used to wrap user selections in [Select](crate::Select) and
 //! [`MultiSelect`](crate::MultiSelect) prompts.
 
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+};
 
 /// Represents a selection made by the user when prompted to select one or several
 /// options among those presented.
@@ -62,3 +65,133 @@ where
         self.value.fmt(f)
     }
 }
+
+/// Answer of [Select](crate::Select) prompts that accept new values, which is
+/// either one of the options presented or a new value typed by the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::{ListOption, SelectOrCreate};
+///
+/// let existing = SelectOrCreate::Existing(ListOption::new(1, "bug"));
+/// let created: SelectOrCreate<&str> = SelectOrCreate::Created(String::from("triage"));
+///
+/// assert_eq!("bug", existing.to_string());
+/// assert_eq!("triage", created.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectOrCreate<T> {
+    /// One of the options presented to the user.
+    Existing(ListOption<T>),
+
+    /// New value typed by the user in the filter input.
+    Created(String),
+}
+
+impl<T> fmt::Display for SelectOrCreate<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Existing(option) => option.fmt(f),
+            Self::Created(value) => value.fmt(f),
+        }
+    }
+}
+
+/// Behavior of the digits typed by the user in [Select](crate::Select) and
+/// [`MultiSelect`](crate::MultiSelect) prompts.
+///
+/// When jumping is enabled, typed digits no longer feed the filter input but
+/// move the cursor to the option displayed at that position of the current
+/// filtered list, starting from 1. Digits typed in quick succession form
+/// multi-digit indexes. Combine it with the `option_index_prefix` property of
+/// [`RenderConfig`](crate::ui::RenderConfig), which then displays positions in
+/// the filtered list instead of indexes in the original one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumericJump {
+    /// Digits are part of the filter input.
+    Disabled,
+
+    /// Digits move the cursor to the option with the typed index.
+    Move,
+
+    /// Digits move the cursor to the option with the typed index, then submit
+    /// it in `Select` prompts or toggle it in `MultiSelect` prompts, as soon
+    /// as typing more digits could not lead to another option or, otherwise,
+    /// once no other digit is typed for a second.
+    Pick,
+}
+
+/// Group of consecutive options in [Select](crate::Select) and
+/// [`MultiSelect`](crate::MultiSelect) prompts, displayed below a non-selectable
+/// header row containing its title, or below a separator row when it has no title.
+///
+/// Groups reference options by their indexes in the original list, which means
+/// that the indexes of the returned [`ListOption`]s are not affected by them.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::OptionGroup;
+///
+/// let recent = OptionGroup::new("Recent", 0..3);
+/// let team = OptionGroup::new("Team projects", 3..10);
+/// let others = OptionGroup::separator(10..12);
+///
+/// assert_eq!(Some("Recent"), recent.title);
+/// assert_eq!(None, others.title);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionGroup<'a> {
+    /// Title displayed in the header row of the group. When `None`, a separator
+    /// row is displayed instead, unless the group is the first row of the list.
+    pub title: Option<&'a str>,
+
+    /// Range of indexes, relative to the original option list, of the options
+    /// contained in the group.
+    pub range: Range<usize>,
+}
+
+impl<'a> OptionGroup<'a> {
+    /// Creates a group with a header row displaying the given title.
+    pub fn new(title: &'a str, range: Range<usize>) -> Self {
+        Self {
+            title: Some(title),
+            range,
+        }
+    }
+
+    /// Creates a group with no title, displayed below a separator row.
+    pub fn separator(range: Range<usize>) -> Self {
+        Self { title: None, range }
+    }
+
+    /// Sorts the groups by their starting index, making sure they are inside
+    /// the bounds of the option list and that they do not overlap.
+    pub(in crate) fn sort_and_validate(groups: &mut [Self], len: usize) -> Result<(), String> {
+        groups.sort_by_key(|g| g.range.start);
+
+        for group in groups.iter() {
+            if group.range.start > group.range.end || group.range.end > len {
+                return Err(format!(
+                    "Group range {:?} is out-of-bounds for length {} of options",
+                    group.range, len
+                ));
+            }
+        }
+
+        for pair in groups.windows(2) {
+            if pair[0].range.end > pair[1].range.start {
+                return Err(format!(
+                    "Group ranges {:?} and {:?} overlap",
+                    pair[0].range, pair[1].range
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+//! Sources of options loaded on demand by [Select](crate::Select) and
+//! [`MultiSelect`](crate::MultiSelect) prompts.
+//!
+//! When a list is too large to be built upfront, or when its items are only
+//! known over time, an [`OptionSource`] can provide the options to the prompt
+//! instead. Options are then loaded as the user scrolls or filters the list,
+//! only as many as needed to fill the current page, and a "n loaded" indicator
+//! is displayed until the source is exhausted.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use inquire::{option_source::OptionSource, Select};
+//!
+//! let source = OptionSource::iter((1..=500_000).map(|i| format!("Order #{}", i)));
+//!
+//! let order = Select::from_source("Which order do you want to refund?", source).prompt();
+//! ```
+
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Receiver, sync::mpsc::TryRecvError, time::Duration};
+
+use crate::error::CustomUserError;
+
+/// Interval at which prompts are rendered again while a source may receive
+/// options without being asked for them, such as an open channel.
+pub(in crate) const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+enum Loader<'a, T> {
+    Paged {
+        fetch: Box<dyn FnMut(usize) -> Result<Vec<T>, CustomUserError> + 'a>,
+        next_page: usize,
+    },
+    Iter(Box<dyn Iterator<Item = T> + 'a>),
+    Channel(Receiver<T>),
+}
+
+struct State<'a, T> {
+    loader: Loader<'a, T>,
+    exhausted: bool,
+}
+
+/// Source of options loaded incrementally by list prompts.
+///
+/// Sources can be created from a paged callback, from an iterator that is
+/// pulled incrementally, or from a channel that receives items while the
+/// prompt is open.
+///
+/// Cloning a source does not duplicate the underlying callback, iterator or
+/// channel, which are shared by all clones.
+pub struct OptionSource<'a, T> {
+    state: Rc<RefCell<State<'a, T>>>,
+}
+
+impl<'a, T> Clone for OptionSource<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Rc::clone(&self.state),
+        }
+    }
+}
+
+impl<'a, T> OptionSource<'a, T> {
+    fn from_loader(loader: Loader<'a, T>) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(State {
+                loader,
+                exhausted: false,
+            })),
+        }
+    }
+
+    /// Creates a source that fetches options in pages, calling the given
+    /// function with the index of the next page, starting at 0.
+    ///
+    /// The source is exhausted when the function returns an empty page.
+    /// Errors are propagated to the prompt as [`InquireError::Custom`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::option_source::OptionSource;
+    ///
+    /// let source = OptionSource::paged(|page| {
+    ///     Ok(match page {
+    ///         0..=9 => (page * 100..(page + 1) * 100).collect(),
+    ///         _ => vec![],
+    ///     })
+    /// });
+    /// # let _: OptionSource<usize> = source;
+    /// ```
+    ///
+    /// [`InquireError::Custom`]: crate::error::InquireError::Custom
+    pub fn paged<F>(fetch: F) -> Self
+    where
+        F: FnMut(usize) -> Result<Vec<T>, CustomUserError> + 'a,
+    {
+        Self::from_loader(Loader::Paged {
+            fetch: Box::new(fetch),
+            next_page: 0,
+        })
+    }
+
+    /// Creates a source that pulls options from the given iterator, only as
+    /// many as needed at a time.
+    pub fn iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'a,
+    {
+        Self::from_loader(Loader::Iter(Box::new(iter.into_iter())))
+    }
+
+    /// Creates a source that receives options from a channel while the prompt
+    /// is open, such as results streamed by a background search.
+    ///
+    /// Received options are displayed on the next rendering of the prompt,
+    /// which is refreshed periodically while the channel is open, and the
+    /// source is exhausted once all senders are dropped.
+    pub fn channel(receiver: Receiver<T>) -> Self {
+        Self::from_loader(Loader::Channel(receiver))
+    }
+
+    /// Whether all options of the source have been loaded.
+    pub fn is_exhausted(&self) -> bool {
+        self.state.borrow().exhausted
+    }
+
+    /// Whether options may still arrive without being asked for, requiring
+    /// the prompt to be refreshed periodically to display them.
+    pub(in crate) fn is_streaming(&self) -> bool {
+        let state = self.state.borrow();
+
+        !state.exhausted && matches!(state.loader, Loader::Channel(_))
+    }
+
+    /// Loads options into the given list until it reaches the given length or
+    /// until the source is exhausted. Channel sources never block, taking
+    /// every option already received instead.
+    pub(in crate) fn load(&self, options: &mut Vec<T>, len: usize) -> Result<(), CustomUserError> {
+        let mut state = self.state.borrow_mut();
+
+        if state.exhausted {
+            return Ok(());
+        }
+
+        let exhausted = match &mut state.loader {
+            Loader::Paged { fetch, next_page } => loop {
+                if options.len() >= len {
+                    break false;
+                }
+
+                let page = fetch(*next_page)?;
+                *next_page = next_page.saturating_add(1);
+
+                if page.is_empty() {
+                    break true;
+                }
+
+                options.extend(page);
+            },
+            Loader::Iter(iter) => loop {
+                if options.len() >= len {
+                    break false;
+                }
+
+                match iter.next() {
+                    Some(option) => options.push(option),
+                    None => break true,
+                }
+            },
+            Loader::Channel(receiver) => loop {
+                match receiver.try_recv() {
+                    Ok(option) => options.push(option),
+                    Err(TryRecvError::Empty) => break false,
+                    Err(TryRecvError::Disconnected) => break true,
+                }
+            },
+        };
+
+        state.exhausted = exhausted;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, sync::mpsc::channel};
+
+    use super::OptionSource;
+
+    #[test]
+    fn iter_source_pulls_only_what_is_needed() {
+        let pulled = Cell::new(0);
+        let source = OptionSource::iter((0..1_000).inspect(|_| pulled.set(pulled.get() + 1)));
+
+        let mut options = vec![];
+        source.load(&mut options, 10).unwrap();
+
+        assert_eq!(10, options.len());
+        assert_eq!(10, pulled.get());
+        assert!(!source.is_exhausted());
+
+        source.load(&mut options, usize::MAX).unwrap();
+
+        assert_eq!(1_000, options.len());
+        assert!(source.is_exhausted());
+    }
+
+    #[test]
+    fn paged_source_stops_at_empty_page() {
+        let source = OptionSource::paged(|page| {
+            Ok(match page {
+                0 | 1 => vec![page; 5],
+                _ => vec![],
+            })
+        });
+
+        let mut options = vec![];
+        source.load(&mut options, 3).unwrap();
+        assert_eq!(vec![0; 5], options);
+
+        source.load(&mut options, 100).unwrap();
+        assert_eq!(10, options.len());
+        assert!(source.is_exhausted());
+    }
+
+    #[test]
+    fn channel_source_takes_received_options() {
+        let (sender, receiver) = channel();
+        let source = OptionSource::channel(receiver);
+
+        let mut options = vec![];
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+        source.load(&mut options, 0).unwrap();
+
+        assert_eq!(vec![1, 2], options);
+        assert!(!source.is_exhausted());
+
+        sender.send(3).unwrap();
+        drop(sender);
+        source.load(&mut options, 0).unwrap();
+
+        assert_eq!(vec![1, 2, 3], options);
+        assert!(source.is_exhausted());
+    }
+}
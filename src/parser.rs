@@ -3,59 +3,148 @@
 //! [`CustomType`](crate::CustomType).
 //!
 //! Parsers receive the user input to a given prompt and return either
-//! a successful result ([Ok]) containing the parsed value or an empty [Err]
+//! a successful result ([Ok]) containing the parsed value or a [`ParseError`]
 //! if a value could not be parsed.
+//!
+//! A [`ParseError`] may carry a specific message, such as "expected a number
+//! between 0 and 255", displayed to the user instead of the error message set
+//! in the prompt, and the position of the error in the input, where the cursor
+//! is placed so that the user can fix it.
+//!
+//! With the opt-in `builtin_parsers` feature, this module also
+//! exports parsers for human durations, byte sizes, percentages, IP addresses,
+//! CIDR blocks, `host:port` pairs, semantic versions and comma-separated lists,
+//! with matching formatters in the [formatter](crate::formatter) module.
+
+use crate::validator::ErrorMessage;
+
+#[cfg(feature = "builtin_parsers")]
+pub use crate::builtin_parsers::{
+    parse_list, Cidr, HostPort, Version, BYTE_SIZE_PARSER, CIDR_PARSER, DURATION_PARSER,
+    HOST_PORT_PARSER, IPV4_PARSER, IPV6_PARSER, IP_ADDR_PARSER, PERCENTAGE_PARSER, VERSION_PARSER,
+};
+
+/// Error returned by parsers when a value could not be parsed from the user input.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::ParseError;
+/// use inquire::validator::ErrorMessage;
+///
+/// // Displays the error message set in the prompt.
+/// let error = ParseError::default();
+/// assert_eq!(ErrorMessage::Default, error.message);
+///
+/// // Displays a specific message and places the cursor after "10".
+/// let error = ParseError::new("unknown unit 'kb', did you mean 'KB'?").with_position(2);
+/// assert_eq!(Some(2), error.position);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseError {
+    /// Message displayed to the user.
+    ///
+    /// When set to [`ErrorMessage::Default`], the error message set in the
+    /// prompt is displayed instead.
+    pub message: ErrorMessage,
+
+    /// Position of the error in the input, counted in characters, where the
+    /// cursor is placed. The cursor is left untouched when `None`.
+    pub position: Option<usize>,
+}
+
+impl ParseError {
+    /// Creates a [`ParseError`] displaying the given message.
+    pub fn new<M: Into<ErrorMessage>>(message: M) -> Self {
+        Self {
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    /// Sets the position of the error in the input, counted in characters.
+    pub fn with_position(mut self, position: usize) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Message to display, falling back to the error message of the prompt.
+    pub(in crate) fn message_or(&self, fallback: &str) -> ErrorMessage {
+        match &self.message {
+            ErrorMessage::Default => fallback.into(),
+            message => message.clone(),
+        }
+    }
+}
+
+impl<T> From<T> for ParseError
+where
+    T: ToString,
+{
+    fn from(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
 
 /// Type alias for parsers used in [Confirm](crate::Confirm) prompts.
 ///
 /// [`BoolParser`]s receive the user input to a given prompt and return either
-/// a successful result ([Ok]) containing the parsed `bool` or an empty [Err]
+/// a successful result ([Ok]) containing the parsed `bool` or a [`ParseError`]
 /// if a value could not be parsed.
 ///
 /// # Examples
 ///
 /// ```
-/// use inquire::parser::BoolParser;
+/// use inquire::parser::{BoolParser, ParseError};
 ///
 /// let parser: BoolParser = &|ans| match ans {
 ///     "si" => Ok(true),
 ///     "no" => Ok(false),
-///     _ => Err(()),
+///     _ => Err("Responda 'si' o 'no'".into()),
 /// };
 /// assert_eq!(Ok(true), parser("si"));
 /// assert_eq!(Ok(false), parser("no"));
-/// assert_eq!(Err(()), parser("yes"));
-/// assert_eq!(Err(()), parser("não"));
+/// assert_eq!(Err(ParseError::new("Responda 'si' o 'no'")), parser("yes"));
 /// ```
-pub type BoolParser<'a> = &'a dyn Fn(&str) -> Result<bool, ()>;
+pub type BoolParser<'a> = &'a dyn Fn(&str) -> Result<bool, ParseError>;
 
 /// Type alias for parsers used in [Confirm](crate::Confirm) prompts.
 ///
 /// [`CustomTypeParser`]s receive the user input to a given prompt and return either
-/// a successful result ([Ok]) containing the parsed `bool` or an empty [Err]
+/// a successful result ([Ok]) containing the parsed value or a [`ParseError`]
 /// if a value could not be parsed.
 ///
 /// # Examples
 ///
 /// ```
-/// use inquire::parser::CustomTypeParser;
+/// use inquire::parser::{CustomTypeParser, ParseError};
 ///
-/// let parser: CustomTypeParser<bool> = &|val| match val {
-///     "si" => Ok(true),
-///     "no" => Ok(false),
-///     _ => Err(()),
+/// let parser: CustomTypeParser<u8> = &|val| match val.find(|c: char| !c.is_ascii_digit()) {
+///     Some(i) => {
+///         // positions are counted in characters, while `find` returns a byte offset
+///         let position = val[..i].chars().count();
+///         Err(ParseError::new("expected only digits").with_position(position))
+///     }
+///     None => val
+///         .parse()
+///         .map_err(|_| "expected a number between 0 and 255".into()),
 /// };
-/// assert_eq!(Ok(true), parser("si"));
-/// assert_eq!(Ok(false), parser("no"));
-/// assert_eq!(Err(()), parser("yes"));
-/// assert_eq!(Err(()), parser("não"));
+/// assert_eq!(Ok(42), parser("42"));
+/// assert_eq!(
+///     Err(ParseError::new("expected only digits").with_position(2)),
+///     parser("42a")
+/// );
+/// assert_eq!(
+///     Err(ParseError::new("expected a number between 0 and 255")),
+///     parser("256")
+/// );
 /// ```
-pub type CustomTypeParser<'a, T> = &'a dyn Fn(&str) -> Result<T, ()>;
+pub type CustomTypeParser<'a, T> = &'a dyn Fn(&str) -> Result<T, ParseError>;
 
 /// Bool formatter used  by default in [Confirm](crate::Confirm) prompts.
 pub const DEFAULT_BOOL_PARSER: BoolParser = &|ans| {
     if ans.len() > 3 {
-        return Err(());
+        return Err(ParseError::default());
     }
 
     let ans = ans.to_lowercase();
@@ -63,7 +152,7 @@ pub const DEFAULT_BOOL_PARSER: BoolParser = &|ans| {
     match ans.as_str() {
         "y" | "yes" => Ok(true),
         "n" | "no" => Ok(false),
-        _ => Err(()),
+        _ => Err(ParseError::default()),
     }
 };
 
@@ -86,11 +175,14 @@ pub const DEFAULT_BOOL_PARSER: BoolParser = &|ans| {
 /// let parser: CustomTypeParser<f64> = parse_type!(f64);
 /// assert_eq!(Ok(32.44f64), parser("32.44"));
 /// assert_eq!(Ok(11e15f64), parser("11e15"));
-/// assert_eq!(Err(()), parser("32f"));
-/// assert_eq!(Err(()), parser("11^2"));
+/// assert!(parser("32f").is_err());
+/// assert!(parser("11^2").is_err());
 /// ```
 macro_rules! parse_type {
     ($type:ty) => {{
-        &|a| a.parse::<$type>().map_err(|_| ())
+        &|a| {
+            a.parse::<$type>()
+                .map_err(|_| $crate::parser::ParseError::default())
+        }
     }};
 }
@@ -1,13 +1,37 @@
+use std::time::Duration;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
-    parser::{BoolParser, DEFAULT_BOOL_PARSER},
+    input::Input,
+    parser::{BoolParser, ParseError, DEFAULT_BOOL_PARSER},
     terminal::{get_default_terminal, Terminal},
-    ui::{Backend, RenderConfig},
+    ui::{
+        Backend, CommonBackend, ConfirmBackend, Key, KeyModifiers, PreviewPosition, RenderConfig,
+    },
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
     CustomType,
 };
 
+/// Ways for the user to answer a [Confirm] prompt.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ConfirmMode {
+    /// The user types an answer, such as "y" or "no", and submits it by
+    /// pressing enter, just like [CustomType](crate::CustomType) prompts.
+    Input,
+
+    /// The prompt is answered as soon as a single typed character is accepted
+    /// by the parser, such as "y" or "n". Pressing enter submits the default
+    /// value, if any.
+    Instant,
+
+    /// A "Yes / No" switch is rendered after the prompt message, flipped with
+    /// the left and right arrows, tab or space, and submitted by pressing enter.
+    /// The labels of the switch are the formatted `true` and `false` values.
+    Toggle,
+}
+
 /// Prompt to ask the user for simple yes/no questions, commonly known by asking the user displaying the `(y/n)` text.
 ///
 /// This prompt is basically a wrapper around the behavior of `CustomType` prompts, providing a sensible set of defaults to ask for simple `true/false` questions, such as confirming an action.
@@ -17,12 +41,17 @@ use crate::{
 ///
 /// Finally, once the answer is submitted, [`Confirm`] prompts display the bool value formatted as either "Yes", if a `true` value was parsed, or "No" otherwise.
 ///
-/// The Confirm prompt does not support custom validators because of the nature of the prompt. The user input is always parsed to true or false. If one of the two alternatives is invalid, a Confirm prompt that only allows yes or no answers does not make a lot of sense to me, but if someone provides a clear use-case I will reconsider.
+/// Validators receive the parsed `bool` value, which is useful when one of the answers is not acceptable in some situations, e.g. refusing to skip a required migration. In all modes, a value rejected by a validator is not submitted and the validation error is displayed instead.
 ///
 /// Confirm prompts provide several options of configuration:
 ///
 /// - **Prompt message**: Required when creating the prompt.
+/// - **Mode**: How the user answers the prompt, see [`ConfirmMode`].
+///   - Set to [`ConfirmMode::Input`] by default, typing the answer and pressing enter.
+///   - [`ConfirmMode::Instant`] answers as soon as "y" or "n" is pressed.
+///   - [`ConfirmMode::Toggle`] renders a "Yes / No" switch, flipped with the arrows, tab or space.
 /// - **Default value**: Default value returned when the user submits an empty response.
+///   - In toggle mode, it is the value initially selected in the switch, `false` if not set.
 /// - **Placeholder**: Short hint that describes the expected value of the input.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
@@ -31,8 +60,9 @@ use crate::{
 ///   - The default `bool` parser returns `true` if the input is either `"y"` or `"yes"`, in a case-insensitive comparison. Similarly, the parser returns `false` if the input is either `"n"` or `"no"`.
 /// - **Default value formatter**: Function that formats how the default value is displayed to the user.
 ///   - By default, displays "y/n" with the default value capitalized, e.g. "y/N".
-/// - **Error message**: Error message to display when a value could not be parsed from the input.
+/// - **Error message**: Error message to display when a value could not be parsed from the input and the parser did not return a specific one.
 ///   - Set to "Invalid answer, try typing 'y' for yes or 'n' for no" by default.
+/// - **Validators**: Custom validators to the parsed value, displaying an error message if the value does not pass the requirements.
 ///
 /// # Example
 ///
@@ -60,6 +90,9 @@ pub struct Confirm<'a> {
     /// Default value, returned when the user input is empty.
     pub default: Option<bool>,
 
+    /// How the user answers the prompt.
+    pub mode: ConfirmMode,
+
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
@@ -75,9 +108,23 @@ pub struct Confirm<'a> {
     /// Function that formats the default value to be presented to the user
     pub default_value_formatter: BoolFormatter<'a>,
 
-    /// Error message displayed when a value could not be parsed from input.
+    /// Error message displayed when a value could not be parsed from input
+    /// and the parser did not return a specific one.
     pub error_message: String,
 
+    /// Collection of validators to apply to the parsed value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<CustomTypeValidator<'a, bool>>,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or fails with [`InquireError::Timeout`] when
+    /// there is none.
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -103,6 +150,9 @@ impl<'a> Confirm<'a> {
         false => String::from("y/N"),
     };
 
+    /// Default mode, where the user types the answer and presses enter.
+    pub const DEFAULT_MODE: ConfirmMode = ConfirmMode::Input;
+
     /// Default error message displayed when parsing fails.
     pub const DEFAULT_ERROR_MESSAGE: &'a str =
         "Invalid answer, try typing 'y' for yes or 'n' for no";
@@ -112,12 +162,15 @@ impl<'a> Confirm<'a> {
         Self {
             message,
             default: None,
+            mode: Self::DEFAULT_MODE,
             placeholder: None,
             help_message: None,
             formatter: Self::DEFAULT_FORMATTER,
             parser: Self::DEFAULT_PARSER,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            validators: vec![],
+            timeout: None,
             render_config: get_configuration(),
         }
     }
@@ -128,6 +181,12 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets how the user answers the prompt.
+    pub fn with_mode(mut self, mode: ConfirmMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Sets the placeholder.
     pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
@@ -152,18 +211,55 @@ impl<'a> Confirm<'a> {
         self
     }
 
-    /// Sets a custom error message displayed when a submission could not be parsed to a value.
+    /// Sets a custom error message displayed when a submission could not be parsed to a value
+    /// and the parser did not return a specific one.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
         self
     }
 
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case one of the answers is not acceptable in some situations.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator(mut self, validator: CustomTypeValidator<'a, bool>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[CustomTypeValidator<'a, bool>]) -> Self {
+        for validator in validators {
+            self.validators.push(*validator);
+        }
+        self
+    }
+
     /// Sets the default value formatter
     pub fn with_default_value_formatter(mut self, formatter: BoolFormatter<'a>) -> Self {
         self.default_value_formatter = formatter;
         self
     }
 
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default value, or the highlighted one in toggle mode.
+    /// When there is none, or when the validators reject it, the prompt fails
+    /// with [`InquireError::Timeout`] instead. The remaining time is displayed
+    /// in the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -206,7 +302,16 @@ impl<'a> Confirm<'a> {
         self,
         backend: &mut Backend<T>,
     ) -> InquireResult<bool> {
-        CustomType::from(self).prompt_with_backend(backend)
+        match self.mode {
+            ConfirmMode::Input => CustomType::from(self).prompt_with_backend(backend),
+            ConfirmMode::Instant | ConfirmMode::Toggle => {
+                if let Some(timeout) = self.timeout {
+                    backend.set_timeout(timeout);
+                }
+
+                ConfirmPrompt::from(self).prompt(backend)
+            }
+        }
     }
 }
 
@@ -229,7 +334,551 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
             formatter: co.formatter,
             parser: co.parser,
             error_message: co.error_message,
+            validators: co.validators,
+            previewer: None,
+            preview_position: PreviewPosition::Beside,
+            timeout: co.timeout,
             render_config: co.render_config,
         }
     }
 }
+
+struct ConfirmPrompt<'a> {
+    message: &'a str,
+    mode: ConfirmMode,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<bool>,
+    input: Input,
+    value: bool,
+    formatter: BoolFormatter<'a>,
+    parser: BoolParser<'a>,
+    default_value_formatter: BoolFormatter<'a>,
+    error_message: String,
+    validators: Vec<CustomTypeValidator<'a, bool>>,
+}
+
+impl<'a> From<Confirm<'a>> for ConfirmPrompt<'a> {
+    fn from(co: Confirm<'a>) -> Self {
+        Self {
+            message: co.message,
+            mode: co.mode,
+            error: None,
+            help_message: co.help_message,
+            default: co.default,
+            input: co
+                .placeholder
+                .map(|p| Input::new().with_placeholder(p))
+                .unwrap_or_else(Input::new),
+            value: co.default.unwrap_or(false),
+            formatter: co.formatter,
+            parser: co.parser,
+            default_value_formatter: co.default_value_formatter,
+            error_message: co.error_message,
+            validators: co.validators,
+        }
+    }
+}
+
+impl<'a> ConfirmPrompt<'a> {
+    fn parse_char(&self, c: char) -> Result<bool, ParseError> {
+        (self.parser)(c.encode_utf8(&mut [0; 4]))
+    }
+
+    fn validate_answer(&self, answer: bool) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator(&answer) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    /// Handles a key press, returning the answer once the prompt is submitted
+    /// and the answer passes the validators.
+    fn on_change(&mut self, key: Key) -> InquireResult<Option<bool>> {
+        let answer = match self.answer_for(key) {
+            Some(answer) => answer,
+            None => return Ok(None),
+        };
+
+        match self.validate_answer(answer)? {
+            Validation::Valid => Ok(Some(answer)),
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Updates the state with a key press, returning the answer if the key
+    /// submits one.
+    fn answer_for(&mut self, key: Key) -> Option<bool> {
+        match (self.mode, key) {
+            (ConfirmMode::Toggle, Key::Submit) => return Some(self.value),
+            (ConfirmMode::Toggle, Key::Left(KeyModifiers::NONE)) => self.value = true,
+            (ConfirmMode::Toggle, Key::Right(KeyModifiers::NONE)) => self.value = false,
+            (ConfirmMode::Toggle, Key::Tab) | (ConfirmMode::Toggle, Key::Char(' ', _)) => {
+                self.value = !self.value
+            }
+            (ConfirmMode::Toggle, Key::Char(c, KeyModifiers::NONE)) => {
+                if let Ok(value) = self.parse_char(c) {
+                    self.value = value;
+                }
+            }
+            (_, Key::Submit) => match self.default {
+                Some(value) => return Some(value),
+                None => self.error = Some(self.error_message.clone().into()),
+            },
+            (_, Key::Char(c, KeyModifiers::NONE)) | (_, Key::Char(c, KeyModifiers::SHIFT)) => {
+                match self.parse_char(c) {
+                    Ok(value) => return Some(value),
+                    Err(err) => self.error = Some(err.message_or(&self.error_message)),
+                }
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn render<B: ConfirmBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        match self.mode {
+            ConfirmMode::Toggle => {
+                let yes = (self.formatter)(true);
+                let no = (self.formatter)(false);
+
+                backend.render_confirm_toggle(prompt, &yes, &no, self.value)?;
+            }
+            _ => {
+                let default_message = self.default.map(self.default_value_formatter);
+
+                backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+            }
+        }
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: ConfirmBackend>(mut self, backend: &mut B) -> InquireResult<bool> {
+        let final_answer: bool;
+
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match self.answer_for(Key::Submit) {
+                    Some(answer) => match self.validate_answer(answer)? {
+                        Validation::Valid => {
+                            final_answer = answer;
+                            break;
+                        }
+                        Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                    },
+                    None => timeout_prompt!(backend, self.message),
+                },
+                key => {
+                    if let Some(answer) = self.on_change(key)? {
+                        final_answer = answer;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let formatted = (self.formatter)(final_answer);
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        parser::ParseError,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Attributes, Backend, RenderConfig, StyleSheet},
+        validator::Validation,
+        Confirm, ConfirmMode,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
+
+    #[test]
+    fn instant_mode_answers_on_a_single_key() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('y')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Instant)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn instant_mode_parses_keys_ignoring_case() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('N')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Instant)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(!ans);
+    }
+
+    #[test]
+    fn instant_mode_rejects_unparsable_keys() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('x'), KeyCode::Enter, KeyCode::Char('n')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Instant)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(!ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains(Confirm::DEFAULT_ERROR_MESSAGE));
+    }
+
+    #[test]
+    fn instant_mode_submits_the_default_on_enter() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Instant)
+            .with_default(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn instant_mode_keeps_custom_parsers() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('y'), KeyCode::Char('s')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Instant)
+            .with_parser(&|ans| match ans {
+                "s" => Ok(true),
+                "n" => Ok(false),
+                _ => Err(ParseError::default()),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn toggle_mode_starts_at_the_default() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let render_config = RenderConfig::empty()
+            .with_active_choice(StyleSheet::empty().with_attr(Attributes::BOLD));
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Toggle)
+            .with_default(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Yes\u{1b}[0m / No"));
+    }
+
+    #[test]
+    fn toggle_mode_flips_on_tab_and_space() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Tab, KeyCode::Char(' '), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Toggle)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(!ans);
+    }
+
+    #[test]
+    fn toggle_mode_moves_to_yes_with_left() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Left, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Toggle)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+    }
+
+    #[test]
+    fn toggle_mode_moves_with_answer_keys_and_right() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('y'), KeyCode::Right, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Toggle)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(!ans);
+    }
+
+    #[test]
+    fn toggle_mode_uses_the_formatter_as_labels() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('y'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let render_config = RenderConfig::empty()
+            .with_active_choice(StyleSheet::empty().with_attr(Attributes::BOLD));
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        let ans = Confirm::new("Proceed?")
+            .with_mode(ConfirmMode::Toggle)
+            .with_formatter(&|ans| match ans {
+                true => String::from("Sim"),
+                false => String::from("Não"),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Sim\u{1b}[0m / Não"));
+    }
+
+    #[test]
+    fn validators_apply_in_input_mode() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('n'),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Char('y'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Run the migrations?")
+            .with_validator(&|ans| match ans {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("The migrations are required".into())),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("The migrations are required"));
+    }
+
+    #[test]
+    fn validators_apply_in_instant_mode() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('n'), KeyCode::Char('y')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Run the migrations?")
+            .with_mode(ConfirmMode::Instant)
+            .with_validator(&|ans| match ans {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("The migrations are required".into())),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("The migrations are required"));
+    }
+
+    #[test]
+    fn validators_apply_in_toggle_mode() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter, KeyCode::Tab, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Run the migrations?")
+            .with_mode(ConfirmMode::Toggle)
+            .with_validator(&|ans| match ans {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("The migrations are required".into())),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("The migrations are required"));
+    }
+
+    #[test]
+    fn timeout_resolves_to_highlighted_value_in_toggle_mode() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Apply the migrations?")
+            .with_mode(ConfirmMode::Toggle)
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(!ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_default_value_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Confirm::new("Accept the terms?")
+            .with_default(false)
+            .with_validator(&|accepted: &bool| match accepted {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("The terms must be accepted".into())),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+}
@@ -1,36 +1,40 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::CustomTypeFormatter,
     input::Input,
-    parser::CustomTypeParser,
+    parser::{CustomTypeParser, ParseError},
     terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, Key, RenderConfig},
-    validator::ErrorMessage,
+    ui::{Backend, CustomTypeBackend, Key, PreviewPosition, RenderConfig},
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
 };
 
 /// Generic prompt suitable for when you need to parse the user input into a specific type, for example an `f64` or a `rust_decimal`, maybe even an `uuid`.
 ///
 /// This prompt has all of the validation, parsing and error handling features built-in to reduce as much boilerplaste as possible from your prompts. Its defaults are necessarily very simple in order to cover a large range of generic cases, for example a "Invalid input" error message.
 ///
-/// You can customize as many aspects of this prompt as you like: prompt message, help message, default value, placeholder, value parser and value formatter.
+/// You can customize as many aspects of this prompt as you like: prompt message, help message, default value, placeholder, value parser, value validators, value formatter and live preview.
 ///
 /// # Behavior
 ///
 /// When initializing this prompt via the `new()` method, some constraints on the return type `T` are added to make sure we can apply a default parser and formatter to the prompt.
 ///
-/// The default parser calls the [`str.parse`](https://doc.rust-lang.org/stable/std/primitive.str.html#method.parse) method, which means that `T` must implement the `FromStr` trait. When the parsing fails for any reason, a default error message "Invalid input" is displayed to the user.
+/// The default parser calls the [`str.parse`](https://doc.rust-lang.org/stable/std/primitive.str.html#method.parse) method, which means that `T` must implement the `FromStr` trait. When the parsing fails for any reason, the error message defined in `error_message`, "Invalid input" by default, is displayed to the user.
 ///
-/// After the user submits, the prompt handler tries to parse the input into the expected type. If the operation succeeds, the value is returned to the prompt caller. If it fails, the message defined in `error_message` is displayed to the user.
+/// After the user submits, the prompt handler tries to parse the input into the expected type. If the operation succeeds, the value is returned to the prompt caller. If it fails, the message of the error returned by the parser is displayed to the user, falling back to the message defined in `error_message`. The input is kept so that the user can fix it, with the cursor placed at the position of the error, if the parser returned one.
+///
+/// Once parsed, the value is checked by the validators, if any, in the order they were added. The first validation error is displayed to the user, who can then fix the input.
 ///
 /// The default formatter simply calls `to_string()` on the parsed value, which means that `T` must implement the `ToString` trait, which normally happens implicitly when you implement the `Display` trait.
 ///
+/// While the user types, a live preview of the parsed value can be displayed beside or below the input, formatted by the function set via `with_previewer`, e.g. `1.5k → 1,500`. When the input can not be parsed, a dimmed "invalid" hint is displayed instead, without blocking the user from typing.
+///
 /// If your type `T` does not satisfy these constraints, you can always manually instantiate the entire struct yourself like this:
 ///
 /// ```no_run
-/// use inquire::{CustomType, ui::RenderConfig};
+/// use inquire::{parser::ParseError, CustomType, ui::{PreviewPosition, RenderConfig}};
 ///
 /// let amount_prompt: CustomType<f64> = CustomType {
 ///     message: "How much is your travel going to cost?",
@@ -41,8 +45,12 @@ use crate::{
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
 ///     parser: &|i| match i.parse::<f64>() {
 ///         Ok(val) => Ok(val),
-///         Err(_) => Err(()),
+///         Err(_) => Err(ParseError::default()),
 ///     },
+///     validators: vec![],
+///     previewer: None,
+///     preview_position: PreviewPosition::Beside,
+///     timeout: None,
 ///     render_config: RenderConfig::default(),
 /// };
 /// ```
@@ -85,9 +93,30 @@ pub struct CustomType<'a, T> {
     /// Function that parses the user input and returns the result value.
     pub parser: CustomTypeParser<'a, T>,
 
-    /// Error message displayed when value could not be parsed from input.
+    /// Error message displayed when value could not be parsed from input
+    /// and the parser did not return a specific one.
     pub error_message: String,
 
+    /// Collection of validators to apply to the parsed value.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub validators: Vec<CustomTypeValidator<'a, T>>,
+
+    /// Function that formats the value parsed from the input while the user
+    /// types, displayed as a live preview. No preview is displayed when `None`.
+    pub previewer: Option<CustomTypeFormatter<'a, T>>,
+
+    /// Position of the live preview in relation to the input.
+    pub preview_position: PreviewPosition,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or fails with [`InquireError::Timeout`] when
+    /// there is none.
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -114,8 +143,12 @@ where
             placeholder: None,
             help_message: None,
             formatter: &|val| val.to_string(),
-            parser: &|a| a.parse::<T>().map_err(|_| ()),
+            parser: &|a| a.parse::<T>().map_err(|_| ParseError::default()),
             error_message: "Invalid input".into(),
+            validators: vec![],
+            previewer: None,
+            preview_position: PreviewPosition::Beside,
+            timeout: None,
             render_config: get_configuration(),
         }
     }
@@ -150,12 +183,68 @@ where
         self
     }
 
-    /// Sets a custom error message displayed when a submission could not be parsed to a value.
+    /// Sets a custom error message displayed when a submission could not be parsed to a value
+    /// and the parser did not return a specific one.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
         self
     }
 
+    /// Adds a validator to the collection of validators. You might want to use this feature
+    /// in case you need to require certain features from the parsed value, such as
+    /// defining a range of accepted numbers.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validator(mut self, validator: CustomTypeValidator<'a, T>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Adds the validators to the collection of validators in the order they are given.
+    /// You might want to use this feature in case you need to require certain features
+    /// from the parsed value, such as defining a range of accepted numbers.
+    ///
+    /// Validators are executed in the order they are stored, stopping at and displaying to the user
+    /// only the first validation error that might appear.
+    ///
+    /// The possible error is displayed to the user one line above the prompt.
+    pub fn with_validators(mut self, validators: &[CustomTypeValidator<'a, T>]) -> Self {
+        for validator in validators {
+            self.validators.push(*validator);
+        }
+        self
+    }
+
+    /// Sets the function that formats the value parsed from the input while the
+    /// user types, displayed as a live preview, e.g. `2h30m → 2 hours 30 minutes`.
+    ///
+    /// When the input can not be parsed, a dimmed "invalid" hint is displayed instead.
+    pub fn with_previewer(mut self, previewer: CustomTypeFormatter<'a, T>) -> Self {
+        self.previewer = Some(previewer);
+        self
+    }
+
+    /// Sets the position of the live preview, either beside the input, the
+    /// default, or on the line below it.
+    pub fn with_preview_position(mut self, preview_position: PreviewPosition) -> Self {
+        self.preview_position = preview_position;
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default value. When it has none, or when the validators
+    /// reject it, the prompt fails with [`InquireError::Timeout`] instead. The
+    /// remaining time is displayed in the help line until the first key is
+    /// pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -198,6 +287,10 @@ where
         self,
         backend: &mut B,
     ) -> InquireResult<T> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
         CustomTypePrompt::from(self).prompt(backend)
     }
 }
@@ -210,6 +303,9 @@ struct CustomTypePrompt<'a, T> {
     input: Input,
     formatter: CustomTypeFormatter<'a, T>,
     parser: CustomTypeParser<'a, T>,
+    validators: Vec<CustomTypeValidator<'a, T>>,
+    previewer: Option<CustomTypeFormatter<'a, T>>,
+    preview_position: PreviewPosition,
     error_message: String,
 }
 
@@ -229,6 +325,9 @@ where
                 .placeholder
                 .map(|p| Input::new().with_placeholder(p))
                 .unwrap_or_else(Input::new),
+            validators: co.validators,
+            previewer: co.previewer,
+            preview_position: co.preview_position,
             error_message: co.error_message,
         }
     }
@@ -242,7 +341,19 @@ where
         self.input.handle_key(key);
     }
 
-    fn get_final_answer(&self) -> Result<T, String> {
+    fn validate_current_answer(&self, answer: &T) -> InquireResult<Validation> {
+        for validator in &self.validators {
+            match validator(answer) {
+                Ok(Validation::Valid) => {}
+                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
+                Err(err) => return Err(InquireError::Custom(err)),
+            }
+        }
+
+        Ok(Validation::Valid)
+    }
+
+    fn get_final_answer(&self) -> Result<T, ParseError> {
         match &self.default {
             Some((val, _)) if self.input.content().is_empty() => return Ok(val.clone()),
             _ => {}
@@ -250,7 +361,7 @@ where
 
         match (self.parser)(self.input.content()) {
             Ok(val) => Ok(val),
-            Err(_) => Err(self.error_message.clone()),
+            Err(err) => Err(err),
         }
     }
 
@@ -268,7 +379,20 @@ where
             .as_ref()
             .map(|(val, formatter)| formatter(val.clone()));
 
-        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+        match self.previewer {
+            Some(previewer) if !self.input.is_empty() => {
+                let preview = (self.parser)(self.input.content()).ok().map(previewer);
+
+                backend.render_prompt_with_preview(
+                    prompt,
+                    default_message.as_deref(),
+                    &self.input,
+                    preview.as_deref(),
+                    self.preview_position,
+                )?;
+            }
+            _ => backend.render_prompt(prompt, default_message.as_deref(), &self.input)?,
+        }
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
@@ -290,14 +414,31 @@ where
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match &self.default {
+                    Some((default, _)) => match self.validate_current_answer(default)? {
+                        Validation::Valid => {
+                            final_answer = default.clone();
+                            break;
+                        }
+                        Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                    },
+                    None => timeout_prompt!(backend, self.message),
+                },
                 Key::Submit => match self.get_final_answer() {
-                    Ok(answer) => {
-                        final_answer = answer;
-                        break;
-                    }
-                    Err(message) => {
-                        self.error = Some(message.into());
-                        self.input.clear();
+                    Ok(answer) => match self.validate_current_answer(&answer)? {
+                        Validation::Valid => {
+                            final_answer = answer;
+                            break;
+                        }
+                        Validation::Invalid(msg) => self.error = Some(msg),
+                    },
+                    Err(err) => {
+                        self.error = Some(err.message_or(&self.error_message));
+
+                        if let Some(position) = err.position {
+                            let cursor = position.min(self.input.length());
+                            self.input = self.input.clone().with_cursor(cursor);
+                        }
                     }
                 },
                 key => self.on_change(key),
@@ -309,3 +450,252 @@ where
         finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        parser::ParseError,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, PreviewPosition, RenderConfig},
+        validator::Validation,
+        CustomType,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
+
+    #[test]
+    fn validators_receive_the_parsed_value() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('8'),
+            KeyCode::Char('0'),
+            KeyCode::Enter,
+            KeyCode::Char('8'),
+            KeyCode::Char('0'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Port:")
+            .with_validator(&|port| match *port >= 1024 {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("Ports below 1024 are reserved".into())),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(8080, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Ports below 1024 are reserved"));
+    }
+
+    #[test]
+    fn parsing_errors_are_displayed_before_validation() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('x'),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Char('2'),
+            KeyCode::Char('0'),
+            KeyCode::Char('4'),
+            KeyCode::Char('8'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Port:")
+            .with_validator(&|port| match *port >= 1024 {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("Ports below 1024 are reserved".into())),
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(2048, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Invalid input"));
+        assert!(!output.contains("Ports below 1024 are reserved"));
+    }
+
+    #[test]
+    fn parser_errors_keep_the_input_and_move_the_cursor() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('8'),
+            KeyCode::Char('x'),
+            KeyCode::Char('0'),
+            KeyCode::Enter,
+            KeyCode::Delete,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let parser = |input: &str| match input.find(|c: char| !c.is_ascii_digit()) {
+            Some(i) => {
+                let position = input[..i].chars().count();
+                Err(ParseError::new("expected only digits").with_position(position))
+            }
+            None => input.parse().map_err(|_| ParseError::default()),
+        };
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Port:")
+            .with_parser(&parser)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(80, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("expected only digits"));
+        assert!(!output.contains("Invalid input"));
+    }
+
+    #[test]
+    fn validator_errors_are_returned() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('1'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Port:")
+            .with_validator(&|_| Err("port registry unavailable".into()))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Custom(_))));
+    }
+
+    #[test]
+    fn previewer_displays_the_parsed_value_beside_the_input() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('2'), KeyCode::Char('k'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Amount:")
+            .with_parser(&|input| match input.strip_suffix('k') {
+                Some(thousands) => thousands
+                    .parse::<u16>()
+                    .map(|n| n * 1000)
+                    .map_err(|_| ParseError::default()),
+                None => input.parse().map_err(|_| ParseError::default()),
+            })
+            .with_previewer(&|amount| format!("{} units", amount))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(2000, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("2k  → 2000 units"));
+    }
+
+    #[test]
+    fn previewer_displays_a_hint_for_invalid_inputs() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('x'),
+            KeyCode::Backspace,
+            KeyCode::Char('5'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Amount:")
+            .with_parser(&|input| input.parse().map_err(|_| ParseError::default()))
+            .with_previewer(&|amount| format!("{} units", amount))
+            .with_preview_position(PreviewPosition::Below)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(5, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("x \r\n  → invalid"));
+        assert!(output.contains("5 \r\n  → 5 units"));
+    }
+
+    #[test]
+    fn timeout_resolves_to_default_value() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Port")
+            .with_default((8080, &|port| port.to_string()))
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(8080, ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_default_value_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = CustomType::<u16>::new("Port")
+            .with_default((80, &|port| port.to_string()))
+            .with_validator(&|port: &u16| match *port < 1024 {
+                true => Ok(Validation::Invalid(
+                    "Privileged ports are not allowed".into(),
+                )),
+                false => Ok(Validation::Valid),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+}
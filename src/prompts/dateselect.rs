@@ -10,7 +10,7 @@ use crate::{
     error::{InquireError, InquireResult},
     formatter::{self, DateFormatter},
     terminal::{get_default_terminal, Terminal},
-    ui::{date::{DateSelectBackend, Accessor}, Backend, Key, KeyModifiers, RenderConfig},
+    ui::{date::{DateSelectBackend, Accessor}, Backend, CommonBackend, Key, KeyModifiers, RenderConfig},
     validator::{DateValidator, ErrorMessage, Validation},
 };
 
@@ -90,6 +90,10 @@ pub struct DateSelect<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<DateValidator<'a>>,
 
+    /// Time to wait for the user to press a key before the prompt fails with
+    /// [`InquireError::Timeout`].
+    pub timeout: Option<std::time::Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -140,6 +144,7 @@ impl<'a> DateSelect<'a> {
             validators: Self::DEFAULT_VALIDATORS,
 			accessor: None,
             week_start: Self::DEFAULT_WEEK_START,
+            timeout: None,
             render_config: get_configuration(),
         }
     }
@@ -202,8 +207,7 @@ impl<'a> DateSelect<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[DateValidator<'a>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
-            self.validators.push(validator.clone());
+            self.validators.push(*validator);
         }
         self
     }
@@ -226,6 +230,17 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default date, today unless set otherwise. When the
+    /// validators reject it, the prompt fails with [`InquireError::Timeout`]
+    /// instead. The remaining time is displayed in the help line until the
+    /// first key is pressed.
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -268,6 +283,10 @@ impl<'a> DateSelect<'a> {
         self,
         backend: &mut Backend<T>,
     ) -> InquireResult<NaiveDate> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
         DateSelectPrompt::new(self)?.prompt(backend)
     }
 }
@@ -442,6 +461,13 @@ impl<'a> DateSelectPrompt<'a> {
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match self.validate_current_answer()? {
+                    Validation::Valid => {
+                        final_answer = self.cur_answer();
+                        break;
+                    }
+                    Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                },
                 Key::Submit | Key::Char(' ', _) => match self.validate_current_answer()? {
                     Validation::Valid => {
                         final_answer = self.cur_answer();
@@ -466,13 +492,15 @@ impl<'a> DateSelectPrompt<'a> {
 mod test {
     use crate::{
         date_utils::get_current_date,
+        error::InquireError,
         terminal::crossterm::CrosstermTerminal,
         ui::{Backend, RenderConfig},
         validator::Validation,
         DateSelect,
     };
-    use chrono::NaiveDate;
+    use chrono::{Datelike, NaiveDate, Weekday};
     use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
 
     fn default<'a>() -> DateSelect<'a> {
         DateSelect::new("Question?")
@@ -540,4 +568,43 @@ mod test {
 
         assert_eq!(today_date.pred(), ans);
     }
+
+    #[test]
+    fn timeout_resolves_to_default_date() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = DateSelect::new("Question")
+            .with_default(NaiveDate::from_ymd(2021, 1, 9))
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(NaiveDate::from_ymd(2021, 1, 9), ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_default_date_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = DateSelect::new("Question")
+            .with_default(NaiveDate::from_ymd(2021, 1, 9))
+            .with_validator(&|d: NaiveDate| match d.weekday() {
+                Weekday::Sat | Weekday::Sun => Ok(Validation::Invalid("Pick a weekday".into())),
+                _ => Ok(Validation::Valid),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
 }
@@ -5,6 +5,7 @@ use std::{
     io::{Read, Write},
     path::Path,
     process,
+    time::Duration,
 };
 
 use lazy_static::lazy_static;
@@ -77,6 +78,10 @@ pub struct Editor<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<StringValidator<'a>>,
 
+    /// Time to wait for the user to press a key before the prompt fails with
+    /// [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -109,6 +114,7 @@ impl<'a> Editor<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
+            timeout: None,
             render_config: RenderConfig::default(),
         }
     }
@@ -172,12 +178,22 @@ impl<'a> Editor<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[StringValidator<'a>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
-            self.validators.push(validator.clone());
+            self.validators.push(*validator);
         }
         self
     }
 
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting the predefined text. When there is none, or when the
+    /// validators reject it, the prompt fails with [`InquireError::Timeout`]
+    /// instead. The remaining time is displayed in the help line until the
+    /// first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -220,6 +236,10 @@ impl<'a> Editor<'a> {
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
         EditorPrompt::new(self)?.prompt(backend)
     }
 }
@@ -231,6 +251,7 @@ struct EditorPrompt<'a> {
     help_message: Option<&'a str>,
     formatter: StringFormatter<'a>,
     validators: Vec<StringValidator<'a>>,
+    predefined_text: Option<&'a str>,
     error: Option<ErrorMessage>,
     tmp_file: NamedTempFile,
 }
@@ -250,6 +271,7 @@ impl<'a> EditorPrompt<'a> {
             help_message: so.help_message,
             formatter: so.formatter,
             validators: so.validators,
+            predefined_text: so.predefined_text,
             error: None,
             tmp_file: Self::create_file(so.file_extension, so.predefined_text)?,
         })
@@ -342,6 +364,13 @@ impl<'a> EditorPrompt<'a> {
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout if self.predefined_text.is_none() => {
+                    timeout_prompt!(backend, self.message)
+                }
+                Key::Timeout => match self.validate_current_answer()? {
+                    Validation::Valid => break self.cur_answer()?,
+                    Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                },
                 Key::Char('e', _) => self.run_editor()?,
                 Key::Submit => match self.validate_current_answer()? {
                     Validation::Valid => break self.cur_answer()?,
@@ -0,0 +1,629 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    type_aliases::OptionHotkey,
+    ui::{Backend, ExpandBackend, Key, KeyModifiers, RenderConfig},
+    utils::same_hotkey,
+    validator::ErrorMessage,
+};
+
+/// Prompt suitable for when the user needs to pick one of a few options by
+/// pressing a single key, in the style of `Overwrite? (y,N,a,q,?)`.
+///
+/// Each option is assigned a hotkey, and pressing it answers the prompt right
+/// away. The hotkeys are displayed after the prompt message, similarly to the
+/// default value of [`Confirm`](crate::Confirm) prompts, with the hotkey of the
+/// default option in uppercase. Pressing `?` expands the list of options along
+/// with their hotkeys, and pressing enter submits the default option, if any.
+///
+/// Hotkeys are matched ignoring case, just like the default `bool` parser of
+/// [`Confirm`](crate::Confirm) prompts. If the user presses any other key, an
+/// error message is displayed.
+///
+/// This prompt provides several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options to choose from. Must be **non-empty**.
+/// - **Hotkeys**: Function that assigns the hotkey of each option.
+///   - By default, the first character of the string value of each option, in lowercase.
+///   - If an option has no hotkey, if a hotkey is assigned to several options or if `?` is assigned to an option, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Default option**: Index of the option submitted when the user presses enter. If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected option string value by default.
+/// - **Error message**: Error message to display when the user presses a key not assigned to any option.
+///   - Set to "Invalid answer, press one of the listed keys or '?' for help" by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Expand;
+///
+/// let options = vec!["yes", "no", "all", "quit"];
+///
+/// let ans = Expand::new("Overwrite config.toml?", options)
+///     .with_default(1)
+///     .prompt();
+///
+/// match ans {
+///     Ok(choice) => println!("You chose {}", choice),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct Expand<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Function called with each option to get its hotkey.
+    pub hotkey: OptionHotkey<'a, T>,
+
+    /// Index of the option submitted when the user presses enter.
+    pub default: Option<usize>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: OptionFormatter<'a, T>,
+
+    /// Error message displayed when the user presses a key not assigned to any option.
+    pub error_message: String,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or fails with [`InquireError::Timeout`] when
+    /// there is none.
+    pub timeout: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> Expand<'a, T>
+where
+    T: Display,
+{
+    /// Default formatter, set to print the selected option string value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::Expand;
+    ///
+    /// let formatter = Expand::<&str>::DEFAULT_FORMATTER;
+    /// assert_eq!(String::from("yes"), formatter(ListOption::new(0, &"yes")));
+    /// ```
+    pub const DEFAULT_FORMATTER: OptionFormatter<'a, T> = &|ans| ans.to_string();
+
+    /// Default hotkey assigner, set to the first character of the string value
+    /// of the option, in lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Expand;
+    ///
+    /// let hotkey = Expand::<&str>::DEFAULT_HOTKEY;
+    /// assert_eq!(Some('o'), hotkey(&"Overwrite", 0));
+    /// assert_eq!(None, hotkey(&"", 1));
+    /// ```
+    pub const DEFAULT_HOTKEY: OptionHotkey<'a, T> =
+        &|opt, _| opt.to_string().chars().flat_map(char::to_lowercase).next();
+
+    /// Key that expands the list of options.
+    pub const HELP_KEY: char = '?';
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
+
+    /// Default error message displayed when the pressed key is not assigned to any option.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str =
+        "Invalid answer, press one of the listed keys or '?' for help";
+
+    /// Creates a [Expand] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            hotkey: Self::DEFAULT_HOTKEY,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: Self::DEFAULT_FORMATTER,
+            error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            timeout: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the function that assigns hotkeys to options.
+    pub fn with_hotkeys(mut self, hotkey: OptionHotkey<'a, T>) -> Self {
+        self.hotkey = hotkey;
+        self
+    }
+
+    /// Sets the index of the option submitted when the user presses enter.
+    pub fn with_default(mut self, default: usize) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets a custom error message displayed when the pressed key is not assigned to any option.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default option. When it has none, the prompt fails with
+    /// [`InquireError::Timeout`] instead. The remaining time is displayed in
+    /// the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(in crate) fn prompt_with_backend<B: ExpandBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        ExpandPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct ExpandPrompt<'a, T> {
+    message: &'a str,
+    options: Vec<T>,
+    hotkeys: Vec<char>,
+    default: Option<usize>,
+    help_message: Option<&'a str>,
+    expanded: bool,
+    formatter: OptionFormatter<'a, T>,
+    error_message: String,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> ExpandPrompt<'a, T>
+where
+    T: Display,
+{
+    fn new(eo: Expand<'a, T>) -> InquireResult<Self> {
+        if eo.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if let Some(default) = eo.default {
+            if default >= eo.options.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Default option index {} is out-of-bounds for length {} of options",
+                    default,
+                    &eo.options.len()
+                )));
+            }
+        }
+
+        let mut hotkeys: Vec<char> = Vec::with_capacity(eo.options.len());
+
+        for (i, opt) in eo.options.iter().enumerate() {
+            let key = match (eo.hotkey)(opt, i) {
+                Some(key) => key,
+                None => {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Option {} has no hotkey",
+                        i
+                    )))
+                }
+            };
+
+            if key == Expand::<T>::HELP_KEY {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Hotkey '{}' of option {} conflicts with the help key",
+                    key, i
+                )));
+            }
+
+            if let Some(other) = hotkeys.iter().position(|k| same_hotkey(*k, key)) {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Hotkey '{}' is assigned to both options {} and {}",
+                    key, other, i
+                )));
+            }
+
+            hotkeys.push(key);
+        }
+
+        Ok(Self {
+            message: eo.message,
+            options: eo.options,
+            hotkeys,
+            default: eo.default,
+            help_message: eo.help_message,
+            expanded: false,
+            formatter: eo.formatter,
+            error_message: eo.error_message,
+            error: None,
+        })
+    }
+
+    /// Builds the hint displayed after the prompt message, listing all
+    /// hotkeys with the one of the default option in uppercase.
+    fn hint(&self) -> String {
+        self.hotkeys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| match self.default {
+                Some(default) if default == i => key.to_uppercase().collect(),
+                _ => key.to_string(),
+            })
+            .chain(std::iter::once(Expand::<T>::HELP_KEY.to_string()))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /// Handles a key press, returning the index of the chosen option once the
+    /// prompt is answered.
+    fn on_change(&mut self, key: Key) -> Option<usize> {
+        match key {
+            Key::Submit => match self.default {
+                Some(default) => return Some(default),
+                None => self.error = Some(self.error_message.clone().into()),
+            },
+            Key::Char(c, m) if !m.contains(KeyModifiers::CONTROL) => {
+                if c == Expand::<T>::HELP_KEY {
+                    self.expanded = !self.expanded;
+                    self.error = None;
+                } else {
+                    match self.hotkeys.iter().position(|k| same_hotkey(*k, c)) {
+                        Some(idx) => return Some(idx),
+                        None => self.error = Some(self.error_message.clone().into()),
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn render<B: ExpandBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_expand_prompt(prompt, &self.hint())?;
+
+        if self.expanded {
+            let options = self
+                .options
+                .iter()
+                .enumerate()
+                .map(|(i, opt)| ListOption::new(i, opt))
+                .collect::<Vec<ListOption<&T>>>();
+
+            backend.render_expand_options(&options, &self.hotkeys)?;
+        }
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: ExpandBackend>(mut self, backend: &mut B) -> InquireResult<ListOption<T>> {
+        let final_answer: usize;
+
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match self.default {
+                    Some(default) => {
+                        final_answer = default;
+                        break;
+                    }
+                    None => timeout_prompt!(backend, self.message),
+                },
+                key => {
+                    if let Some(idx) = self.on_change(key) {
+                        final_answer = idx;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let value = self.options.swap_remove(final_answer);
+        let final_answer = ListOption::new(final_answer, value);
+        let formatted = (self.formatter)(final_answer.as_ref());
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        list_option::ListOption,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+        Expand,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    #[test]
+    fn hotkey_answers_right_away() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('a')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(2, "all"), ans);
+    }
+
+    #[test]
+    fn hotkeys_ignore_case() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('Q')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(3, "quit"), ans);
+    }
+
+    #[test]
+    fn uppercase_hotkeys_match_lowercase_keys() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('a')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .with_hotkeys(&|opt, _| opt.chars().flat_map(char::to_uppercase).next())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(2, "all"), ans);
+    }
+
+    #[test]
+    fn enter_submits_the_default_option() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .with_default(1)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, "no"), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Overwrite? (y,N,a,q,?)"));
+    }
+
+    #[test]
+    fn unknown_keys_display_an_error() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('x'), KeyCode::Enter, KeyCode::Char('y')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(0, "yes"), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains(Expand::<&str>::DEFAULT_ERROR_MESSAGE));
+    }
+
+    #[test]
+    fn help_key_expands_the_options() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('?'), KeyCode::Char('n')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("  y) yes\r\n  n) no\r\n  a) all\r\n  q) quit\r\n"));
+    }
+
+    #[test]
+    fn conflicting_hotkeys_are_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "Yank"]).prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn help_key_can_not_be_a_hotkey() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", "no", "all", "quit"])
+            .with_hotkeys(&|opt, i| match i {
+                0 => Some('?'),
+                _ => opt.chars().next(),
+            })
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn options_without_hotkeys_are_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Expand::new("Overwrite?", vec!["yes", ""]).prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+}
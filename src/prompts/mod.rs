@@ -7,18 +7,30 @@ mod custom_type;
 mod dateselect;
 #[cfg(feature = "editor")]
 mod editor;
+mod expand;
 mod multiselect;
+mod number;
 mod password;
+mod quantity_select;
+mod reorder;
 mod select;
 mod text;
+mod tree_multiselect;
+mod tree_select;
 
-pub use confirm::Confirm;
+pub use confirm::{Confirm, ConfirmMode};
 pub use custom_type::CustomType;
 #[cfg(feature = "date")]
 pub use dateselect::DateSelect;
 #[cfg(feature = "editor")]
 pub use editor::Editor;
+pub use expand::Expand;
 pub use multiselect::MultiSelect;
-pub use password::{Password, PasswordDisplayMode};
+pub use number::{Number, Numeric};
+pub use password::{Password, PasswordConfirmation, PasswordDisplayMode};
+pub use quantity_select::QuantitySelect;
+pub use reorder::Reorder;
 pub use select::Select;
 pub use text::Text;
+pub use tree_multiselect::TreeMultiSelect;
+pub use tree_select::TreeSelect;
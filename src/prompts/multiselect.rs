@@ -1,21 +1,33 @@
-use std::{collections::BTreeSet, fmt::Display};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     config::{self, get_configuration},
     error::{InquireError, InquireResult},
     formatter::MultiOptionFormatter,
     input::Input,
-    list_option::ListOption,
+    list_option::{ListOption, NumericJump, OptionGroup},
+    option_source::{OptionSource, REFRESH_INTERVAL},
     terminal::get_default_terminal,
-    type_aliases::Filter,
-    ui::{Backend, Key, KeyModifiers, MultiSelectBackend, RenderConfig},
-    utils::paginate,
+    type_aliases::{Filter, OptionDisabler, Previewer},
+    ui::{
+        Backend, Key, KeyModifiers, MultiSelectBackend, OptionLayout, Preview, PreviewPosition,
+        RenderConfig,
+    },
+    utils::{
+        append_group_rows, closest_selectable, group_rows, is_enabled, paginate_grid,
+        paginate_preview, paginate_with_headers, IndexJump, OptionRow, Page, RowIndex, Selection,
+    },
     validator::{ErrorMessage, MultiOptionValidator, Validation},
 };
 
 /// Prompt suitable for when you need the user to select many options (including none if applicable) among a list of them.
 ///
-/// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
+/// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow. Selecting and cleaning all options only applies to the ones matching the current filter, as does inverting their selection by pressing ctrl+t. Holding shift while moving up or down selects a contiguous range of options, and pressing tab toggles whether only the selected options are displayed.
 ///
 /// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
@@ -33,10 +45,18 @@ use crate::{
 ///   - Prints the selected options string value, joined using a comma as the separator, by default.
 /// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements, e.g. not allowing 0 selected options or limiting the number of options that the user is allowed to select.
 ///   - No validators are on by default.
+/// - **Selection order**: Whether the options are returned in the order the user selected them, instead of their order in the list. When enabled, selected options are displayed with their position in the selection, e.g. `[1]` and `[2]`, instead of the selected checkbox. Default selections are considered selected in the order they are provided. Disabled by default.
+/// - **Selection limits**: Minimum and maximum number of selected options. Selecting options beyond the maximum is blocked, including when selecting all of them, and the answer can not be submitted until the minimum is met, both cases displaying an error message. While any limit is set, a counter of selected options is displayed after the prompt message. If the minimum is greater than the maximum, or the default selections exceed the maximum, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
+/// - **Layout**: Options are displayed one per row by default, or flowing into as many columns as fit the terminal width with the grid layout, suitable for short options. In grids, the left and right arrows move between columns instead of selecting none or all of the options, and the page size counts lines of the grid. Option groups are not supported in grids, failing with an [`InquireError::InvalidConfiguration`] error.
+/// - **Numeric jump**: Digits typed by the user can move the cursor to the option displayed with that index in the current filtered list, instead of being part of the filter input. Digits typed in quick succession form multi-digit indexes, and the option can optionally be toggled as soon as typing more digits could not lead to another one. Disabled by default.
 /// - **Filter function**: Function that defines if an option is displayed or not based on the current filter input.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+/// - **Disabled options**: Function that defines if an option is disabled, along with the reason why it can not be selected. Disabled options are still displayed, using the disabled style of the `RenderConfig` followed by their reason, but they can not be highlighted nor toggled. Their selection state, possibly set by the default selections, is kept as is.
+/// - **Preview**: Function that builds a multi-line preview of the highlighted option, displayed below or beside the option list within the configured height, 10 lines by default. The user can scroll the preview by pressing ctrl+up and ctrl+down.
+/// - **Option source**: Source from which options are loaded on demand, as the user scrolls or filters the list, instead of being all provided upfront. Useful for very large lists or for options received over time. While the source is not exhausted, the number of options loaded so far is displayed below the list. Selecting all options only selects the ones loaded so far.
+/// - **Option groups**: Groups of consecutive options, displayed below a header with the group title or below a separator. Toggling a header selects (or deselects) all of the group's options displayed under the current filter, and groups with no options matching the current filter are hidden. If any of the groups is out-of-range of the option list or overlaps another one, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
 ///
 /// # Example
 ///
@@ -51,6 +71,9 @@ pub struct MultiSelect<'a, T> {
     /// Options displayed to the user.
     pub options: Vec<T>,
 
+    /// Source of additional options, loaded on demand after the ones in `options`.
+    pub source: Option<OptionSource<'a, T>>,
+
     /// Default indexes of options to be selected from the start.
     pub default: Option<&'a [usize]>,
 
@@ -64,16 +87,47 @@ pub struct MultiSelect<'a, T> {
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
 
+    /// Behavior of the digits typed by the user.
+    pub numeric_jump: NumericJump,
+
+    /// Layout of the displayed options.
+    pub layout: OptionLayout,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Groups of options, displayed below a header or a separator.
+    pub groups: Vec<OptionGroup<'a>>,
+
     /// Function called with the current user input to filter the provided
     /// options.
     pub filter: Filter<'a, T>,
 
+    /// Function called with each option to check whether it is disabled,
+    /// returning the reason why it can not be selected.
+    pub disabler: Option<OptionDisabler<'a, T>>,
+
+    /// Function called with the highlighted option to build its preview.
+    pub previewer: Option<Previewer<'a, T>>,
+
+    /// Maximum number of preview lines displayed at once.
+    pub preview_height: usize,
+
+    /// Position of the preview in relation to the option list.
+    pub preview_position: PreviewPosition,
+
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
+    /// Whether the options are returned in the order they were selected.
+    pub selection_order: bool,
+
+    /// Minimum number of selected options required to submit the answer.
+    pub min_selections: Option<usize>,
+
+    /// Maximum number of options that can be selected.
+    pub max_selections: Option<usize>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
@@ -82,6 +136,12 @@ pub struct MultiSelect<'a, T> {
     /// In case of error, the message is displayed one line above the prompt.
     pub validator: Option<MultiOptionValidator<'a, T>>,
 
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or with no options selected when there is none.
+    /// If the validator rejects that selection, the prompt fails with
+    /// [`InquireError::Timeout`] instead.
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -159,12 +219,28 @@ where
     /// Default value of vim mode, equal to the global default value [config::DEFAULT_PAGE_SIZE]
     pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
 
+    /// Default behavior of typed digits, which are part of the filter input.
+    pub const DEFAULT_NUMERIC_JUMP: NumericJump = NumericJump::Disabled;
+
+    /// Default layout, displaying one option per row.
+    pub const DEFAULT_LAYOUT: OptionLayout = OptionLayout::List;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
+    /// Default maximum number of preview lines displayed at once.
+    pub const DEFAULT_PREVIEW_HEIGHT: usize = 10;
+
+    /// Default position of the preview.
+    pub const DEFAULT_PREVIEW_POSITION: PreviewPosition = PreviewPosition::Below;
+
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default value of whether the options are returned in the order they
+    /// were selected.
+    pub const DEFAULT_SELECTION_ORDER: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
@@ -174,19 +250,44 @@ where
         Self {
             message,
             options,
+            source: None,
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
             vim_mode: Self::DEFAULT_VIM_MODE,
+            numeric_jump: Self::DEFAULT_NUMERIC_JUMP,
+            layout: Self::DEFAULT_LAYOUT,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            groups: Vec::new(),
             keep_filter: Self::DEFAULT_KEEP_FILTER,
+            selection_order: Self::DEFAULT_SELECTION_ORDER,
+            min_selections: None,
+            max_selections: None,
             filter: Self::DEFAULT_FILTER,
+            disabler: None,
+            previewer: None,
+            preview_height: Self::DEFAULT_PREVIEW_HEIGHT,
+            preview_position: Self::DEFAULT_PREVIEW_POSITION,
             formatter: Self::DEFAULT_FORMATTER,
             validator: None,
+            timeout: None,
             render_config: get_configuration(),
         }
     }
 
+    /// Creates a [MultiSelect] with the provided message, loading its options on demand
+    /// from the given source, along with default configuration values.
+    pub fn from_source(message: &'a str, source: OptionSource<'a, T>) -> Self {
+        Self::new(message, Vec::new()).with_source(source)
+    }
+
+    /// Sets the source from which options are loaded on demand, after the
+    /// options provided in the constructor.
+    pub fn with_source(mut self, source: OptionSource<'a, T>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
     /// Sets the help message of the prompt.
     pub fn with_help_message(mut self, message: &'a str) -> Self {
         self.help_message = Some(message);
@@ -211,6 +312,19 @@ where
         self
     }
 
+    /// Sets the layout of the displayed options.
+    pub fn with_layout(mut self, layout: OptionLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the behavior of the digits typed by the user, which can move the
+    /// cursor to the option displayed with the typed index.
+    pub fn with_numeric_jump(mut self, numeric_jump: NumericJump) -> Self {
+        self.numeric_jump = numeric_jump;
+        self
+    }
+
     /// Sets the keep filter behavior.
     pub fn with_keep_filter(mut self, keep_filter: bool) -> Self {
         self.keep_filter = keep_filter;
@@ -223,6 +337,33 @@ where
         self
     }
 
+    /// Sets the function that defines which options are disabled.
+    ///
+    /// Disabled options are displayed along with the returned reason, but
+    /// can not be highlighted nor toggled.
+    pub fn with_disabler(mut self, disabler: OptionDisabler<'a, T>) -> Self {
+        self.disabler = Some(disabler);
+        self
+    }
+
+    /// Sets the function that builds the preview of the highlighted option.
+    pub fn with_previewer(mut self, previewer: Previewer<'a, T>) -> Self {
+        self.previewer = Some(previewer);
+        self
+    }
+
+    /// Sets the maximum number of preview lines displayed at once.
+    pub fn with_preview_height(mut self, preview_height: usize) -> Self {
+        self.preview_height = preview_height;
+        self
+    }
+
+    /// Sets the position of the preview in relation to the option list.
+    pub fn with_preview_position(mut self, preview_position: PreviewPosition) -> Self {
+        self.preview_position = preview_position;
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
@@ -239,7 +380,39 @@ where
         self
     }
 
+    /// Sets whether the options are returned in the order they were selected,
+    /// instead of their order in the list.
+    ///
+    /// When enabled, selected options display their position in the selection,
+    /// e.g. `[1]`, instead of the selected checkbox.
+    pub fn with_selection_order(mut self, selection_order: bool) -> Self {
+        self.selection_order = selection_order;
+        self
+    }
+
+    /// Sets the minimum number of selected options required to submit the answer.
+    ///
+    /// Until it is met, submitting displays an error message instead.
+    pub fn with_min_selections(mut self, min_selections: usize) -> Self {
+        self.min_selections = Some(min_selections);
+        self
+    }
+
+    /// Sets the maximum number of options that can be selected.
+    ///
+    /// Selecting more options, one by one or all at once, is blocked with an
+    /// error message.
+    pub fn with_max_selections(mut self, max_selections: usize) -> Self {
+        self.max_selections = Some(max_selections);
+        self
+    }
+
     /// Sets the indexes to be selected by the default.
+    ///
+    /// With option sources, indexes of options that were not loaded yet are
+    /// left out of the answer if the user submits before they are loaded, and
+    /// the prompt fails with [`InquireError::InvalidConfiguration`] if the
+    /// source is exhausted without providing them.
     pub fn with_default(mut self, default: &'a [usize]) -> Self {
         self.default = Some(default);
         self
@@ -251,6 +424,26 @@ where
         self
     }
 
+    /// Sets the groups of options, displayed below a header containing their
+    /// title or below a separator when they have no title.
+    ///
+    /// Options not contained in any group are displayed without a header.
+    pub fn with_groups(mut self, groups: Vec<OptionGroup<'a>>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default selection or no options when it has none. When
+    /// the validator rejects it, the prompt fails with
+    /// [`InquireError::Timeout`] instead. The remaining time is displayed in
+    /// the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -327,6 +520,10 @@ where
         self,
         backend: &mut B,
     ) -> InquireResult<Vec<ListOption<T>>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
         MultiSelectPrompt::new(self)?.prompt(backend)
     }
 }
@@ -334,17 +531,37 @@ where
 struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     options: Vec<T>,
+    source: Option<OptionSource<'a, T>>,
     string_options: Vec<String>,
     help_message: Option<&'a str>,
     vim_mode: bool,
+    numeric_jump: NumericJump,
+    index_jump: IndexJump,
+    layout: OptionLayout,
+    columns: usize,
+    value_width: usize,
     cursor_index: usize,
-    checked: BTreeSet<usize>,
+    checked: Selection,
+    checked_only: bool,
+    range_anchor: Option<usize>,
+    range_checked: Vec<usize>,
     page_size: usize,
     keep_filter: bool,
+    min_selections: Option<usize>,
+    max_selections: Option<usize>,
     input: Input,
     filtered_options: Vec<usize>,
+    disabler: Option<OptionDisabler<'a, T>>,
+    disabled: Vec<Option<String>>,
+    groups: Vec<OptionGroup<'a>>,
+    rows: Vec<RowIndex>,
     filter: Filter<'a, T>,
     formatter: MultiOptionFormatter<'a, T>,
+    previewer: Option<Previewer<'a, T>>,
+    preview_height: usize,
+    preview_position: PreviewPosition,
+    preview: Option<(usize, Preview)>,
+    preview_offset: usize,
     validator: Option<MultiOptionValidator<'a, T>>,
     error: Option<ErrorMessage>,
 }
@@ -353,15 +570,27 @@ impl<'a, T> MultiSelectPrompt<'a, T>
 where
     T: Display,
 {
-    fn new(mso: MultiSelect<'a, T>) -> InquireResult<Self> {
-        if mso.options.is_empty() {
+    fn new(mut mso: MultiSelect<'a, T>) -> InquireResult<Self> {
+        if let Some(source) = &mso.source {
+            let max_default = mso.default.and_then(|d| d.iter().max().cloned());
+            let len = max_default
+                .unwrap_or_default()
+                .max(mso.starting_cursor)
+                .saturating_add(1)
+                .max(mso.page_size);
+            source.load(&mut mso.options, len)?;
+        }
+
+        let loading = matches!(&mso.source, Some(s) if !s.is_exhausted());
+
+        if mso.options.is_empty() && !loading {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
         if let Some(default) = mso.default {
             for i in default {
-                if i >= &mso.options.len() {
+                if i >= &mso.options.len() && !loading {
                     return Err(InquireError::InvalidConfiguration(format!(
                         "Index {} is out-of-bounds for length {} of options",
                         i,
@@ -371,79 +600,336 @@ where
             }
         }
 
-        let string_options = mso.options.iter().map(T::to_string).collect();
-        let filtered_options = (0..mso.options.len()).collect();
-        let checked_options = mso
-            .default
-            .map_or_else(BTreeSet::new, |d| d.iter().cloned().collect());
+        let len = match mso.source {
+            Some(_) => usize::MAX,
+            None => mso.options.len(),
+        };
+        OptionGroup::sort_and_validate(&mut mso.groups, len)
+            .map_err(InquireError::InvalidConfiguration)?;
+
+        if mso.layout == OptionLayout::Grid && !mso.groups.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Option groups are not supported in grid layouts".into(),
+            ));
+        }
+
+        let starting_row = RowIndex::Option(mso.starting_cursor);
+        let mut checked_options = Selection::new(mso.selection_order);
+        if let Some(default) = mso.default {
+            checked_options.extend(default.iter().cloned());
+        }
+
+        if let (Some(min), Some(max)) = (mso.min_selections, mso.max_selections) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum of {} selections is greater than the maximum of {}",
+                    min, max
+                )));
+            }
+        }
+        if let Some(max) = mso.max_selections {
+            if checked_options.len() > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Default selections exceed the maximum of {} selections",
+                    max
+                )));
+            }
+        }
 
-        Ok(Self {
+        let mut prompt = Self {
             message: mso.message,
             options: mso.options,
-            string_options,
-            filtered_options,
+            source: mso.source,
+            string_options: Vec::new(),
+            filtered_options: Vec::new(),
+            disabler: mso.disabler,
+            disabled: Vec::new(),
+            groups: mso.groups,
+            rows: Vec::new(),
             help_message: mso.help_message,
             vim_mode: mso.vim_mode,
-            cursor_index: mso.starting_cursor,
+            numeric_jump: mso.numeric_jump,
+            index_jump: IndexJump::default(),
+            layout: mso.layout,
+            columns: 1,
+            value_width: 0,
+            cursor_index: 0,
             page_size: mso.page_size,
             keep_filter: mso.keep_filter,
+            min_selections: mso.min_selections,
+            max_selections: mso.max_selections,
             input: Input::new(),
             filter: mso.filter,
             formatter: mso.formatter,
+            previewer: mso.previewer,
+            preview_height: mso.preview_height,
+            preview_position: mso.preview_position,
+            preview: None,
+            preview_offset: 0,
             validator: mso.validator,
             error: None,
             checked: checked_options,
-        })
-    }
+            checked_only: false,
+            range_anchor: None,
+            range_checked: Vec::new(),
+        };
 
-    fn filter_options(&self) -> Vec<usize> {
-        self.options
+        prompt.append_options(0);
+        prompt.cursor_index = prompt
+            .rows
             .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| match self.input.content() {
-                val if val.is_empty() => Some(i),
-                val if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) => Some(i),
-                _ => None,
+            .position(|r| *r == starting_row)
+            .unwrap_or_default();
+        prompt.skip_to_selectable(false, true);
+
+        Ok(prompt)
+    }
+
+    /// Returns the indexes of the options, starting from the given one, that
+    /// match the current filter.
+    fn filter_options(&mut self, from: usize) -> Vec<usize> {
+        let filtering = !self.input.content().is_empty();
+
+        if filtering {
+            self.build_string_options();
+        }
+
+        let filter = self.input.content();
+
+        (from..self.options.len())
+            .filter(|i| !self.checked_only || self.checked.contains(i))
+            .filter(|i| {
+                !filtering || (self.filter)(filter, &self.options[*i], &self.string_options[*i], *i)
             })
             .collect()
     }
 
+    /// Rebuilds the displayed rows after the filter or the display of only
+    /// the selected options changed.
+    fn refresh_rows(&mut self) {
+        self.filtered_options = self.filter_options(0);
+        self.rows = group_rows(&self.groups, &self.filtered_options);
+        if self.rows.len() <= self.cursor_index {
+            self.cursor_index = self.rows.len().saturating_sub(1);
+        }
+        self.skip_to_selectable(false, false);
+    }
+
+    /// Builds the string values of the options loaded since the last call.
+    fn build_string_options(&mut self) {
+        // string values are only needed when filtering or displaying a grid,
+        // so they are built lazily
+        for opt in &self.options[self.string_options.len()..] {
+            self.string_options.push(opt.to_string());
+        }
+    }
+
+    /// Updates the disabled and filtered options, along with the displayed
+    /// rows, to include the options loaded from the given index onwards.
+    fn append_options(&mut self, from: usize) {
+        if let Some(disabler) = self.disabler {
+            for (i, opt) in self.options.iter().enumerate().skip(from) {
+                self.disabled.push(disabler(opt, i));
+            }
+        }
+
+        if self.layout == OptionLayout::Grid {
+            self.build_string_options();
+
+            for i in from..self.options.len() {
+                let reason = match self.disabled.get(i) {
+                    Some(Some(reason)) if !reason.is_empty() => reason.width() + 3,
+                    _ => 0,
+                };
+
+                self.value_width = self
+                    .value_width
+                    .max(self.string_options[i].width() + reason);
+            }
+        }
+
+        let filtered = self.filter_options(from);
+        append_group_rows(&mut self.rows, &self.groups, &filtered);
+        self.filtered_options.extend(filtered);
+    }
+
+    /// Loads options from the source, if any, until there are enough of them
+    /// to display the given number of rows or until the source is exhausted.
+    fn load_options(&mut self, rows: usize) -> InquireResult<()> {
+        let source = match &self.source {
+            Some(source) => source.clone(),
+            None => return Ok(()),
+        };
+
+        // options loaded from now on are not selected, so they would never be
+        // displayed while only the selected ones are
+        if self.checked_only {
+            return Ok(());
+        }
+
+        // options received by streaming sources are taken as they arrive,
+        // even when the page is already full
+        while (self.rows.len() < rows || source.is_streaming()) && !source.is_exhausted() {
+            let from = self.options.len();
+            let missing = rows.saturating_sub(self.rows.len()).max(self.page_size);
+
+            source.load(&mut self.options, from.saturating_add(missing))?;
+
+            if self.options.len() == from {
+                break;
+            }
+
+            self.append_options(from);
+        }
+
+        if source.is_exhausted() {
+            self.validate_default_selections()?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the default selections exist once the source is exhausted,
+    /// as they may refer to options that were not received yet when the
+    /// prompt was created.
+    fn validate_default_selections(&self) -> InquireResult<()> {
+        match self.checked.iter().find(|i| **i >= self.options.len()) {
+            Some(i) => Err(InquireError::InvalidConfiguration(format!(
+                "Index {} is out-of-bounds for length {} of options",
+                i,
+                self.options.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
         if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index = self
                 .cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.filtered_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
         } else {
             self.cursor_index = self.cursor_index.saturating_sub(qty);
         }
+
+        self.skip_to_selectable(true, wrap);
     }
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
         self.cursor_index = self.cursor_index.saturating_add(qty);
 
-        if self.cursor_index >= self.filtered_options.len() {
-            self.cursor_index = if self.filtered_options.is_empty() {
+        if self.cursor_index >= self.rows.len() {
+            self.cursor_index = if self.rows.is_empty() {
                 0
             } else if wrap {
-                self.cursor_index % self.filtered_options.len()
+                self.cursor_index % self.rows.len()
             } else {
-                self.filtered_options.len().saturating_sub(1)
+                self.rows.len().saturating_sub(1)
             }
         }
+
+        self.skip_to_selectable(false, wrap);
+    }
+
+    /// Moves the cursor away from separators and disabled options, which can
+    /// not be highlighted. Group headers can, as they are used to toggle the
+    /// whole group.
+    fn skip_to_selectable(&mut self, up: bool, wrap: bool) {
+        let rows = &self.rows;
+        let groups = &self.groups;
+        let disabled = &self.disabled;
+
+        self.cursor_index =
+            closest_selectable(rows.len(), self.cursor_index, up, wrap, |i| match rows[i] {
+                RowIndex::Group(g) => groups[g].title.is_some(),
+                RowIndex::Option(idx) => is_enabled(disabled, idx),
+                RowIndex::Create => false,
+            })
+            .unwrap_or_default();
+    }
+
+    /// Returns the indexes of the enabled options displayed under the current
+    /// filter.
+    fn visible_enabled_options(&self) -> impl Iterator<Item = &usize> {
+        let disabled = &self.disabled;
+
+        self.filtered_options
+            .iter()
+            .filter(move |idx| is_enabled(disabled, **idx))
+    }
+
+    /// Returns the indexes of the enabled options of the given group that are
+    /// displayed under the current filter.
+    fn visible_group_options(&self, group: usize) -> impl Iterator<Item = &usize> {
+        let range = self.groups[group].range.clone();
+
+        self.visible_enabled_options()
+            .filter(move |idx| range.contains(idx))
+    }
+
+    fn is_group_checked(&self, group: usize) -> bool {
+        let mut options = self.visible_group_options(group).peekable();
+
+        options.peek().is_some() && options.all(|idx| self.checked.contains(idx))
+    }
+
+    /// Returns how many more options can be selected before reaching the
+    /// maximum number of selections.
+    fn remaining_selections(&self) -> usize {
+        match self.max_selections {
+            Some(max) => max.saturating_sub(self.checked.len()),
+            None => usize::MAX,
+        }
+    }
+
+    /// Selects the given options, up to the maximum number of selections,
+    /// displaying an error message when some of them could not be selected.
+    fn check_options(&mut self, options: Vec<usize>) {
+        let remaining = self.remaining_selections();
+
+        if options.len() > remaining {
+            let max = self.max_selections.unwrap_or_default();
+            self.error = Some(ErrorMessage::Custom(format!(
+                "You can select at most {} {}",
+                max,
+                plural_options(max)
+            )));
+        }
+
+        self.checked.extend(options.into_iter().take(remaining));
     }
 
     fn toggle_cursor_selection(&mut self) {
-        let idx = match self.filtered_options.get(self.cursor_index) {
-            Some(val) => val,
-            None => return,
-        };
+        self.error = None;
+
+        match self.rows.get(self.cursor_index) {
+            Some(RowIndex::Option(idx)) if is_enabled(&self.disabled, *idx) => {
+                if self.checked.contains(idx) {
+                    self.checked.remove(idx);
+                } else {
+                    self.check_options(vec![*idx]);
+                }
+            }
+            Some(RowIndex::Group(g)) => {
+                let options: Vec<usize> = self.visible_group_options(*g).cloned().collect();
 
-        if self.checked.contains(idx) {
-            self.checked.remove(idx);
-        } else {
-            self.checked.insert(*idx);
+                if self.is_group_checked(*g) {
+                    for idx in &options {
+                        self.checked.remove(idx);
+                    }
+                } else {
+                    let checked = &self.checked;
+                    let options = options
+                        .into_iter()
+                        .filter(|idx| !checked.contains(idx))
+                        .collect();
+
+                    self.check_options(options);
+                }
+            }
+            _ => return,
         }
 
         if !self.keep_filter {
@@ -451,53 +937,272 @@ where
         }
     }
 
-    fn on_change(&mut self, key: Key) {
+    /// Selects the enabled options displayed under the current filter, up to
+    /// the maximum number of selections.
+    fn check_visible_options(&mut self) {
+        self.error = None;
+
+        let checked = &self.checked;
+        let options = self
+            .visible_enabled_options()
+            .filter(|idx| !checked.contains(idx))
+            .cloned()
+            .collect();
+
+        self.check_options(options);
+    }
+
+    /// Deselects the enabled options displayed under the current filter.
+    fn clear_visible_selections(&mut self) {
+        self.error = None;
+
+        let options: Vec<usize> = self.visible_enabled_options().cloned().collect();
+
+        for idx in &options {
+            self.checked.remove(idx);
+        }
+    }
+
+    /// Inverts the selection of the enabled options displayed under the
+    /// current filter, up to the maximum number of selections.
+    fn invert_visible_selections(&mut self) {
+        self.error = None;
+
+        let checked = &self.checked;
+        let (selected, unselected): (Vec<usize>, Vec<usize>) = self
+            .visible_enabled_options()
+            .partition(|idx| checked.contains(idx));
+
+        for idx in &selected {
+            self.checked.remove(idx);
+        }
+
+        self.check_options(unselected);
+    }
+
+    /// Moves the cursor one row up or down and selects the enabled options
+    /// between the anchor, the option highlighted when the range was started,
+    /// and the cursor. Options selected by the range that fall out of it are
+    /// deselected again, while the ones selected beforehand are left alone.
+    fn extend_selection(&mut self, up: bool) -> InquireResult<()> {
+        self.error = None;
+
+        if self.range_anchor.is_none() {
+            self.range_anchor = self.highlighted_option();
+            self.range_checked.clear();
+        }
+
+        if up {
+            self.move_cursor_up(1, false);
+        } else {
+            self.load_options(self.cursor_index.saturating_add(2))?;
+            self.move_cursor_down(1, false);
+        }
+
+        let anchor_row = self.range_anchor.and_then(|anchor| {
+            self.rows
+                .iter()
+                .position(|r| *r == RowIndex::Option(anchor))
+        });
+        let anchor_row = match anchor_row {
+            Some(row) => row,
+            None => return Ok(()),
+        };
+
+        let start = anchor_row.min(self.cursor_index);
+        let end = anchor_row.max(self.cursor_index);
+        let disabled = &self.disabled;
+        let range: Vec<usize> = self.rows[start..=end]
+            .iter()
+            .filter_map(|row| match row {
+                RowIndex::Option(idx) if is_enabled(disabled, *idx) => Some(*idx),
+                _ => None,
+            })
+            .collect();
+
+        let previous = std::mem::take(&mut self.range_checked);
+        let (kept, left): (Vec<usize>, Vec<usize>) =
+            previous.into_iter().partition(|idx| range.contains(idx));
+
+        for idx in &left {
+            self.checked.remove(idx);
+        }
+
+        let checked = &self.checked;
+        let unchecked: Vec<usize> = range
+            .into_iter()
+            .filter(|idx| !checked.contains(idx))
+            .collect();
+
+        self.check_options(unchecked.clone());
+
+        let checked = &self.checked;
+        self.range_checked = kept
+            .into_iter()
+            .chain(unchecked.into_iter().filter(|idx| checked.contains(idx)))
+            .collect();
+
+        Ok(())
+    }
+
+    /// Moves the cursor to the option displayed with the index being typed,
+    /// toggling it right away when configured to.
+    fn jump_to_index(&mut self, digit: char) {
+        self.range_anchor = None;
+
+        let len = self.filtered_options.len();
+        let digit = digit.to_digit(10).unwrap_or_default();
+
+        let index = match self.index_jump.push(digit, len, Instant::now()) {
+            Some(index) => index,
+            None => return,
+        };
+        let option = self.filtered_options[index - 1];
+
+        match self
+            .rows
+            .iter()
+            .position(|r| *r == RowIndex::Option(option))
+        {
+            Some(row) if is_enabled(&self.disabled, option) => self.cursor_index = row,
+            _ => return,
+        }
+
+        if self.numeric_jump == NumericJump::Pick && IndexJump::is_complete(index, len) {
+            self.toggle_cursor_selection();
+        }
+    }
+
+    /// Toggles the option of the index typed in pick mode once it can no
+    /// longer be completed with more digits, its option having been
+    /// highlighted when the index was typed.
+    fn toggle_expired_index(&mut self) {
+        if self.numeric_jump != NumericJump::Pick {
+            return;
+        }
+
+        let option = match self
+            .index_jump
+            .expire(Instant::now())
+            .and_then(|index| self.filtered_options.get(index - 1))
+        {
+            Some(option) => *option,
+            None => return,
+        };
+
+        if self.rows.get(self.cursor_index) == Some(&RowIndex::Option(option)) {
+            self.toggle_cursor_selection();
+        }
+    }
+
+    fn on_change(&mut self, key: Key) -> InquireResult<()> {
+        self.index_jump.reset();
+
+        // any other key ends the range being selected with shift
+        if !matches!(
+            key,
+            Key::Up(KeyModifiers::SHIFT) | Key::Down(KeyModifiers::SHIFT)
+        ) {
+            self.range_anchor = None;
+        }
+
+        // in grids, moving vertically skips a whole line of options
+        let grid = self.layout == OptionLayout::Grid;
+        let line = self.columns;
+        let page = self.page_size.saturating_mul(line);
+
         match key {
-            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
-            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
-            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(line, !grid),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(line, !grid),
+            Key::PageUp => self.move_cursor_up(page, false),
             Key::Home => self.move_cursor_up(usize::MAX, false),
 
-            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
-            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
-            Key::PageDown => self.move_cursor_down(self.page_size, false),
-            Key::End => self.move_cursor_down(usize::MAX, false),
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(line, !grid),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => {
+                self.move_cursor_down(line, !grid)
+            }
+            Key::PageDown => {
+                let rows = self.cursor_index.saturating_add(page);
+                self.load_options(rows.saturating_add(1))?;
+                self.move_cursor_down(page, false)
+            }
+
+            Key::Left(KeyModifiers::NONE) if grid => self.move_cursor_up(1, true),
+            Key::Char('h', KeyModifiers::NONE) if grid && self.vim_mode => {
+                self.move_cursor_up(1, true)
+            }
+            Key::Right(KeyModifiers::NONE) if grid => {
+                self.load_options(self.cursor_index.saturating_add(2))?;
+                self.move_cursor_down(1, true)
+            }
+            Key::Char('l', KeyModifiers::NONE) if grid && self.vim_mode => {
+                self.load_options(self.cursor_index.saturating_add(2))?;
+                self.move_cursor_down(1, true)
+            }
+            Key::End => {
+                self.load_options(usize::MAX)?;
+                self.move_cursor_down(usize::MAX, false)
+            }
+
+            Key::Up(KeyModifiers::CONTROL) => {
+                self.preview_offset = self.preview_offset.saturating_sub(1)
+            }
+            Key::Down(KeyModifiers::CONTROL) => self.scroll_preview_down(),
+
+            Key::Up(KeyModifiers::SHIFT) => self.extend_selection(true)?,
+            Key::Down(KeyModifiers::SHIFT) => self.extend_selection(false)?,
 
             Key::Char(' ', KeyModifiers::NONE) => self.toggle_cursor_selection(),
             Key::Right(KeyModifiers::NONE) => {
-                self.checked.clear();
-                for idx in &self.filtered_options {
-                    self.checked.insert(*idx);
-                }
+                self.check_visible_options();
 
                 if !self.keep_filter {
                     self.input.clear();
                 }
             }
             Key::Left(KeyModifiers::NONE) => {
-                self.checked.clear();
+                self.clear_visible_selections();
+
+                if !self.keep_filter {
+                    self.input.clear();
+                }
+            }
+            Key::Char('t', KeyModifiers::CONTROL) => {
+                self.invert_visible_selections();
 
                 if !self.keep_filter {
                     self.input.clear();
                 }
             }
+            Key::Tab => {
+                self.checked_only = !self.checked_only;
+                self.refresh_rows();
+            }
             key => {
                 let dirty = self.input.handle_key(key);
 
                 if dirty {
-                    let options = self.filter_options();
-                    if options.len() <= self.cursor_index {
-                        self.cursor_index = options.len().saturating_sub(1);
-                    }
-                    self.filtered_options = options;
+                    self.refresh_rows();
                 }
             }
         };
+
+        Ok(())
     }
 
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(min) = self.min_selections {
+            if self.checked.len() < min {
+                return Ok(Validation::Invalid(ErrorMessage::Custom(format!(
+                    "Select at least {} {}",
+                    min,
+                    plural_options(min)
+                ))));
+            }
+        }
+
         if let Some(validator) = self.validator {
-            let selected_options = self
+            let mut selected_options = self
                 .options
                 .iter()
                 .enumerate()
@@ -506,6 +1211,7 @@ where
                     false => None,
                 })
                 .collect::<Vec<ListOption<&T>>>();
+            self.checked.sort_by_order(&mut selected_options);
 
             let res = validator(&selected_options)?;
             Ok(res)
@@ -520,38 +1226,160 @@ where
         // by iterating in descending order, we can safely
         // swap remove because the elements to the right
         // that we did not remove will not matter anymore.
-        for index in self.checked.iter().rev() {
+        // default selections of options that were not loaded yet are dropped.
+        let loaded = self.options.len();
+        for index in self.checked.iter().rev().filter(|i| **i < loaded) {
             let index = *index;
             let value = self.options.swap_remove(index);
             let lo = ListOption::new(index, value);
             answer.push(lo);
         }
         answer.reverse();
+        self.checked.sort_by_order(&mut answer);
 
         answer
     }
 
-    fn render<B: MultiSelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
-        let prompt = &self.message;
+    fn highlighted_option(&self) -> Option<usize> {
+        match self.rows.get(self.cursor_index) {
+            Some(RowIndex::Option(idx)) if is_enabled(&self.disabled, *idx) => Some(*idx),
+            _ => None,
+        }
+    }
 
-        backend.frame_setup()?;
+    /// Builds the preview of the highlighted option, unless it was already
+    /// built for the same option.
+    fn update_preview(&mut self) -> InquireResult<()> {
+        let previewer = match self.previewer {
+            Some(previewer) => previewer,
+            None => return Ok(()),
+        };
 
-        if let Some(err) = &self.error {
+        match self.highlighted_option() {
+            Some(idx) if self.preview.as_ref().map(|(i, _)| *i) != Some(idx) => {
+                self.preview = Some((idx, previewer(&self.options[idx])?));
+                self.preview_offset = 0;
+            }
+            Some(_) => {}
+            None => self.preview = None,
+        }
+
+        Ok(())
+    }
+
+    fn scroll_preview_down(&mut self) {
+        if let Some((_, preview)) = &self.preview {
+            let max_offset = preview.lines().len().saturating_sub(self.preview_height);
+            self.preview_offset = self.preview_offset.saturating_add(1).min(max_offset);
+        }
+    }
+
+    fn render<B: MultiSelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        if let Some(err) = &self.error {
             backend.render_error_message(err)?;
         }
 
-        backend.render_multiselect_prompt(prompt, &self.input)?;
+        let counter = match (self.min_selections, self.max_selections) {
+            (_, Some(max)) => Some(format!("{}/{} selected", self.checked.len(), max)),
+            (Some(_), None) => Some(format!("{} selected", self.checked.len())),
+            (None, None) => None,
+        };
+
+        backend.render_multiselect_prompt(prompt, counter.as_deref(), &self.input)?;
+
+        let page_size = self.page_size.saturating_mul(self.columns);
+        self.load_options(self.cursor_index.saturating_add(page_size))?;
+        self.update_preview()?;
+
+        let groups = &self.groups;
+        let page = match self.layout {
+            OptionLayout::List => paginate_with_headers(
+                self.page_size,
+                &self.rows,
+                self.cursor_index,
+                |r| matches!(r, RowIndex::Group(g) if groups[*g].title.is_some()),
+            ),
+            OptionLayout::Grid => {
+                let beside =
+                    self.preview.is_some() && self.preview_position == PreviewPosition::Beside;
+
+                self.columns = backend.grid_columns(self.value_width, self.rows.len(), beside);
+                paginate_grid(self.page_size, &self.rows, self.cursor_index, self.columns)
+            }
+        };
 
-        let choices = self
-            .filtered_options
+        let choices = page
+            .content
             .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|row| match *row {
+                RowIndex::Group(g) => match self.groups[g].title {
+                    Some(title) => OptionRow::Header {
+                        title,
+                        checked: self.is_group_checked(g),
+                    },
+                    None => OptionRow::Separator,
+                },
+                RowIndex::Option(i) => {
+                    let option = ListOption::new(i, &self.options[i]);
+                    let prefix_index = match self.numeric_jump {
+                        NumericJump::Disabled => i,
+                        _ => self.filtered_options.binary_search(&i).unwrap_or_default(),
+                    };
+
+                    match self.disabled.get(i) {
+                        Some(Some(reason)) => OptionRow::Disabled {
+                            option,
+                            prefix_index,
+                            reason,
+                        },
+                        _ => OptionRow::Option {
+                            option,
+                            prefix_index,
+                            hotkey: None,
+                        },
+                    }
+                }
+                RowIndex::Create => unreachable!(),
+            })
+            .collect::<Vec<OptionRow<&T>>>();
+
+        let page = Page {
+            first: page.first,
+            last: page.last,
+            content: &choices,
+            selection: page.selection,
+            total: page.total,
+        };
 
-        let page = paginate(self.page_size, &choices, self.cursor_index);
+        let preview = self.preview.as_ref().map(|(_, preview)| {
+            paginate_preview(
+                preview,
+                self.preview_height,
+                self.preview_offset,
+                self.preview_position,
+            )
+        });
+
+        match self.layout {
+            OptionLayout::List => backend.render_options(page, &self.checked, preview)?,
+            OptionLayout::Grid => backend.render_option_grid(
+                page,
+                self.columns,
+                self.value_width,
+                &self.checked,
+                preview,
+            )?,
+        }
 
-        backend.render_options(page, &self.checked)?;
+        if let Some(source) = &self.source {
+            if !source.is_exhausted() {
+                backend.render_loaded_indicator(self.options.len())?;
+            }
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -569,16 +1397,41 @@ where
         loop {
             self.render(backend)?;
 
+            let mut refresh = match &self.source {
+                Some(source) if source.is_streaming() => Some(REFRESH_INTERVAL),
+                _ => None,
+            };
+            // wakes up to pick the index being typed once it can not be completed
+            if let (NumericJump::Pick, Some(deadline)) =
+                (self.numeric_jump, self.index_jump.deadline())
+            {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                refresh = Some(refresh.map_or(remaining, |r| r.min(remaining)));
+            }
+            backend.set_refresh_interval(refresh);
+
             let key = backend.read_key()?;
 
+            self.toggle_expired_index();
+
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Tick => {}
+                Key::Timeout => match self.validate_current_answer()? {
+                    Validation::Valid => break,
+                    Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                },
                 Key::Submit => match self.validate_current_answer()? {
                     Validation::Valid => break,
                     Validation::Invalid(msg) => self.error = Some(msg),
                 },
-                key => self.on_change(key),
+                Key::Char(c, KeyModifiers::NONE)
+                    if c.is_ascii_digit() && self.numeric_jump != NumericJump::Disabled =>
+                {
+                    self.jump_to_index(c)
+                }
+                key => self.on_change(key)?,
             }
         }
 
@@ -590,17 +1443,28 @@ where
     }
 }
 
+fn plural_options(qty: usize) -> &'static str {
+    match qty {
+        1 => "option",
+        _ => "options",
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test {
     use crate::{
+        error::InquireError,
         formatter::MultiOptionFormatter,
-        list_option::ListOption,
+        list_option::{ListOption, NumericJump, OptionGroup},
+        option_source::OptionSource,
         terminal::crossterm::CrosstermTerminal,
-        ui::{Backend, RenderConfig},
+        ui::{Backend, OptionLayout, RenderConfig},
+        validator::Validation,
         MultiSelect,
     };
-    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::{sync::mpsc::channel, time::Duration};
 
     #[test]
     /// Tests that a closure that actually closes on a variable can be used
@@ -687,4 +1551,612 @@ mod test {
 
         assert_eq!(vec![ListOption::new(1, 2), ListOption::new(2, 3)], ans);
     }
+
+    #[test]
+    fn toggling_group_header_toggles_visible_group_options() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Char('5'),
+            KeyCode::Up,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4, 5, 6];
+        let groups = vec![
+            OptionGroup::new("Low", 0..2),
+            OptionGroup::new("High", 2..6),
+        ];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = MultiSelect::new("Question", options)
+            .with_groups(groups)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(2, 3),
+                ListOption::new(3, 4),
+                ListOption::new(5, 6)
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn disabled_options_are_not_toggled() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Left,
+            KeyCode::Right,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Question", options)
+            .with_default(&[2])
+            .with_disabler(&|opt, _| match opt {
+                1 => Some(String::from("deprecated")),
+                3 => Some(String::new()),
+                _ => None,
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(1, 2),
+                ListOption::new(2, 3),
+                ListOption::new(3, 4)
+            ],
+            ans
+        );
+
+        drop(backend);
+        assert!(String::from_utf8(write).unwrap().contains("1 (deprecated)"));
+    }
+
+    #[test]
+    fn default_selections_not_received_yet_are_not_answered() {
+        let read: Vec<KeyEvent> = vec![KeyEvent::from(KeyCode::Enter)];
+        let mut read = read.iter();
+
+        let (sender, receiver) = channel();
+        sender.send("alpha").unwrap();
+        sender.send("beta").unwrap();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::from_source("Question", OptionSource::channel(receiver))
+            .with_default(&[1, 5])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(1, "beta")], ans);
+        drop(sender);
+    }
+
+    #[test]
+    fn default_selections_out_of_exhausted_source_are_invalid() {
+        let (sender, receiver) = channel();
+        sender.send("alpha").unwrap();
+        sender.send("beta").unwrap();
+
+        // the channel is closed while the prompt waits for keys
+        let mut sender = Some(sender);
+        let mut read = std::iter::from_fn(|| {
+            drop(sender.take());
+            None
+        });
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::from_source("Question", OptionSource::channel(receiver))
+            .with_default(&[5])
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn selecting_all_selects_loaded_source_options() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Right, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::from_source("Question", OptionSource::iter(0..1_000))
+            .with_page_size(3)
+            .with_default(&[4])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(0, 0),
+                ListOption::new(1, 1),
+                ListOption::new(2, 2),
+                ListOption::new(3, 3),
+                ListOption::new(4, 4),
+            ],
+            ans
+        );
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("5 loaded"));
+    }
+
+    #[test]
+    fn typed_indexes_toggle_options_when_picking() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('1'), KeyCode::Char('3'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = MultiSelect::new("Question", options)
+            .with_numeric_jump(NumericJump::Pick)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, 1), ListOption::new(2, 3)], ans);
+    }
+
+    #[test]
+    fn ambiguous_index_is_toggled_once_it_can_not_be_completed() {
+        let keys: Vec<KeyEvent> = vec![KeyCode::Char('1'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut keys = keys.iter();
+        let mut reads = 0;
+
+        // no key is pressed after the digit until the index can not be
+        // completed anymore
+        let mut read = std::iter::from_fn(|| {
+            reads += 1;
+            match reads {
+                2 => None,
+                _ => keys.next(),
+            }
+        });
+
+        let options: Vec<usize> = (1..=12).collect();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = MultiSelect::new("Question", options)
+            .with_numeric_jump(NumericJump::Pick)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, 1)], ans);
+    }
+
+    #[test]
+    fn grid_layout_moves_between_columns_instead_of_selecting_all() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Right, KeyCode::Char(' '), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Regions", vec!["us", "eu", "ap"])
+            .with_layout(OptionLayout::Grid)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(1, "eu")], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("  [ ] us  > [x] eu    [ ] ap\r\n"));
+    }
+
+    #[test]
+    fn toggling_beyond_max_selections_is_blocked() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Toppings", vec!["ham", "egg", "leek"])
+            .with_max_selections(2)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![ListOption::new(0, "ham"), ListOption::new(1, "egg")],
+            ans
+        );
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("? Toppings [0/2 selected]"));
+        assert!(output.contains("? Toppings [2/2 selected]"));
+        assert!(output.contains("You can select at most 2 options"));
+    }
+
+    #[test]
+    fn selecting_all_respects_max_selections() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Right, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Toppings", vec!["ham", "egg", "leek"])
+            .with_max_selections(1)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "ham")], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("You can select at most 1 option"));
+    }
+
+    #[test]
+    fn submitting_requires_min_selections() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter, KeyCode::Char(' '), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Toppings", vec!["ham", "egg", "leek"])
+            .with_min_selections(1)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "ham")], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("? Toppings [0 selected]"));
+        assert!(output.contains("Select at least 1 option"));
+    }
+
+    #[test]
+    fn min_selections_greater_than_max_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Toppings", vec!["ham", "egg", "leek"])
+            .with_min_selections(2)
+            .with_max_selections(1)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn selecting_all_only_applies_to_filtered_options() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Char('a'),
+            KeyCode::Char('v'),
+            KeyCode::Right,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Fruits", vec!["apple", "avocado", "banana"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![ListOption::new(1, "avocado"), ListOption::new(2, "banana")],
+            ans
+        );
+    }
+
+    #[test]
+    fn shift_arrows_select_a_contiguous_range() {
+        let read: Vec<KeyEvent> = vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Letters", vec!["a", "b", "c", "d", "e"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(1, "b"),
+                ListOption::new(2, "c"),
+                ListOption::new(3, "d"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn shift_arrows_shrink_the_range_back_to_the_anchor() {
+        let read: Vec<KeyEvent> = vec![
+            KeyEvent::from(KeyCode::Char(' ')),
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Letters", vec!["a", "b", "c", "d", "e"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(0, "a"),
+                ListOption::new(1, "b"),
+                ListOption::new(2, "c"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn ctrl_t_inverts_the_selection() {
+        let read: Vec<KeyEvent> = vec![
+            KeyEvent::from(KeyCode::Char(' ')),
+            KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Letters", vec!["a", "b", "c"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(1, "b"), ListOption::new(2, "c")], ans);
+    }
+
+    #[test]
+    fn tab_toggles_displaying_only_selected_options() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Tab,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Letters", vec!["a", "b", "c"])
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(2, "c")], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("  [x] a\r\n> [x] c\r\n"));
+    }
+
+    #[test]
+    fn selection_order_returns_options_in_the_order_they_were_picked() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Home,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Home,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Regions", vec!["us", "eu", "ap"])
+            .with_selection_order(true)
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![ListOption::new(2, "ap"), ListOption::new(1, "eu")],
+            ans
+        );
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> [2] us\r\n  [ ] eu\r\n  [1] ap\r\n"));
+        assert!(output.contains("> [ ] us\r\n  [2] eu\r\n  [1] ap\r\n"));
+    }
+
+    #[test]
+    fn timeout_resolves_to_default_selection() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Regions", vec!["a", "b", "c"])
+            .with_default(&[2, 0])
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(2, "c")], ans);
+    }
+
+    #[test]
+    fn timeout_without_default_resolves_to_empty_selection() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Regions", vec!["a", "b", "c"])
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert!(ans.is_empty());
+    }
+
+    #[test]
+    fn timeout_fails_when_empty_selection_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Regions", vec!["a", "b", "c"])
+            .with_validator(&|a: &[ListOption<&&str>]| match a.is_empty() {
+                true => Ok(Validation::Invalid("Pick at least one region".into())),
+                false => Ok(Validation::Valid),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+
+    #[test]
+    fn timeout_fails_when_default_selection_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = MultiSelect::new("Regions", vec!["a", "b", "c"])
+            .with_default(&[0])
+            .with_validator(&|a: &[ListOption<&&str>]| match a.len() {
+                1 => Ok(Validation::Invalid("Pick at least two regions".into())),
+                _ => Ok(Validation::Valid),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
 }
@@ -0,0 +1,893 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr, time::Duration};
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    formatter::CustomTypeFormatter,
+    input::Input,
+    parser::{CustomTypeParser, ParseError},
+    terminal::get_default_terminal,
+    ui::{Backend, Key, KeyModifiers, NumberBackend, RenderConfig},
+    validator::ErrorMessage,
+};
+
+/// Numeric types that can be prompted for in [`Number`] prompts.
+///
+/// This trait is implemented for all primitive integer and floating-point
+/// types. You can implement it for other numeric types, such as decimals,
+/// to use them in [`Number`] prompts.
+pub trait Numeric: Copy + PartialOrd + Display + FromStr {
+    /// The value zero, used as the starting value when there is no default
+    /// value nor minimum.
+    const ZERO: Self;
+
+    /// The value one, used as the default step.
+    const ONE: Self;
+
+    /// The value ten, used as the default page step.
+    const TEN: Self;
+
+    /// Adds a step to the value, saturating at the bounds of the type.
+    fn add_step(self, step: Self) -> Self;
+
+    /// Subtracts a step from the value, saturating at the bounds of the type.
+    fn sub_step(self, step: Self) -> Self;
+
+    /// Converts the value to a `f64`, used to position the handle of the slider.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_numeric_int {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TEN: Self = 10;
+
+                fn add_step(self, step: Self) -> Self {
+                    self.saturating_add(step)
+                }
+
+                fn sub_step(self, step: Self) -> Self {
+                    self.saturating_sub(step)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const TEN: Self = 10.0;
+
+                fn add_step(self, step: Self) -> Self {
+                    round_to_decimals(self + step, decimals(self).max(decimals(step)))
+                }
+
+                fn sub_step(self, step: Self) -> Self {
+                    round_to_decimals(self - step, decimals(self).max(decimals(step)))
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_numeric_float!(f32, f64);
+
+/// Number of decimal places in the shortest representation of the value.
+fn decimals<T: Display>(value: T) -> usize {
+    let repr = value.to_string();
+
+    repr.find('.').map_or(0, |dot| repr.len() - dot - 1)
+}
+
+/// Rounds a float to the given number of decimal places, dropping the
+/// representation errors of binary floats, such as `0.1 + 0.2` being
+/// `0.30000000000000004`.
+fn round_to_decimals<T: Copy + Display + FromStr>(value: T, decimals: usize) -> T {
+    format!("{:.*}", decimals, value).parse().unwrap_or(value)
+}
+
+/// Prompt suitable for when you need the user to enter a number, which can be
+/// either typed directly or changed in steps with the arrow keys.
+///
+/// Up and down arrows increase and decrease the value by the step, while page
+/// up and page down do so by the page step. The input is then replaced by the
+/// new value, starting from the parsed input, the default value, the minimum
+/// or zero, in this order. Typing a number directly is still possible, and the
+/// input is parsed just like in [`CustomType`](crate::CustomType) prompts.
+///
+/// Values are clamped to the minimum and maximum, if any, both when stepping
+/// and when submitting a typed number. When both of them are set, a slider
+/// bar can be displayed below the prompt, with a handle positioned according
+/// to the current value.
+///
+/// This prompt provides several options of configuration:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Default value**: Default value returned when the user submits an empty response.
+/// - **Placeholder**: Short hint that describes the expected value of the input.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Calls `to_string()` on the value by default.
+/// - **Parser**: Custom parser for user inputs.
+///   - Calls [`str.parse`](https://doc.rust-lang.org/stable/std/primitive.str.html#method.parse) by default.
+/// - **Error message**: Error message to display when a value could not be parsed from the input and the parser did not return a specific one.
+///   - Set to "Invalid number" by default.
+/// - **Step and page step**: Amounts added or subtracted by the arrows and by page up and page down.
+///   - Set to one and ten by default. If they are not greater than zero, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Minimum and maximum**: Bounds to which the value is clamped.
+///   - If the minimum is greater than the maximum, or if the default value is out of them, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Slider width**: Width of the slider bar displayed below the prompt, in columns.
+///   - No slider is displayed by default. If it is set while any of the bounds is not, or if it is zero, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Number;
+///
+/// let volume = Number::<u8>::new("Volume:")
+///     .with_default(50)
+///     .with_bounds(0, 100)
+///     .with_page_step(25)
+///     .with_slider(20)
+///     .prompt();
+///
+/// match volume {
+///     Ok(volume) => println!("Volume set to {}", volume),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct Number<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Default value, returned when the user input is empty.
+    pub default: Option<T>,
+
+    /// Short hint that describes the expected value of the input.
+    pub placeholder: Option<&'a str>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: CustomTypeFormatter<'a, T>,
+
+    /// Function that parses the user input and returns the result value.
+    pub parser: CustomTypeParser<'a, T>,
+
+    /// Error message displayed when value could not be parsed from input
+    /// and the parser did not return a specific one.
+    pub error_message: String,
+
+    /// Amount added or subtracted by the up and down arrows.
+    pub step: T,
+
+    /// Amount added or subtracted by page up and page down.
+    pub page_step: T,
+
+    /// Minimum value, to which lower values are clamped.
+    pub min: Option<T>,
+
+    /// Maximum value, to which higher values are clamped.
+    pub max: Option<T>,
+
+    /// Width of the slider bar displayed below the prompt, in columns.
+    ///
+    /// No slider is displayed when `None`.
+    pub slider_width: Option<usize>,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or fails with [`InquireError::Timeout`] when
+    /// there is none.
+    pub timeout: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> Number<'a, T>
+where
+    T: Numeric,
+{
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to change the value, page up/down for larger steps, type to enter it");
+
+    /// Default error message.
+    pub const DEFAULT_ERROR_MESSAGE: &'a str = "Invalid number";
+
+    /// Creates a [Number] with the provided message and default configuration values.
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            default: None,
+            placeholder: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            formatter: &|val| val.to_string(),
+            parser: &|a| a.parse::<T>().map_err(|_| ParseError::default()),
+            error_message: Self::DEFAULT_ERROR_MESSAGE.into(),
+            step: T::ONE,
+            page_step: T::TEN,
+            min: None,
+            max: None,
+            slider_width: None,
+            timeout: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the default value.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the parser.
+    pub fn with_parser(mut self, parser: CustomTypeParser<'a, T>) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Sets a custom error message displayed when a submission could not be parsed to a value
+    /// and the parser did not return a specific one.
+    pub fn with_error_message(mut self, error_message: &'a str) -> Self {
+        self.error_message = String::from(error_message);
+        self
+    }
+
+    /// Sets the amount added or subtracted by the up and down arrows.
+    ///
+    /// For floats, the result is rounded to the decimal places of the step or
+    /// of the current value, whichever has more, so that stepping by `0.1`
+    /// goes from `0.2` to `0.3` rather than to `0.30000000000000004`.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the amount added or subtracted by page up and page down.
+    pub fn with_page_step(mut self, page_step: T) -> Self {
+        self.page_step = page_step;
+        self
+    }
+
+    /// Sets the minimum value, to which lower values are clamped.
+    pub fn with_min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value, to which higher values are clamped.
+    pub fn with_max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets both the minimum and maximum values.
+    pub fn with_bounds(self, min: T, max: T) -> Self {
+        self.with_min(min).with_max(max)
+    }
+
+    /// Displays a slider bar with the given width, in columns, below the prompt.
+    ///
+    /// Both the minimum and maximum values must be set.
+    pub fn with_slider(mut self, width: usize) -> Self {
+        self.slider_width = Some(width);
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default value. When it has none, the prompt fails with
+    /// [`InquireError::Timeout`] instead. The remaining time is displayed in
+    /// the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    pub fn prompt(self) -> InquireResult<T> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(in crate) fn prompt_with_backend<B: NumberBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<T> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        NumberPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct NumberPrompt<'a, T> {
+    message: &'a str,
+    error: Option<ErrorMessage>,
+    help_message: Option<&'a str>,
+    default: Option<T>,
+    input: Input,
+    formatter: CustomTypeFormatter<'a, T>,
+    parser: CustomTypeParser<'a, T>,
+    error_message: String,
+    step: T,
+    page_step: T,
+    min: Option<T>,
+    max: Option<T>,
+    slider_width: Option<usize>,
+}
+
+impl<'a, T> NumberPrompt<'a, T>
+where
+    T: Numeric,
+{
+    fn new(no: Number<'a, T>) -> InquireResult<Self> {
+        let positive = |step: T| step.partial_cmp(&T::ZERO) == Some(Ordering::Greater);
+
+        if !positive(no.step) || !positive(no.page_step) {
+            return Err(InquireError::InvalidConfiguration(
+                "Step and page step must be greater than zero".into(),
+            ));
+        }
+
+        if let (Some(min), Some(max)) = (no.min, no.max) {
+            if min > max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Minimum value {} is greater than maximum value {}",
+                    min, max
+                )));
+            }
+        }
+
+        if let Some(default) = no.default {
+            let below_min = no.min.map_or(false, |min| default < min);
+            let above_max = no.max.map_or(false, |max| default > max);
+
+            if below_min || above_max {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Default value {} is out of the bounds of the prompt",
+                    default
+                )));
+            }
+        }
+
+        match no.slider_width {
+            Some(0) => {
+                return Err(InquireError::InvalidConfiguration(
+                    "Slider width must be greater than zero".into(),
+                ))
+            }
+            Some(_) if no.min.is_none() || no.max.is_none() => {
+                return Err(InquireError::InvalidConfiguration(
+                    "Slider requires both minimum and maximum values".into(),
+                ))
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            message: no.message,
+            error: None,
+            help_message: no.help_message,
+            default: no.default,
+            input: no
+                .placeholder
+                .map(|p| Input::new().with_placeholder(p))
+                .unwrap_or_else(Input::new),
+            formatter: no.formatter,
+            parser: no.parser,
+            error_message: no.error_message,
+            step: no.step,
+            page_step: no.page_step,
+            min: no.min,
+            max: no.max,
+            slider_width: no.slider_width,
+        })
+    }
+
+    /// Clamps the value to the bounds of the prompt, returning `None` for
+    /// values that can not be compared, such as NaN.
+    fn clamp(&self, value: T) -> Option<T> {
+        value.partial_cmp(&T::ZERO)?;
+
+        match (self.min, self.max) {
+            (Some(min), _) if value.partial_cmp(&min) == Some(Ordering::Less) => Some(min),
+            (_, Some(max)) if value.partial_cmp(&max) == Some(Ordering::Greater) => Some(max),
+            _ => Some(value),
+        }
+    }
+
+    /// Value the input currently stands for: the parsed input or, when it is
+    /// empty or invalid, the default value, the minimum or zero.
+    fn current_value(&self) -> T {
+        let parsed = match self.input.content() {
+            "" => None,
+            content => (self.parser)(content)
+                .ok()
+                .filter(|val| val.partial_cmp(&T::ZERO).is_some()),
+        };
+
+        parsed.or(self.default).or(self.min).unwrap_or(T::ZERO)
+    }
+
+    fn step_by(&mut self, up: bool, step: T) {
+        let current = self.current_value();
+        let value = match up {
+            true => current.add_step(step),
+            false => current.sub_step(step),
+        };
+
+        if let Some(value) = self.clamp(value) {
+            self.input = Input::new_with(&value.to_string());
+        }
+    }
+
+    fn on_change(&mut self, key: Key) {
+        match key {
+            Key::Up(KeyModifiers::NONE) => self.step_by(true, self.step),
+            Key::Down(KeyModifiers::NONE) => self.step_by(false, self.step),
+            Key::PageUp => self.step_by(true, self.page_step),
+            Key::PageDown => self.step_by(false, self.page_step),
+            key => {
+                self.input.handle_key(key);
+            }
+        }
+    }
+
+    fn get_final_answer(&self) -> Result<T, ParseError> {
+        match self.default {
+            Some(val) if self.input.content().is_empty() => return Ok(val),
+            _ => {}
+        }
+
+        match (self.parser)(self.input.content()) {
+            Ok(val) => self.clamp(val).ok_or_else(ParseError::default),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn render<B: NumberBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        if let Some(error) = &self.error {
+            backend.render_error_message(error)?;
+        }
+
+        let default_message = self.default.map(|val| (self.formatter)(val));
+
+        backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
+
+        if let (Some(width), Some(min), Some(max)) = (self.slider_width, self.min, self.max) {
+            let range = max.to_f64() - min.to_f64();
+            let position = match range > 0.0 {
+                true => (self.current_value().to_f64() - min.to_f64()) / range,
+                false => 0.0,
+            };
+
+            backend.render_slider(&min.to_string(), &max.to_string(), position, width)?;
+        }
+
+        if let Some(message) = self.help_message {
+            backend.render_help_message(message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: NumberBackend>(mut self, backend: &mut B) -> InquireResult<T> {
+        let final_answer: T;
+
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match self.default {
+                    Some(default) => {
+                        final_answer = default;
+                        break;
+                    }
+                    None => timeout_prompt!(backend, self.message),
+                },
+                Key::Submit => match self.get_final_answer() {
+                    Ok(answer) => {
+                        final_answer = answer;
+                        break;
+                    }
+                    Err(err) => {
+                        self.error = Some(err.message_or(&self.error_message));
+
+                        if let Some(position) = err.position {
+                            let cursor = position.min(self.input.length());
+                            self.input = self.input.clone().with_cursor(cursor);
+                        }
+                    }
+                },
+                key => self.on_change(key),
+            }
+        }
+
+        let formatted = (self.formatter)(final_answer);
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+        Number,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    #[test]
+    fn arrows_step_from_the_default_value() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Up,
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<i32>::new("Replicas:")
+            .with_default(3)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(5, ans);
+    }
+
+    #[test]
+    fn float_steps_keep_the_precision_of_the_step() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Up, KeyCode::Up, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<f64>::new("Ratio:")
+            .with_step(0.1)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(0.3, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("0.3"));
+        assert!(!output.contains("0.30000000000000004"));
+    }
+
+    #[test]
+    fn float_steps_keep_the_precision_of_the_value() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<f32>::new("Ratio:")
+            .with_default(0.35)
+            .with_step(0.1)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(0.15, ans);
+    }
+
+    #[test]
+    fn page_up_steps_by_the_page_step_up_to_the_max() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::PageUp,
+            KeyCode::PageUp,
+            KeyCode::PageUp,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<u32>::new("Volume:")
+            .with_default(40)
+            .with_bounds(0, 100)
+            .with_page_step(25)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(100, ans);
+    }
+
+    #[test]
+    fn page_down_steps_by_the_page_step_down_to_the_min() {
+        let read: Vec<KeyEvent> = vec![KeyCode::PageDown, KeyCode::PageDown, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<u32>::new("Volume:")
+            .with_default(40)
+            .with_bounds(0, 100)
+            .with_page_step(25)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(0, ans);
+    }
+
+    #[test]
+    fn typed_values_are_parsed_and_clamped() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('2'),
+            KeyCode::Char('.'),
+            KeyCode::Char('5'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<f64>::new("Ratio:")
+            .with_bounds(0.0, 1.0)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(1.0, ans);
+    }
+
+    #[test]
+    fn values_that_can_not_be_compared_are_rejected() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('N'),
+            KeyCode::Char('a'),
+            KeyCode::Char('N'),
+            KeyCode::Enter,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<f64>::new("Ratio:")
+            .with_bounds(0.0, 1.0)
+            .with_step(0.1)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(0.1, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains(Number::<f64>::DEFAULT_ERROR_MESSAGE));
+    }
+
+    #[test]
+    fn arrows_step_from_the_typed_value() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('1'),
+            KeyCode::Char('2'),
+            KeyCode::Up,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<i32>::new("Replicas:")
+            .with_step(5)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(17, ans);
+    }
+
+    #[test]
+    fn invalid_input_displays_an_error() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('x'),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Char('2'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<i32>::new("Replicas:")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(2, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains(Number::<i32>::DEFAULT_ERROR_MESSAGE));
+    }
+
+    #[test]
+    fn slider_displays_the_value_between_the_bounds() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<u8>::new("Volume:")
+            .with_default(50)
+            .with_bounds(0, 100)
+            .with_slider(11)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(50, ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("0 ━━━━━●───── 100"));
+    }
+
+    #[test]
+    fn slider_without_bounds_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Number::<u8>::new("Volume:")
+            .with_min(0)
+            .with_slider(10)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
@@ -24,6 +26,17 @@ pub enum PasswordDisplayMode {
     Full,
 }
 
+/// Second stage of a password prompt, asking the user to enter the password
+/// again to confirm it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PasswordConfirmation<'a> {
+    /// Message presented to the user when asking for the password again.
+    pub message: &'a str,
+
+    /// Error message displayed when the two entries do not match.
+    pub error_message: &'a str,
+}
+
 /// Prompt meant for secretive text inputs.
 ///
 /// By default, the password prompt behaves like a standard one you'd see in common CLI applications: the user has no UI indicators about the state of the current input. They do not know how many characters they typed, or which character they typed, with no option to display the current text input.
@@ -41,6 +54,9 @@ pub enum PasswordDisplayMode {
 ///   - By default, it prints eight asterisk characters: `********`.
 /// - **Validators**: Custom validators to make sure a given submitted input pass the specified requirements, e.g. not allowing empty inputs or requiring special characters.
 ///   - No validators are on by default.
+/// - **Confirmation**: By calling `with_confirmation(message, mismatch_error)`, the user is asked to enter the password again once the first entry is valid.
+///   - When the entries do not match, the mismatch error is displayed and the user starts over from the first entry.
+///   - Both entries are displayed according to the display mode, and a display toggled with `Ctrl+R` stays toggled in both stages.
 ///
 /// Remember that for CLI applications it is standard to not allow use any display modes other than `Hidden` and to not allow the user to see the text input in any way. _Use the customization options at your discretion_.
 ///
@@ -93,6 +109,13 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<StringValidator<'a>>,
 
+    /// Confirmation stage asking the user to enter the password again, if any.
+    pub confirmation: Option<PasswordConfirmation<'a>>,
+
+    /// Time to wait for the user to press a key before the prompt fails with
+    /// [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -129,6 +152,8 @@ impl<'a> Password<'a> {
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
+            confirmation: None,
+            timeout: None,
             render_config: get_configuration(),
         }
     }
@@ -178,12 +203,33 @@ impl<'a> Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[StringValidator<'a>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
-            self.validators.push(validator.clone());
+            self.validators.push(*validator);
         }
         self
     }
 
+    /// Asks the user to enter the password again, with the given message, once
+    /// the first entry passes the validators.
+    ///
+    /// When the two entries do not match, the mismatch error is displayed and
+    /// the user starts over from the first entry.
+    pub fn with_confirmation(mut self, message: &'a str, mismatch_error: &'a str) -> Self {
+        self.confirmation = Some(PasswordConfirmation {
+            message,
+            error_message: mismatch_error,
+        });
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt fails with [`InquireError::Timeout`], as passwords have no
+    /// default value. The remaining time is displayed in the help line until
+    /// the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -226,6 +272,10 @@ impl<'a> Password<'a> {
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
         PasswordPrompt::from(self).prompt(backend)
     }
 }
@@ -239,6 +289,8 @@ struct PasswordPrompt<'a> {
     enable_display_toggle: bool,
     formatter: StringFormatter<'a>,
     validators: Vec<StringValidator<'a>>,
+    confirmation: Option<PasswordConfirmation<'a>>,
+    first_entry: Option<String>,
     error: Option<ErrorMessage>,
 }
 
@@ -252,6 +304,8 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             enable_display_toggle: so.enable_display_toggle,
             formatter: so.formatter,
             validators: so.validators,
+            confirmation: so.confirmation,
+            first_entry: None,
             input: Input::new(),
             error: None,
         }
@@ -302,8 +356,36 @@ impl<'a> PasswordPrompt<'a> {
         self.input.content().into()
     }
 
+    /// Handles a valid entry, returning the final answer once it is confirmed,
+    /// if confirmation is required.
+    fn on_valid_entry(&mut self) -> Option<String> {
+        let confirmation = match self.confirmation {
+            Some(confirmation) => confirmation,
+            None => return Some(self.cur_answer()),
+        };
+
+        let entry = self.cur_answer();
+        self.input = Input::new();
+
+        match self.first_entry.take() {
+            None => {
+                self.first_entry = Some(entry);
+                self.error = None;
+                None
+            }
+            Some(first_entry) if first_entry == entry => Some(entry),
+            Some(_) => {
+                self.error = Some(confirmation.error_message.into());
+                None
+            }
+        }
+    }
+
     fn render<B: PasswordBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
-        let prompt = &self.message;
+        let prompt = match (&self.first_entry, &self.confirmation) {
+            (Some(_), Some(confirmation)) => confirmation.message,
+            _ => self.message,
+        };
 
         backend.frame_setup()?;
 
@@ -343,10 +425,19 @@ impl<'a> PasswordPrompt<'a> {
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => timeout_prompt!(backend, self.message),
+                Key::Submit if self.first_entry.is_some() => {
+                    if let Some(answer) = self.on_valid_entry() {
+                        final_answer = answer;
+                        break;
+                    }
+                }
                 Key::Submit => match self.validate_current_answer()? {
                     Validation::Valid => {
-                        final_answer = self.cur_answer();
-                        break;
+                        if let Some(answer) = self.on_valid_entry() {
+                            final_answer = answer;
+                            break;
+                        }
                     }
                     Validation::Invalid(msg) => self.error = Some(msg),
                 },
@@ -363,13 +454,13 @@ impl<'a> PasswordPrompt<'a> {
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test {
-    use super::Password;
+    use super::{Password, PasswordDisplayMode};
     use crate::{
         terminal::crossterm::CrosstermTerminal,
         ui::{Backend, RenderConfig},
         validator::{ErrorMessage, Validation},
     };
-    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     fn default<'a>() -> Password<'a> {
         Password::new("Question?")
@@ -478,4 +569,87 @@ mod test {
             _ => Ok(Validation::Invalid(ErrorMessage::Default)),
         })
     );
+
+    password_test!(
+        confirmed_input,
+        text_to_events!("secret\nsecret\n"),
+        "secret",
+        default().with_confirmation("Confirm:", "The passwords do not match")
+    );
+
+    password_test!(
+        mismatched_confirmation_restarts_from_first_entry,
+        text_to_events!("secret\nsecert\nagain\nagain\n"),
+        "again",
+        default().with_confirmation("Confirm:", "The passwords do not match")
+    );
+
+    password_test!(
+        validators_are_applied_to_first_entry_only,
+        text_to_events!("abc\ndef\nabcdef\n"),
+        "abcdef",
+        default()
+            .with_validator(&|ans| match ans.len() > 5 {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(ErrorMessage::Default)),
+            })
+            .with_confirmation("Confirm:", "The passwords do not match")
+    );
+
+    #[test]
+    fn mismatch_error_is_displayed_above_first_entry() {
+        let read: Vec<KeyEvent> = text_to_events!("ab\nac\nab\nab\n")
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Password::new("Password:")
+            .with_display_mode(PasswordDisplayMode::Masked)
+            .with_confirmation("Confirm:", "The passwords do not match")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("ab", ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Confirm: **"));
+
+        let error = output.find("# The passwords do not match").unwrap();
+        assert!(output[error..].contains("Password: **"));
+    }
+
+    #[test]
+    fn display_toggle_is_kept_in_confirmation_stage() {
+        let mut read = vec![KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)];
+        read.extend(text_to_events!("abc\nxyz").map(KeyEvent::from));
+        read.push(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        read.extend(vec![KeyEvent::from(KeyCode::Backspace); 3]);
+        read.extend(text_to_events!("abc\n").map(KeyEvent::from));
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Password::new("Password:")
+            .with_display_mode(PasswordDisplayMode::Masked)
+            .with_display_toggle_enabled()
+            .with_confirmation("Confirm:", "The passwords do not match")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("abc", ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Password: abc"));
+        assert!(output.contains("Confirm: xyz"));
+        assert!(output.contains("Confirm: ***"));
+        assert!(!output.contains("Confirm: abc"));
+    }
 }
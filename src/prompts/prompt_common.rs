@@ -7,6 +7,15 @@ macro_rules! cancel_prompt {
     }};
 }
 
+macro_rules! timeout_prompt {
+    ($backend:expr, $prompt_message:expr) => {{
+        $backend.frame_setup()?;
+        $backend.render_canceled_prompt($prompt_message)?;
+        $backend.frame_finish()?;
+        return Err(InquireError::Timeout);
+    }};
+}
+
 macro_rules! interrupt_prompt {
     () => {
         return Err(InquireError::OperationInterrupted)
@@ -0,0 +1,788 @@
+use std::{fmt::Display, ops::RangeInclusive, time::Duration};
+
+use crate::{
+    config::{self, get_configuration},
+    error::{InquireError, InquireResult},
+    formatter::QuantityFormatter,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    type_aliases::QuantityLimits,
+    ui::{Backend, Key, KeyModifiers, QuantitySelectBackend, RenderConfig},
+    utils::paginate,
+};
+
+/// Prompt suitable for when you need the user to pick how many of each option
+/// they want, such as the runners of a build matrix or the instances of a
+/// resource request.
+///
+/// Each option is displayed along with its count, in place of the checkbox of
+/// [`MultiSelect`](crate::MultiSelect) prompts. The user can increase the count
+/// of the highlighted option by pressing the right arrow or `+`, decrease it by
+/// pressing the left arrow or `-`, or set it directly by typing a number. A
+/// count of zero means the option is not selected, and it is always allowed
+/// regardless of the limits of the option. Typed counts are clamped to the
+/// limits of the option when the cursor leaves it or the answer is submitted.
+///
+/// The prompt returns the options with a count greater than zero, in the order
+/// of the list, along with their counts.
+///
+/// Like all others, this prompt also allows you to customize several aspects of it:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options displayed to the user. Must be **non-empty**.
+/// - **Default counts**: Counts of options when the prompt is first rendered, by their index. Options without a default count start at their minimum. If any of the indices is out-of-range of the option list, or any of the non-zero counts is out of the limits of its option, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Limits**: Function that defines the minimum and maximum counts of each option. Options can always be set back to zero, even when their minimum is greater than zero. By default, counts range from zero up to `u32::MAX`. If any of the ranges is empty, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the count and the string value of each selected option, joined by a comma and a space, by default.
+/// - **Page size**: Number of options displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::QuantitySelect;
+///
+/// let runners = vec!["linux", "windows", "macos"];
+///
+/// let ans = QuantitySelect::new("How many runners of each kind do you need?", runners)
+///     .with_default(&[(0, 2)])
+///     .with_limits(&|_, _| 0..=8)
+///     .prompt();
+///
+/// match ans {
+///     Ok(runners) => println!("Requesting {} kinds of runners", runners.len()),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct QuantitySelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Default counts of options, by their index.
+    pub default: Option<&'a [(usize, u32)]>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the options displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using jk and change their
+    /// counts using hl.
+    pub vim_mode: bool,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Function called with each option to define its minimum and maximum counts.
+    pub limits: Option<QuantityLimits<'a, T>>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: QuantityFormatter<'a, T>,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with the counts the options start at.
+    pub timeout: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> QuantitySelect<'a, T>
+where
+    T: Display,
+{
+    /// Default formatter, which prints the count and the string value of each
+    /// selected option, separated by a comma and a space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::QuantitySelect;
+    ///
+    /// let formatter = QuantitySelect::<&str>::DEFAULT_FORMATTER;
+    /// let ans = vec![(ListOption::new(0, &"linux"), 4), (ListOption::new(2, &"macos"), 1)];
+    /// assert_eq!(String::from("4 x linux, 1 x macos"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: QuantityFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|(opt, count)| format!("{} x {}", count, opt))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, ←→ or +- to change the count, type to set it");
+
+    /// Creates a [QuantitySelect] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            limits: None,
+            formatter: Self::DEFAULT_FORMATTER,
+            timeout: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disabled vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the starting cursor index.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the default counts of options, as pairs of option index and count.
+    pub fn with_default(mut self, default: &'a [(usize, u32)]) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the function that defines the minimum and maximum counts of each option.
+    ///
+    /// A count of zero, which deselects the option, is always allowed.
+    pub fn with_limits(mut self, limits: QuantityLimits<'a, T>) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: QuantityFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting the counts the options start at. The remaining time is
+    /// displayed in the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned values of the options with a count greater than
+    /// zero, along with their counts.
+    pub fn prompt(self) -> InquireResult<Vec<(T, u32)>> {
+        self.raw_prompt().map(|options| {
+            options
+                .into_iter()
+                .map(|(option, count)| (option.value, count))
+                .collect()
+        })
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<(T, u32)>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the options with a count greater than zero, where each
+    /// [`ListOption`](crate::list_option::ListOption) contains the index
+    /// of the option in the original list and its owned value, along with
+    /// their counts.
+    pub fn raw_prompt(self) -> InquireResult<Vec<(ListOption<T>, u32)>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(in crate) fn prompt_with_backend<B: QuantitySelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<(ListOption<T>, u32)>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        QuantitySelectPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct QuantitySelectPrompt<'a, T> {
+    message: &'a str,
+    options: Vec<T>,
+    counts: Vec<u32>,
+    limits: Vec<RangeInclusive<u32>>,
+    help_message: Option<&'a str>,
+    vim_mode: bool,
+    cursor_index: usize,
+    page_size: usize,
+    typing: bool,
+    formatter: QuantityFormatter<'a, T>,
+}
+
+impl<'a, T> QuantitySelectPrompt<'a, T>
+where
+    T: Display,
+{
+    fn new(qso: QuantitySelect<'a, T>) -> InquireResult<Self> {
+        if qso.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if qso.starting_cursor >= qso.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                qso.starting_cursor,
+                &qso.options.len()
+            )));
+        }
+
+        let limits: Vec<RangeInclusive<u32>> = match qso.limits {
+            Some(limits) => qso
+                .options
+                .iter()
+                .enumerate()
+                .map(|(i, opt)| limits(opt, i))
+                .collect(),
+            None => vec![0..=u32::MAX; qso.options.len()],
+        };
+
+        if let Some(i) = limits.iter().position(RangeInclusive::is_empty) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Limits of option {} can not be empty",
+                i
+            )));
+        }
+
+        let mut counts: Vec<u32> = limits.iter().map(|range| *range.start()).collect();
+
+        for (i, count) in qso.default.unwrap_or_default() {
+            match limits.get(*i) {
+                Some(range) if *count == 0 || range.contains(count) => counts[*i] = *count,
+                Some(_) => {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Default count {} of option {} is out of its limits",
+                        count, i
+                    )))
+                }
+                None => {
+                    return Err(InquireError::InvalidConfiguration(format!(
+                        "Index {} is out-of-bounds for length {} of options",
+                        i,
+                        &qso.options.len()
+                    )))
+                }
+            }
+        }
+
+        Ok(Self {
+            message: qso.message,
+            options: qso.options,
+            counts,
+            limits,
+            help_message: qso.help_message,
+            vim_mode: qso.vim_mode,
+            cursor_index: qso.starting_cursor,
+            page_size: qso.page_size,
+            typing: false,
+            formatter: qso.formatter,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
+        if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index = self
+                .cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.options.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index = self.cursor_index.saturating_sub(qty);
+        }
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
+        self.cursor_index = self.cursor_index.saturating_add(qty);
+
+        if self.cursor_index >= self.options.len() {
+            self.cursor_index = if wrap {
+                self.cursor_index % self.options.len()
+            } else {
+                self.options.len().saturating_sub(1)
+            }
+        }
+    }
+
+    /// Sets the count of the highlighted option, clamped to its limits unless
+    /// it is zero.
+    fn set_count(&mut self, count: u32) {
+        let range = &self.limits[self.cursor_index];
+
+        self.counts[self.cursor_index] = match count {
+            0 => 0,
+            count => count.max(*range.start()).min(*range.end()),
+        };
+    }
+
+    fn increment(&mut self) {
+        self.set_count(self.counts[self.cursor_index].saturating_add(1));
+    }
+
+    /// Decrements the count of the highlighted option, dropping it to zero
+    /// once it goes below the minimum of the option.
+    fn decrement(&mut self) {
+        let count = self.counts[self.cursor_index];
+
+        match count > *self.limits[self.cursor_index].start() {
+            true => self.set_count(count - 1),
+            false => self.set_count(0),
+        }
+    }
+
+    /// Appends the digit to the count being typed for the highlighted option,
+    /// starting a new count when the previous key was not a digit.
+    ///
+    /// The typed count is kept as is until [`Self::finish_typing`] is called,
+    /// so that the limits do not interfere with multi-digit counts.
+    fn type_digit(&mut self, digit: char) {
+        let digit = digit.to_digit(10).unwrap_or_default();
+        let count = match self.typing {
+            true => self.counts[self.cursor_index]
+                .saturating_mul(10)
+                .saturating_add(digit),
+            false => digit,
+        };
+
+        self.counts[self.cursor_index] = count;
+        self.typing = true;
+    }
+
+    /// Clamps the count typed for the highlighted option, if any, to its limits.
+    fn finish_typing(&mut self) {
+        if std::mem::replace(&mut self.typing, false) {
+            self.set_count(self.counts[self.cursor_index]);
+        }
+    }
+
+    fn on_change(&mut self, key: Key) {
+        if let Key::Char(c, KeyModifiers::NONE) = key {
+            if c.is_ascii_digit() {
+                self.type_digit(c);
+                return;
+            }
+        }
+
+        self.finish_typing();
+
+        match key {
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Home => self.move_cursor_up(usize::MAX, false),
+
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::PageDown => self.move_cursor_down(self.page_size, false),
+            Key::End => self.move_cursor_down(usize::MAX, false),
+
+            Key::Right(KeyModifiers::NONE) | Key::Char('+', _) => self.increment(),
+            Key::Char('l', KeyModifiers::NONE) if self.vim_mode => self.increment(),
+            Key::Left(KeyModifiers::NONE) | Key::Char('-', _) => self.decrement(),
+            Key::Char('h', KeyModifiers::NONE) if self.vim_mode => self.decrement(),
+
+            _ => {}
+        }
+    }
+
+    fn get_final_answer(mut self) -> Vec<(ListOption<T>, u32)> {
+        self.finish_typing();
+
+        let counts = self.counts;
+
+        self.options
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| counts[*i] > 0)
+            .map(|(i, value)| (ListOption::new(i, value), counts[i]))
+            .collect()
+    }
+
+    fn render<B: QuantitySelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        backend.render_quantity_select_prompt(prompt)?;
+
+        let options = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, opt)| ListOption::new(i, opt))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.page_size, &options, self.cursor_index);
+
+        backend.render_quantities(page, &self.counts)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: QuantitySelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<(ListOption<T>, u32)>> {
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout | Key::Submit => break,
+                key => self.on_change(key),
+            }
+        }
+
+        let message = self.message;
+        let formatter = self.formatter;
+        let final_answer = self.get_final_answer();
+        let refs = final_answer
+            .iter()
+            .map(|(option, count)| (option.as_ref(), *count))
+            .collect::<Vec<_>>();
+        let formatted = formatter(&refs);
+
+        finish_prompt_with_answer!(backend, message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        list_option::ListOption,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{quantity::QuantityRenderConfig, Backend, RenderConfig, Styled},
+        QuantitySelect,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    #[test]
+    fn arrows_and_signs_change_counts_within_limits() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Right,
+            KeyCode::Char('+'),
+            KeyCode::Char('+'),
+            KeyCode::Left,
+            KeyCode::Up,
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux", "windows", "macos"])
+            .with_limits(&|runner, _| match *runner {
+                "macos" => 0..=1,
+                _ => 0..=8,
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (ListOption::new(0, "linux"), 2),
+                (ListOption::new(2, "macos"), 1)
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn typed_digits_set_the_count() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('1'),
+            KeyCode::Char('5'),
+            KeyCode::Down,
+            KeyCode::Char('4'),
+            KeyCode::Char('2'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux", "windows"])
+            .with_default(&[(1, 3)])
+            .with_limits(&|_, _| 0..=20)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (ListOption::new(0, "linux"), 15),
+                (ListOption::new(1, "windows"), 20),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn typed_counts_are_clamped_when_finished() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('1'),
+            KeyCode::Char('5'),
+            KeyCode::Down,
+            KeyCode::Char('1'),
+            KeyCode::Up,
+            KeyCode::Char('2'),
+            KeyCode::Char('5'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux", "windows"])
+            .with_limits(&|_, _| 2..=20)
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (ListOption::new(0, "linux"), 20),
+                (ListOption::new(1, "windows"), 2),
+            ],
+            ans
+        );
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> [15] linux\r\n  [ 2] windows\r\n"));
+        assert!(output.contains("  [15] linux\r\n> [ 1] windows\r\n"));
+    }
+
+    #[test]
+    fn typed_count_within_limits_is_kept() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('1'), KeyCode::Char('5'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux"])
+            .with_limits(&|_, _| 2..=20)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![(ListOption::new(0, "linux"), 15)], ans);
+    }
+
+    #[test]
+    fn options_with_minimum_can_be_deselected() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Right,
+            KeyCode::Left,
+            KeyCode::Left,
+            KeyCode::Down,
+            KeyCode::Right,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux", "windows"])
+            .with_default(&[(1, 0)])
+            .with_limits(&|_, _| 2..=20)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![(ListOption::new(1, "windows"), 2)], ans);
+    }
+
+    #[test]
+    fn options_at_zero_are_not_selected() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Left, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux", "windows"])
+            .with_default(&[(0, 1), (1, 2)])
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![(ListOption::new(1, "windows"), 2)], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> [1] linux\r\n  [2] windows\r\n"));
+        assert!(output.contains("> [0] linux\r\n  [2] windows\r\n"));
+    }
+
+    #[test]
+    fn counts_are_rendered_with_the_quantity_config() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let config = RenderConfig::empty().with_quantity_config(
+            QuantityRenderConfig::empty().with_delimiters(Styled::new(""), Styled::new("x")),
+        );
+        let mut backend = Backend::new(terminal, config).unwrap();
+
+        QuantitySelect::new("Runners", vec!["linux", "windows"])
+            .with_default(&[(0, 12)])
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> 12x linux\r\n   0x windows\r\n"));
+    }
+
+    #[test]
+    fn default_count_out_of_limits_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = QuantitySelect::new("Runners", vec!["linux"])
+            .with_default(&[(0, 5)])
+            .with_limits(&|_, _| 0..=4)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+}
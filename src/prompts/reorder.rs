@@ -0,0 +1,676 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::{self, get_configuration},
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    ui::{Backend, Key, KeyModifiers, RenderConfig, ReorderBackend},
+    utils::paginate,
+    validator::{ErrorMessage, MultiOptionValidator, Validation},
+};
+
+/// Prompt suitable for when you need the user to sort a list of options,
+/// such as ranking priorities or defining the order of steps.
+///
+/// The user can move the highlighted option one row at a time by pressing
+/// shift and the up or down arrows. Alternatively, the highlighted option can
+/// be grabbed by pressing space, after which the arrows, page up, page down,
+/// home and end keys move the option itself instead of the cursor, until space
+/// is pressed again to drop it. The option being moved is displayed with the
+/// moving prefix and style of the [`RenderConfig`].
+///
+/// The prompt returns all options in the order defined by the user, where each
+/// [`ListOption`] keeps the index of the option in the original list.
+///
+/// Like all others, this prompt also allows you to customize several aspects of it:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Options list**: Options to sort. Must be **non-empty**.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the string value of all options, in the final order, joined by a comma and a space by default.
+/// - **Validator**: Custom validator to make sure the submitted order passes the specified requirements, e.g. keeping a given option on top.
+///   - No validators are on by default.
+/// - **Page size**: Number of options displayed at once, 7 by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::Reorder;
+///
+/// let options = vec!["Performance", "Reliability", "Security", "Usability"];
+///
+/// let ans = Reorder::new("Rank these qualities by priority:", options).prompt();
+///
+/// match ans {
+///     Ok(ranking) => println!("Your top priority is {}", ranking[0]),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct Reorder<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the options displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using jk and move them using
+    /// shift+jk.
+    pub vim_mode: bool,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Function that formats the options in their final order and presents
+    /// them to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// Validator to apply to the options in their final order.
+    ///
+    /// When the validator returns an error, the error message is displayed
+    /// and the user is able to keep moving the options before submitting again.
+    pub validator: Option<MultiOptionValidator<'a, T>>,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with the options in their original order. If the validator rejects that
+    /// order, the prompt fails with [`InquireError::Timeout`] instead.
+    pub timeout: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> Reorder<'a, T>
+where
+    T: Display,
+{
+    /// Default formatter, which prints the string value of all options
+    /// in their final order, separated by a comma and a space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::Reorder;
+    ///
+    /// let formatter = Reorder::<&str>::DEFAULT_FORMATTER;
+    /// let ans = vec![ListOption::new(2, &"c"), ListOption::new(0, &"a")];
+    /// assert_eq!(String::from("c, a"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
+
+    /// Default starting cursor index.
+    pub const DEFAULT_STARTING_CURSOR: usize = 0;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, shift+↑↓ or space to reorder, enter to submit");
+
+    /// Creates a [Reorder] with the provided message and options, along with default configuration values.
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self {
+            message,
+            options,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            formatter: Self::DEFAULT_FORMATTER,
+            validator: None,
+            timeout: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disabled vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the starting cursor index.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the validator to apply to the options in their final order.
+    pub fn with_validator(mut self, validator: MultiOptionValidator<'a, T>) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting the options in their original order. When the validator
+    /// rejects it, the prompt fails with [`InquireError::Timeout`] instead. The
+    /// remaining time is displayed in the help line until the first key is
+    /// pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned options in the order defined by the user.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|options| options.into_iter().map(|option| option.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the options in the order defined by the user, where each
+    /// [`ListOption`](crate::list_option::ListOption) contains the index
+    /// of the option in the original list and its owned value.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(in crate) fn prompt_with_backend<B: ReorderBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        ReorderPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct ReorderPrompt<'a, T> {
+    message: &'a str,
+    options: Vec<T>,
+    order: Vec<usize>,
+    help_message: Option<&'a str>,
+    vim_mode: bool,
+    cursor_index: usize,
+    page_size: usize,
+    moving: bool,
+    formatter: MultiOptionFormatter<'a, T>,
+    validator: Option<MultiOptionValidator<'a, T>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> ReorderPrompt<'a, T>
+where
+    T: Display,
+{
+    fn new(ro: Reorder<'a, T>) -> InquireResult<Self> {
+        if ro.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if ro.starting_cursor >= ro.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                ro.starting_cursor,
+                &ro.options.len()
+            )));
+        }
+
+        Ok(Self {
+            message: ro.message,
+            order: (0..ro.options.len()).collect(),
+            options: ro.options,
+            help_message: ro.help_message,
+            vim_mode: ro.vim_mode,
+            cursor_index: ro.starting_cursor,
+            page_size: ro.page_size,
+            moving: false,
+            formatter: ro.formatter,
+            validator: ro.validator,
+            error: None,
+        })
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
+        if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index = self
+                .cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.order.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index = self.cursor_index.saturating_sub(qty);
+        }
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
+        self.cursor_index = self.cursor_index.saturating_add(qty);
+
+        if self.cursor_index >= self.order.len() {
+            self.cursor_index = if wrap {
+                self.cursor_index % self.order.len()
+            } else {
+                self.order.len().saturating_sub(1)
+            }
+        }
+    }
+
+    /// Moves the highlighted option to the given position, shifting the
+    /// ones in between, and keeps the cursor on it.
+    fn move_option_to(&mut self, position: usize) {
+        let position = position.min(self.order.len().saturating_sub(1));
+        let option = self.order.remove(self.cursor_index);
+
+        self.order.insert(position, option);
+        self.cursor_index = position;
+    }
+
+    fn move_option_up(&mut self, qty: usize) {
+        self.move_option_to(self.cursor_index.saturating_sub(qty));
+    }
+
+    fn move_option_down(&mut self, qty: usize) {
+        self.move_option_to(self.cursor_index.saturating_add(qty));
+    }
+
+    fn on_change(&mut self, key: Key) {
+        let moving = self.moving;
+
+        match key {
+            Key::Char(' ', KeyModifiers::NONE) => self.moving = !self.moving,
+
+            Key::Up(KeyModifiers::SHIFT) => self.move_option_up(1),
+            Key::Char('K', _) if self.vim_mode => self.move_option_up(1),
+            Key::Down(KeyModifiers::SHIFT) => self.move_option_down(1),
+            Key::Char('J', _) if self.vim_mode => self.move_option_down(1),
+
+            Key::Up(KeyModifiers::NONE) if moving => self.move_option_up(1),
+            Key::Char('k', KeyModifiers::NONE) if moving && self.vim_mode => self.move_option_up(1),
+            Key::PageUp if moving => self.move_option_up(self.page_size),
+            Key::Home if moving => self.move_option_to(0),
+
+            Key::Down(KeyModifiers::NONE) if moving => self.move_option_down(1),
+            Key::Char('j', KeyModifiers::NONE) if moving && self.vim_mode => {
+                self.move_option_down(1)
+            }
+            Key::PageDown if moving => self.move_option_down(self.page_size),
+            Key::End if moving => self.move_option_to(usize::MAX),
+
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Home => self.move_cursor_up(usize::MAX, false),
+
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::PageDown => self.move_cursor_down(self.page_size, false),
+            Key::End => self.move_cursor_down(usize::MAX, false),
+
+            _ => {}
+        }
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(validator) = self.validator {
+            let options = self
+                .order
+                .iter()
+                .map(|idx| ListOption::new(*idx, &self.options[*idx]))
+                .collect::<Vec<ListOption<&T>>>();
+
+            let res = validator(&options)?;
+            Ok(res)
+        } else {
+            Ok(Validation::Valid)
+        }
+    }
+
+    fn get_final_answer(self) -> Vec<ListOption<T>> {
+        let mut positions = vec![0; self.order.len()];
+
+        for (position, idx) in self.order.iter().enumerate() {
+            positions[*idx] = position;
+        }
+
+        let mut answer = self
+            .options
+            .into_iter()
+            .enumerate()
+            .map(|(idx, value)| ListOption::new(idx, value))
+            .collect::<Vec<ListOption<T>>>();
+        answer.sort_by_key(|option| positions[option.index]);
+
+        answer
+    }
+
+    fn render<B: ReorderBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_reorder_prompt(prompt)?;
+
+        // options are indexed by their current position, displayed by index prefixes
+        let options = self
+            .order
+            .iter()
+            .enumerate()
+            .map(|(position, idx)| ListOption::new(position, &self.options[*idx]))
+            .collect::<Vec<ListOption<&T>>>();
+
+        let page = paginate(self.page_size, &options, self.cursor_index);
+
+        backend.render_reorder_options(page, self.moving)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: ReorderBackend>(mut self, backend: &mut B) -> InquireResult<Vec<ListOption<T>>> {
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match self.validate_current_answer()? {
+                    Validation::Valid => break,
+                    Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                },
+                Key::Submit => match self.validate_current_answer()? {
+                    Validation::Valid => break,
+                    Validation::Invalid(msg) => self.error = Some(msg),
+                },
+                key => self.on_change(key),
+            }
+        }
+
+        let message = self.message;
+        let formatter = self.formatter;
+        let final_answer = self.get_final_answer();
+        let formatted = formatter(
+            &final_answer
+                .iter()
+                .map(ListOption::as_ref)
+                .collect::<Vec<_>>(),
+        );
+
+        finish_prompt_with_answer!(backend, message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        list_option::ListOption,
+        terminal::crossterm::CrosstermTerminal,
+        ui::{Backend, RenderConfig},
+        validator::Validation,
+        Reorder,
+    };
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::time::Duration;
+
+    #[test]
+    fn submitting_right_away_keeps_the_original_order() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Reorder::new("Rank", vec!["a", "b", "c"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(0, "a"),
+                ListOption::new(1, "b"),
+                ListOption::new(2, "c"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn shift_arrows_move_the_highlighted_option() {
+        let read: Vec<KeyEvent> = vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::from(KeyCode::Down),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT),
+            KeyEvent::from(KeyCode::End),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Reorder::new("Rank", vec!["a", "b", "c", "d"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(2, "c"),
+                ListOption::new(0, "a"),
+                ListOption::new(1, "b"),
+                ListOption::new(3, "d"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn grabbed_option_moves_with_the_cursor_until_dropped() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Home,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Reorder::new("Rank", vec!["a", "b", "c", "d"])
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(3, "d"),
+                ListOption::new(1, "b"),
+                ListOption::new(2, "c"),
+                ListOption::new(0, "a"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn grabbed_option_is_displayed_with_the_moving_prefix() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char(' '), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        Reorder::new("Rank", vec!["a", "b"])
+            .without_help_message()
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> a\r\n  b\r\n"));
+        assert!(output.contains("↕ a\r\n  b\r\n"));
+    }
+
+    #[test]
+    fn validator_is_applied_to_the_final_order() {
+        let read: Vec<KeyEvent> = vec![
+            KeyEvent::from(KeyCode::Enter),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT),
+            KeyEvent::from(KeyCode::Enter),
+        ];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Reorder::new("Rank", vec!["a", "b", "c"])
+            .with_validator(&|ans| {
+                Ok(match ans[0].index {
+                    0 => Validation::Invalid("Move 'a' down".into()),
+                    _ => Validation::Valid,
+                })
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, "b"), ans[0]);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Move 'a' down"));
+    }
+
+    #[test]
+    fn timeout_resolves_to_original_order() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Reorder::new("Rank", vec!["a", "b"])
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(1, "b")], ans);
+    }
+}
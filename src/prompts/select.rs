@@ -1,15 +1,29 @@
-use std::fmt::Display;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     config::{self, get_configuration},
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
     input::Input,
-    list_option::ListOption,
+    list_option::{ListOption, NumericJump, OptionGroup, SelectOrCreate},
+    option_source::{OptionSource, REFRESH_INTERVAL},
     terminal::get_default_terminal,
-    type_aliases::Filter,
-    ui::{Backend, Key, KeyModifiers, RenderConfig, SelectBackend},
-    utils::paginate,
+    type_aliases::{Filter, OptionDisabler, OptionHotkey, Previewer},
+    ui::{
+        Backend, Key, KeyModifiers, OptionLayout, Preview, PreviewPosition, RenderConfig,
+        SelectBackend,
+    },
+    utils::{
+        append_group_rows, closest_selectable, fold_hotkey, group_rows, is_enabled, paginate_grid,
+        paginate_preview, paginate_with_headers, IndexJump, OptionRow, Page, RowIndex,
+    },
+    validator::{ErrorMessage, StringValidator, Validation},
 };
 
 /// Prompt suitable for when you need the user to select one option among many.
@@ -33,7 +47,15 @@ use crate::{
 ///   - Prints the selected option string value by default.
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
+/// - **Layout**: Options are displayed one per row by default, or flowing into as many columns as fit the terminal width with the grid layout, suitable for short options. In grids, the left and right arrows move between columns, and the page size counts lines of the grid. Option groups are not supported in grids, failing with an [`InquireError::InvalidConfiguration`] error.
+/// - **Numeric jump**: Digits typed by the user can move the cursor to the option displayed with that index in the current filtered list, instead of being part of the filter input. Digits typed in quick succession form multi-digit indexes, and the option can optionally be submitted as soon as typing more digits could not lead to another one. Disabled by default.
 /// - **Filter function**: Function that defines if an option is displayed or not based on the current filter input.
+/// - **Disabled options**: Function that defines if an option is disabled, along with the reason why it can not be selected. Disabled options are still displayed, using the disabled style of the `RenderConfig` followed by their reason, but they can not be highlighted.
+/// - **Hotkeys**: Function that assigns a single-character hotkey to options, e.g. `y` to `yes` and `q` to `quit`. Pressing the hotkey submits its option right away, if displayed and enabled, instead of being part of the filter input. Hotkeys are matched ignoring case. Hotkeys are highlighted inside the options using the hotkey style of the `RenderConfig`. If a hotkey is assigned to several options, or conflicts with the keys used by vim mode or numeric jumps, the prompt will fail with an [`InquireError::InvalidConfiguration`] error. Options loaded from a source have their hotkeys checked as they are loaded, so such errors can happen after the prompt is displayed. Hotkeys are not supported when prompting via [`prompt_or_create`](Select::prompt_or_create), as the user must be able to type any character of new values, also failing with an [`InquireError::InvalidConfiguration`] error.
+/// - **Preview**: Function that builds a multi-line preview of the highlighted option, displayed below or beside the option list within the configured height, 10 lines by default. The user can scroll the preview by pressing ctrl+up and ctrl+down.
+/// - **Option source**: Source from which options are loaded on demand, as the user scrolls or filters the list, instead of being all provided upfront. Useful for very large lists or for options received over time. While the source is not exhausted, the number of options loaded so far is displayed below the list.
+/// - **Option groups**: Groups of consecutive options, displayed below a header with the group title or below a separator. Headers and separators can not be highlighted and groups with no options matching the current filter are hidden. If any of the groups is out-of-range of the option list or overlaps another one, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **New values**: When prompted via [`prompt_or_create`](Select::prompt_or_create), the user can also submit the filter input as a new value, either by highlighting the `+ create 'foo'` row displayed below the options when the input does not exactly match any of them, or by pressing tab. An optional validator is applied to new values, which are returned as [`SelectOrCreate::Created`]. The option list may be empty in this case.
 ///
 /// # Example
 ///
@@ -61,6 +83,9 @@ pub struct Select<'a, T> {
     /// Options displayed to the user.
     pub options: Vec<T>,
 
+    /// Source of additional options, loaded on demand after the ones in `options`.
+    pub source: Option<OptionSource<'a, T>>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
@@ -71,16 +96,50 @@ pub struct Select<'a, T> {
     /// navigate through the options using hjkl.
     pub vim_mode: bool,
 
+    /// Behavior of the digits typed by the user.
+    pub numeric_jump: NumericJump,
+
+    /// Layout of the displayed options.
+    pub layout: OptionLayout,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Groups of options, displayed below a header or a separator.
+    pub groups: Vec<OptionGroup<'a>>,
+
     /// Function called with the current user input to filter the provided
     /// options.
     pub filter: Filter<'a, T>,
 
+    /// Function called with each option to check whether it is disabled,
+    /// returning the reason why it can not be selected.
+    pub disabler: Option<OptionDisabler<'a, T>>,
+
+    /// Function called with each option to get its hotkey, which submits the
+    /// option when pressed.
+    pub hotkey: Option<OptionHotkey<'a, T>>,
+
+    /// Function called with the highlighted option to build its preview.
+    pub previewer: Option<Previewer<'a, T>>,
+
+    /// Maximum number of preview lines displayed at once.
+    pub preview_height: usize,
+
+    /// Position of the preview in relation to the option list.
+    pub preview_position: PreviewPosition,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Validator applied to new values typed by the user, when the prompt
+    /// accepts them.
+    pub creation_validator: Option<StringValidator<'a>>,
+
+    /// Time to wait for the user to press a key before the prompt fails with
+    /// [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -146,9 +205,21 @@ where
     /// Default value of vim mode.
     pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
 
+    /// Default behavior of typed digits, which are part of the filter input.
+    pub const DEFAULT_NUMERIC_JUMP: NumericJump = NumericJump::Disabled;
+
+    /// Default layout, displaying one option per row.
+    pub const DEFAULT_LAYOUT: OptionLayout = OptionLayout::List;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
+    /// Default maximum number of preview lines displayed at once.
+    pub const DEFAULT_PREVIEW_HEIGHT: usize = 10;
+
+    /// Default position of the preview.
+    pub const DEFAULT_PREVIEW_POSITION: PreviewPosition = PreviewPosition::Below;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
@@ -158,16 +229,40 @@ where
         Self {
             message,
             options,
+            source: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
             vim_mode: Self::DEFAULT_VIM_MODE,
+            numeric_jump: Self::DEFAULT_NUMERIC_JUMP,
+            layout: Self::DEFAULT_LAYOUT,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            groups: Vec::new(),
             filter: Self::DEFAULT_FILTER,
+            disabler: None,
+            hotkey: None,
+            previewer: None,
+            preview_height: Self::DEFAULT_PREVIEW_HEIGHT,
+            preview_position: Self::DEFAULT_PREVIEW_POSITION,
             formatter: Self::DEFAULT_FORMATTER,
+            creation_validator: None,
+            timeout: None,
             render_config: get_configuration(),
         }
     }
 
+    /// Creates a [Select] with the provided message, loading its options on demand
+    /// from the given source, along with default configuration values.
+    pub fn from_source(message: &'a str, source: OptionSource<'a, T>) -> Self {
+        Self::new(message, Vec::new()).with_source(source)
+    }
+
+    /// Sets the source from which options are loaded on demand, after the
+    /// options provided in the constructor.
+    pub fn with_source(mut self, source: OptionSource<'a, T>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
     /// Sets the help message of the prompt.
     pub fn with_help_message(mut self, message: &'a str) -> Self {
         self.help_message = Some(message);
@@ -192,24 +287,107 @@ where
         self
     }
 
+    /// Sets the layout of the displayed options.
+    pub fn with_layout(mut self, layout: OptionLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the behavior of the digits typed by the user, which can move the
+    /// cursor to the option displayed with the typed index.
+    pub fn with_numeric_jump(mut self, numeric_jump: NumericJump) -> Self {
+        self.numeric_jump = numeric_jump;
+        self
+    }
+
     /// Sets the filter function.
     pub fn with_filter(mut self, filter: Filter<'a, T>) -> Self {
         self.filter = filter;
         self
     }
 
+    /// Sets the function that defines which options are disabled.
+    ///
+    /// Disabled options are displayed along with the returned reason, but
+    /// can not be highlighted nor submitted.
+    pub fn with_disabler(mut self, disabler: OptionDisabler<'a, T>) -> Self {
+        self.disabler = Some(disabler);
+        self
+    }
+
+    /// Sets the function that assigns hotkeys to options.
+    ///
+    /// Pressing the hotkey of an option submits it right away, as long as it
+    /// is displayed and enabled, and hotkeys are never part of the filter input.
+    /// Hotkeys are matched ignoring case, just like in
+    /// [`Expand`](crate::Expand) prompts.
+    ///
+    /// Hotkeys of options loaded from a source are validated as the options
+    /// are loaded, which can make the prompt fail after it is displayed.
+    /// Hotkeys can not be used along with
+    /// [`prompt_or_create`](Select::prompt_or_create).
+    pub fn with_hotkeys(mut self, hotkey: OptionHotkey<'a, T>) -> Self {
+        self.hotkey = Some(hotkey);
+        self
+    }
+
+    /// Sets the function that builds the preview of the highlighted option.
+    pub fn with_previewer(mut self, previewer: Previewer<'a, T>) -> Self {
+        self.previewer = Some(previewer);
+        self
+    }
+
+    /// Sets the maximum number of preview lines displayed at once.
+    pub fn with_preview_height(mut self, preview_height: usize) -> Self {
+        self.preview_height = preview_height;
+        self
+    }
+
+    /// Sets the position of the preview in relation to the option list.
+    pub fn with_preview_position(mut self, preview_position: PreviewPosition) -> Self {
+        self.preview_position = preview_position;
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets the validator applied to new values typed by the user, when
+    /// prompted via [`prompt_or_create`](Select::prompt_or_create).
+    pub fn with_creation_validator(mut self, validator: StringValidator<'a>) -> Self {
+        self.creation_validator = Some(validator);
+        self
+    }
+
     /// Sets the starting cursor index.
     pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
         self.starting_cursor = starting_cursor;
         self
     }
 
+    /// Sets the groups of options, displayed below a header containing their
+    /// title or below a separator when they have no title.
+    ///
+    /// Options not contained in any group are displayed without a header.
+    pub fn with_groups(mut self, groups: Vec<OptionGroup<'a>>) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting the option under the starting cursor. When there is none,
+    /// such as when the options of a source are not loaded yet, the prompt
+    /// fails with [`InquireError::Timeout`] instead. The remaining time is
+    /// displayed in the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -259,40 +437,117 @@ where
         self.prompt_with_backend(&mut backend)
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, also accepting
+    /// the filter input as a new value.
+    ///
+    /// Returns either a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user,
+    /// or the new value typed by the user.
+    pub fn prompt_or_create(self) -> InquireResult<SelectOrCreate<T>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_or_create_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, also accepting
+    /// the filter input as a new value.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_or_create_skippable(self) -> InquireResult<Option<SelectOrCreate<T>>> {
+        match self.prompt_or_create() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
     pub(in crate) fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        match SelectPrompt::new(self, false)?.prompt(backend)? {
+            SelectOrCreate::Existing(option) => Ok(option),
+            SelectOrCreate::Created(_) => unreachable!(),
+        }
+    }
+
+    pub(in crate) fn prompt_or_create_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<SelectOrCreate<T>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        SelectPrompt::new(self, true)?.prompt(backend)
     }
 }
 
 struct SelectPrompt<'a, T> {
     message: &'a str,
     options: Vec<T>,
+    source: Option<OptionSource<'a, T>>,
     string_options: Vec<String>,
     filtered_options: Vec<usize>,
+    disabler: Option<OptionDisabler<'a, T>>,
+    disabled: Vec<Option<String>>,
+    hotkey: Option<OptionHotkey<'a, T>>,
+    hotkeys: Vec<Option<char>>,
+    hotkey_options: HashMap<char, usize>,
+    groups: Vec<OptionGroup<'a>>,
+    rows: Vec<RowIndex>,
     help_message: Option<&'a str>,
     vim_mode: bool,
+    numeric_jump: NumericJump,
+    index_jump: IndexJump,
+    layout: OptionLayout,
+    columns: usize,
+    value_width: usize,
     cursor_index: usize,
     page_size: usize,
     input: Input,
     filter: Filter<'a, T>,
     formatter: OptionFormatter<'a, T>,
+    previewer: Option<Previewer<'a, T>>,
+    preview_height: usize,
+    preview_position: PreviewPosition,
+    preview: Option<(usize, Preview)>,
+    preview_offset: usize,
+    creatable: bool,
+    creation_validator: Option<StringValidator<'a>>,
+    error: Option<ErrorMessage>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
 where
     T: Display,
 {
-    fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
+    fn new(mut so: Select<'a, T>, creatable: bool) -> InquireResult<Self> {
+        if let Some(source) = &so.source {
+            let len = so.starting_cursor.saturating_add(1).max(so.page_size);
+            source.load(&mut so.options, len)?;
+        }
+
+        let loading = matches!(&so.source, Some(s) if !s.is_exhausted());
+
+        if so.options.is_empty() && !loading && !creatable {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
 
-        if so.starting_cursor >= so.options.len() {
+        if so.starting_cursor >= so.options.len() && !so.options.is_empty() && !loading {
             return Err(InquireError::InvalidConfiguration(format!(
                 "Starting cursor index {} is out-of-bounds for length {} of options",
                 so.starting_cursor,
@@ -300,100 +555,476 @@ where
             )));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let filtered_options = (0..so.options.len()).collect();
+        let len = match so.source {
+            Some(_) => usize::MAX,
+            None => so.options.len(),
+        };
+        OptionGroup::sort_and_validate(&mut so.groups, len)
+            .map_err(InquireError::InvalidConfiguration)?;
+
+        if so.layout == OptionLayout::Grid && !so.groups.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Option groups are not supported in grid layouts".into(),
+            ));
+        }
+
+        if creatable && so.hotkey.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "Hotkeys are not supported in prompts creating new values".into(),
+            ));
+        }
 
-        Ok(Self {
+        let mut prompt = Self {
             message: so.message,
             options: so.options,
-            string_options,
-            filtered_options,
+            source: so.source,
+            string_options: Vec::new(),
+            filtered_options: Vec::new(),
+            disabler: so.disabler,
+            disabled: Vec::new(),
+            hotkey: so.hotkey,
+            hotkeys: Vec::new(),
+            hotkey_options: HashMap::new(),
+            groups: so.groups,
+            rows: Vec::new(),
             help_message: so.help_message,
             vim_mode: so.vim_mode,
-            cursor_index: so.starting_cursor,
+            numeric_jump: so.numeric_jump,
+            index_jump: IndexJump::default(),
+            layout: so.layout,
+            columns: 1,
+            value_width: 0,
+            cursor_index: 0,
             page_size: so.page_size,
             input: Input::new(),
             filter: so.filter,
             formatter: so.formatter,
-        })
-    }
+            previewer: so.previewer,
+            preview_height: so.preview_height,
+            preview_position: so.preview_position,
+            preview: None,
+            preview_offset: 0,
+            creatable,
+            creation_validator: so.creation_validator,
+            error: None,
+        };
+
+        let starting_row = RowIndex::Option(so.starting_cursor);
 
-    fn filter_options(&self) -> Vec<usize> {
-        self.options
+        prompt.append_options(0)?;
+        prompt.cursor_index = prompt
+            .rows
             .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| match self.input.content() {
-                val if val.is_empty() => Some(i),
-                val if (self.filter)(val, opt, self.string_options.get(i).unwrap(), i) => Some(i),
-                _ => None,
-            })
+            .position(|r| *r == starting_row)
+            .unwrap_or_default();
+        prompt.skip_to_selectable(false, true);
+
+        Ok(prompt)
+    }
+
+    /// Returns the indexes of the options, starting from the given one, that
+    /// match the current filter.
+    fn filter_options(&mut self, from: usize) -> Vec<usize> {
+        if self.input.content().is_empty() {
+            return (from..self.options.len()).collect();
+        }
+
+        self.build_string_options();
+
+        let filter = self.input.content();
+
+        (from..self.options.len())
+            .filter(|i| (self.filter)(filter, &self.options[*i], &self.string_options[*i], *i))
             .collect()
     }
 
+    /// Builds the string values of the options loaded since the last call.
+    fn build_string_options(&mut self) {
+        // string values are only needed when filtering or displaying a grid,
+        // so they are built lazily
+        for opt in &self.options[self.string_options.len()..] {
+            self.string_options.push(opt.to_string());
+        }
+    }
+
+    /// Updates the disabled and filtered options, along with the displayed
+    /// rows, to include the options loaded from the given index onwards.
+    fn append_options(&mut self, from: usize) -> InquireResult<()> {
+        if let Some(disabler) = self.disabler {
+            for (i, opt) in self.options.iter().enumerate().skip(from) {
+                self.disabled.push(disabler(opt, i));
+            }
+        }
+
+        if let Some(hotkey) = self.hotkey {
+            for (i, opt) in self.options.iter().enumerate().skip(from) {
+                let key = hotkey(opt, i);
+
+                if let Some(key) = key {
+                    self.validate_hotkey(key, i)?;
+                    self.hotkey_options.insert(fold_hotkey(key), i);
+                }
+
+                self.hotkeys.push(key);
+            }
+        }
+
+        if self.layout == OptionLayout::Grid {
+            self.build_string_options();
+
+            for i in from..self.options.len() {
+                let reason = match self.disabled.get(i) {
+                    Some(Some(reason)) if !reason.is_empty() => reason.width() + 3,
+                    _ => 0,
+                };
+
+                self.value_width = self
+                    .value_width
+                    .max(self.string_options[i].width() + reason);
+            }
+        }
+
+        let filtered = self.filter_options(from);
+        self.append_rows(from, &filtered);
+        self.filtered_options.extend(filtered);
+
+        Ok(())
+    }
+
+    /// Checks that the hotkey of the given option is not assigned to a previous
+    /// option nor used by the prompt itself.
+    fn validate_hotkey(&self, key: char, option: usize) -> InquireResult<()> {
+        if let Some(other) = self.hotkey_option(key) {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Hotkey '{}' is assigned to both options {} and {}",
+                key, other, option
+            )));
+        }
+
+        let reserved = match fold_hotkey(key) {
+            'j' | 'k' => self.vim_mode,
+            'h' | 'l' => self.vim_mode && self.layout == OptionLayout::Grid,
+            '0'..='9' => self.numeric_jump != NumericJump::Disabled,
+            key => key.is_control(),
+        };
+
+        if reserved {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Hotkey '{}' of option {} conflicts with the prompt key bindings",
+                key, option
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the displayed rows from the filtered options, followed by the
+    /// row creating a new value when the prompt accepts them and the filter
+    /// input does not exactly match any of the options.
+    fn update_rows(&mut self) {
+        self.rows = group_rows(&self.groups, &self.filtered_options);
+
+        let input = self.input.content();
+
+        if self.creatable && !input.is_empty() && !self.string_options.iter().any(|s| s == input) {
+            self.rows.push(RowIndex::Create);
+        }
+    }
+
+    /// Appends the rows of the given filtered options, loaded from the given
+    /// index onwards, keeping the row creating a new value last unless one of
+    /// the options exactly matches the filter input.
+    fn append_rows(&mut self, from: usize, filtered: &[usize]) {
+        let creating = self.can_create();
+
+        if creating {
+            self.rows.pop();
+        }
+
+        append_group_rows(&mut self.rows, &self.groups, filtered);
+
+        let input = self.input.content();
+
+        if creating && !self.string_options[from..].iter().any(|s| s == input) {
+            self.rows.push(RowIndex::Create);
+        }
+    }
+
+    /// Whether the row creating a new value is displayed, always as the last row.
+    fn can_create(&self) -> bool {
+        self.rows.last() == Some(&RowIndex::Create)
+    }
+
+    /// Loads options from the source, if any, until there are enough of them
+    /// to display the given number of rows or until the source is exhausted.
+    fn load_options(&mut self, rows: usize) -> InquireResult<()> {
+        let source = match &self.source {
+            Some(source) => source.clone(),
+            None => return Ok(()),
+        };
+
+        // options received by streaming sources are taken as they arrive,
+        // even when the page is already full
+        while (self.rows.len() < rows || source.is_streaming()) && !source.is_exhausted() {
+            let from = self.options.len();
+            let missing = rows.saturating_sub(self.rows.len()).max(self.page_size);
+
+            source.load(&mut self.options, from.saturating_add(missing))?;
+
+            if self.options.len() == from {
+                break;
+            }
+
+            self.append_options(from)?;
+        }
+
+        Ok(())
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
         if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index = self
                 .cursor_index
                 .checked_sub(qty)
-                .unwrap_or_else(|| self.filtered_options.len().saturating_sub(after_wrap))
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
         } else {
             self.cursor_index = self.cursor_index.saturating_sub(qty);
         }
+
+        self.skip_to_selectable(true, wrap);
     }
 
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
         self.cursor_index = self.cursor_index.saturating_add(qty);
 
-        if self.cursor_index >= self.filtered_options.len() {
-            self.cursor_index = if self.filtered_options.is_empty() {
+        if self.cursor_index >= self.rows.len() {
+            self.cursor_index = if self.rows.is_empty() {
                 0
             } else if wrap {
-                self.cursor_index % self.filtered_options.len()
+                self.cursor_index % self.rows.len()
             } else {
-                self.filtered_options.len().saturating_sub(1)
+                self.rows.len().saturating_sub(1)
+            }
+        }
+
+        self.skip_to_selectable(false, wrap);
+    }
+
+    /// Moves the cursor away from group headers, separators and disabled
+    /// options, which can not be highlighted.
+    fn skip_to_selectable(&mut self, up: bool, wrap: bool) {
+        let rows = &self.rows;
+        let disabled = &self.disabled;
+
+        self.cursor_index =
+            closest_selectable(rows.len(), self.cursor_index, up, wrap, |i| match rows[i] {
+                RowIndex::Group(_) => false,
+                RowIndex::Option(idx) => is_enabled(disabled, idx),
+                RowIndex::Create => true,
+            })
+            .unwrap_or_default();
+    }
+
+    /// Option with the given hotkey, if any, ignoring case.
+    fn hotkey_option(&self, key: char) -> Option<usize> {
+        self.hotkey_options.get(&fold_hotkey(key)).copied()
+    }
+
+    /// Moves the cursor to the option with the given hotkey, returning whether
+    /// it is displayed and enabled.
+    fn jump_to_hotkey(&mut self, key: char) -> bool {
+        let option = match self.hotkey_option(key) {
+            Some(option) => option,
+            None => return false,
+        };
+
+        match self
+            .rows
+            .iter()
+            .position(|r| *r == RowIndex::Option(option))
+        {
+            Some(row) if is_enabled(&self.disabled, option) => {
+                self.cursor_index = row;
+                true
             }
+            _ => false,
+        }
+    }
+
+    /// Moves the cursor to the option displayed with the index being typed,
+    /// returning whether it should be submitted right away.
+    fn jump_to_index(&mut self, digit: char) -> bool {
+        let len = self.filtered_options.len();
+        let digit = digit.to_digit(10).unwrap_or_default();
+
+        let index = match self.index_jump.push(digit, len, Instant::now()) {
+            Some(index) => index,
+            None => return false,
+        };
+        let option = self.filtered_options[index - 1];
+
+        match self
+            .rows
+            .iter()
+            .position(|r| *r == RowIndex::Option(option))
+        {
+            Some(row) if is_enabled(&self.disabled, option) => self.cursor_index = row,
+            _ => return false,
+        }
+
+        self.numeric_jump == NumericJump::Pick && IndexJump::is_complete(index, len)
+    }
+
+    /// Whether the index typed in pick mode can no longer be completed with
+    /// more digits, in which case its option, highlighted when the index was
+    /// typed, should be submitted.
+    fn pick_expired_index(&mut self) -> bool {
+        if self.numeric_jump != NumericJump::Pick {
+            return false;
         }
+
+        let option = match self
+            .index_jump
+            .expire(Instant::now())
+            .and_then(|index| self.filtered_options.get(index - 1))
+        {
+            Some(option) => *option,
+            None => return false,
+        };
+
+        self.rows.get(self.cursor_index) == Some(&RowIndex::Option(option))
+            && self.has_answer_highlighted()
     }
 
-    fn on_change(&mut self, key: Key) {
+    fn on_change(&mut self, key: Key) -> InquireResult<()> {
+        self.index_jump.reset();
+
+        // in grids, moving vertically skips a whole line of options
+        let grid = self.layout == OptionLayout::Grid;
+        let line = self.columns;
+        let page = self.page_size.saturating_mul(line);
+
         match key {
-            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
-            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
-            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(line, !grid),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(line, !grid),
+            Key::PageUp => self.move_cursor_up(page, false),
             Key::Home => self.move_cursor_up(usize::MAX, false),
 
-            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
-            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
-            Key::PageDown => self.move_cursor_down(self.page_size, false),
-            Key::End => self.move_cursor_down(usize::MAX, false),
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(line, !grid),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => {
+                self.move_cursor_down(line, !grid)
+            }
+            Key::PageDown => {
+                let rows = self.cursor_index.saturating_add(page);
+                self.load_options(rows.saturating_add(1))?;
+                self.move_cursor_down(page, false)
+            }
+
+            Key::Left(KeyModifiers::NONE) if grid => self.move_cursor_up(1, true),
+            Key::Char('h', KeyModifiers::NONE) if grid && self.vim_mode => {
+                self.move_cursor_up(1, true)
+            }
+            Key::Right(KeyModifiers::NONE) if grid => {
+                self.load_options(self.cursor_index.saturating_add(2))?;
+                self.move_cursor_down(1, true)
+            }
+            Key::Char('l', KeyModifiers::NONE) if grid && self.vim_mode => {
+                self.load_options(self.cursor_index.saturating_add(2))?;
+                self.move_cursor_down(1, true)
+            }
+            Key::End => {
+                self.load_options(usize::MAX)?;
+                self.move_cursor_down(usize::MAX, false)
+            }
+
+            Key::Up(KeyModifiers::CONTROL) => {
+                self.preview_offset = self.preview_offset.saturating_sub(1)
+            }
+            Key::Down(KeyModifiers::CONTROL) => self.scroll_preview_down(),
 
             key => {
                 let dirty = self.input.handle_key(key);
 
                 if dirty {
-                    let options = self.filter_options();
-                    if options.len() <= self.cursor_index {
-                        self.cursor_index = options.len().saturating_sub(1);
+                    self.filtered_options = self.filter_options(0);
+                    self.update_rows();
+                    if self.rows.len() <= self.cursor_index {
+                        self.cursor_index = self.rows.len().saturating_sub(1);
                     }
-                    self.filtered_options = options;
+                    self.skip_to_selectable(false, false);
                 }
             }
         };
+
+        Ok(())
     }
 
     fn has_answer_highlighted(&mut self) -> bool {
-        self.filtered_options.get(self.cursor_index).is_some()
+        self.highlighted_option().is_some()
+    }
+
+    /// Applies the creation validator, if any, to the filter input, keeping
+    /// the error message to be displayed when it is invalid.
+    fn validate_created_value(&mut self) -> InquireResult<bool> {
+        if let Some(validator) = self.creation_validator {
+            if let Validation::Invalid(msg) = validator(self.input.content())? {
+                self.error = Some(msg);
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
     }
 
-    fn get_final_answer(&mut self) -> ListOption<T> {
+    fn get_final_answer(&mut self) -> SelectOrCreate<T> {
         // should only be called after current cursor index is validated
-        // on has_answer_highlighted
+        // on has_answer_highlighted or validate_created_value
+
+        match self.rows.get(self.cursor_index) {
+            Some(RowIndex::Option(index)) => {
+                let index = *index;
+                let value = self.options.swap_remove(index);
+
+                SelectOrCreate::Existing(ListOption::new(index, value))
+            }
+            Some(RowIndex::Create) => SelectOrCreate::Created(self.input.content().to_owned()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn highlighted_option(&self) -> Option<usize> {
+        match self.rows.get(self.cursor_index) {
+            Some(RowIndex::Option(idx)) if is_enabled(&self.disabled, *idx) => Some(*idx),
+            _ => None,
+        }
+    }
+
+    /// Builds the preview of the highlighted option, unless it was already
+    /// built for the same option.
+    fn update_preview(&mut self) -> InquireResult<()> {
+        let previewer = match self.previewer {
+            Some(previewer) => previewer,
+            None => return Ok(()),
+        };
+
+        match self.highlighted_option() {
+            Some(idx) if self.preview.as_ref().map(|(i, _)| *i) != Some(idx) => {
+                self.preview = Some((idx, previewer(&self.options[idx])?));
+                self.preview_offset = 0;
+            }
+            Some(_) => {}
+            None => self.preview = None,
+        }
 
-        let index = *self.filtered_options.get(self.cursor_index).unwrap();
-        let value = self.options.swap_remove(index);
+        Ok(())
+    }
 
-        ListOption::new(index, value)
+    fn scroll_preview_down(&mut self) {
+        if let Some((_, preview)) = &self.preview {
+            let max_offset = preview.lines().len().saturating_sub(self.preview_height);
+            self.preview_offset = self.preview_offset.saturating_add(1).min(max_offset);
+        }
     }
 
     fn render<B: SelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
@@ -401,18 +1032,99 @@ where
 
         backend.frame_setup()?;
 
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
         backend.render_select_prompt(prompt, &self.input)?;
 
-        let choices = self
-            .filtered_options
+        let page_size = self.page_size.saturating_mul(self.columns);
+        self.load_options(self.cursor_index.saturating_add(page_size))?;
+        self.update_preview()?;
+
+        let groups = &self.groups;
+        let page = match self.layout {
+            OptionLayout::List => paginate_with_headers(
+                self.page_size,
+                &self.rows,
+                self.cursor_index,
+                |r| matches!(r, RowIndex::Group(g) if groups[*g].title.is_some()),
+            ),
+            OptionLayout::Grid => {
+                let beside =
+                    self.preview.is_some() && self.preview_position == PreviewPosition::Beside;
+
+                self.columns = backend.grid_columns(self.value_width, self.rows.len(), beside);
+                paginate_grid(self.page_size, &self.rows, self.cursor_index, self.columns)
+            }
+        };
+
+        let choices = page
+            .content
             .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|row| match *row {
+                RowIndex::Group(g) => match self.groups[g].title {
+                    Some(title) => OptionRow::Header {
+                        title,
+                        checked: false,
+                    },
+                    None => OptionRow::Separator,
+                },
+                RowIndex::Option(i) => {
+                    let option = ListOption::new(i, &self.options[i]);
+                    let prefix_index = match self.numeric_jump {
+                        NumericJump::Disabled => i,
+                        _ => self.filtered_options.binary_search(&i).unwrap_or_default(),
+                    };
+
+                    match self.disabled.get(i) {
+                        Some(Some(reason)) => OptionRow::Disabled {
+                            option,
+                            prefix_index,
+                            reason,
+                        },
+                        _ => OptionRow::Option {
+                            option,
+                            prefix_index,
+                            hotkey: self.hotkeys.get(i).copied().flatten(),
+                        },
+                    }
+                }
+                RowIndex::Create => OptionRow::Create {
+                    input: self.input.content(),
+                },
+            })
+            .collect::<Vec<OptionRow<&T>>>();
+
+        let page = Page {
+            first: page.first,
+            last: page.last,
+            content: &choices,
+            selection: page.selection,
+            total: page.total,
+        };
 
-        let page = paginate(self.page_size, &choices, self.cursor_index);
+        let preview = self.preview.as_ref().map(|(_, preview)| {
+            paginate_preview(
+                preview,
+                self.preview_height,
+                self.preview_offset,
+                self.preview_position,
+            )
+        });
+
+        match self.layout {
+            OptionLayout::List => backend.render_options(page, preview)?,
+            OptionLayout::Grid => {
+                backend.render_option_grid(page, self.columns, self.value_width, preview)?
+            }
+        }
 
-        backend.render_options(page)?;
+        if let Some(source) = &self.source {
+            if !source.is_exhausted() {
+                backend.render_loaded_indicator(self.options.len())?;
+            }
+        }
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -423,27 +1135,74 @@ where
         Ok(())
     }
 
-    fn prompt<B: SelectBackend>(mut self, backend: &mut B) -> InquireResult<ListOption<T>> {
+    fn prompt<B: SelectBackend>(mut self, backend: &mut B) -> InquireResult<SelectOrCreate<T>> {
         loop {
             self.render(backend)?;
 
+            let mut refresh = match &self.source {
+                Some(source) if source.is_streaming() => Some(REFRESH_INTERVAL),
+                _ => None,
+            };
+            // wakes up to pick the index being typed once it can not be completed
+            if let (NumericJump::Pick, Some(deadline)) =
+                (self.numeric_jump, self.index_jump.deadline())
+            {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                refresh = Some(refresh.map_or(remaining, |r| r.min(remaining)));
+            }
+            backend.set_refresh_interval(refresh);
+
             let key = backend.read_key()?;
 
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
-                Key::Submit => match self.has_answer_highlighted() {
-                    true => break,
-                    false => {}
-                },
-                key => self.on_change(key),
-            }
-        }
-
-        let final_answer = self.get_final_answer();
-        let formatted = (self.formatter)(final_answer.as_ref());
-
-        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+                _ if self.pick_expired_index() => break,
+                Key::Tick => {}
+                Key::Timeout if self.has_answer_highlighted() => break,
+                Key::Timeout => timeout_prompt!(backend, self.message),
+                Key::Submit => {
+                    let submitted = match self.rows.get(self.cursor_index) {
+                        Some(RowIndex::Create) => self.validate_created_value()?,
+                        _ => self.has_answer_highlighted(),
+                    };
+
+                    if submitted {
+                        break;
+                    }
+                }
+                Key::Char(c, m)
+                    if !m.contains(KeyModifiers::CONTROL) && self.hotkey_option(c).is_some() =>
+                {
+                    if self.jump_to_hotkey(c) {
+                        break;
+                    }
+                }
+                Key::Char(c, KeyModifiers::NONE)
+                    if c.is_ascii_digit() && self.numeric_jump != NumericJump::Disabled =>
+                {
+                    if self.jump_to_index(c) && self.has_answer_highlighted() {
+                        break;
+                    }
+                }
+                Key::Tab if self.can_create() => {
+                    self.cursor_index = self.rows.len() - 1;
+
+                    if self.validate_created_value()? {
+                        break;
+                    }
+                }
+                key => self.on_change(key)?,
+            }
+        }
+
+        let final_answer = self.get_final_answer();
+        let formatted = match &final_answer {
+            SelectOrCreate::Existing(option) => (self.formatter)(option.as_ref()),
+            SelectOrCreate::Created(value) => value.clone(),
+        };
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
     }
 }
 
@@ -451,13 +1210,17 @@ where
 #[cfg(feature = "crossterm")]
 mod test {
     use crate::{
+        error::InquireError,
         formatter::OptionFormatter,
-        list_option::ListOption,
+        list_option::{ListOption, NumericJump, OptionGroup, SelectOrCreate},
+        option_source::OptionSource,
         terminal::crossterm::CrosstermTerminal,
-        ui::{Backend, RenderConfig},
+        ui::{Backend, IndexPrefix, OptionLayout, Preview, RenderConfig},
+        validator::Validation,
         Select,
     };
-    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::{cell::Cell, sync::mpsc::channel, time::Duration};
 
     #[test]
     /// Tests that a closure that actually closes on a variable can be used
@@ -545,4 +1308,738 @@ mod test {
 
         assert_eq!(ListOption::new(0, 1), ans);
     }
+
+    #[test]
+    fn cursor_skips_group_headers_and_separators() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4, 5, 6];
+        let groups = vec![
+            OptionGroup::new("Low", 0..2),
+            OptionGroup::new("Mid", 2..4),
+            OptionGroup::separator(4..6),
+        ];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_groups(groups)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(2, 3), ans);
+    }
+
+    #[test]
+    fn cursor_skips_group_headers_and_separators_upwards() {
+        let read: Vec<KeyEvent> = vec![KeyCode::End, KeyCode::Up, KeyCode::Up, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4, 5, 6];
+        let groups = vec![
+            OptionGroup::new("Low", 0..2),
+            OptionGroup::new("Mid", 2..4),
+            OptionGroup::separator(4..6),
+        ];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_groups(groups)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(3, 4), ans);
+    }
+
+    #[test]
+    fn cursor_wraps_around_group_headers() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Up, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4, 5, 6];
+        let groups = vec![
+            OptionGroup::new("Low", 0..2),
+            OptionGroup::new("Mid", 2..4),
+            OptionGroup::separator(4..6),
+        ];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_groups(groups)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(5, 6), ans);
+    }
+
+    #[test]
+    fn filtering_hides_empty_groups() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('5'),
+            KeyCode::Home,
+            KeyCode::Up,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4, 5, 6];
+        let groups = vec![
+            OptionGroup::new("Low", 0..2),
+            OptionGroup::new("Mid", 2..4),
+            OptionGroup::separator(4..6),
+        ];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_groups(groups)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(4, 5), ans);
+    }
+
+    #[test]
+    fn overlapping_groups_are_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_groups(vec![
+                OptionGroup::new("A", 0..2),
+                OptionGroup::new("B", 1..3),
+            ])
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn cursor_skips_disabled_options() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec![1, 2, 3, 4];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_disabler(&|opt, _| match opt % 2 {
+                1 => Some(String::from("odd")),
+                _ => None,
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(3, 4), ans);
+    }
+
+    #[test]
+    fn preview_of_highlighted_option_is_rendered() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec!["alpha", "beta"];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_previewer(&|opt| {
+                Ok(Preview::from(format!(
+                    "name: {}\nlength: {}",
+                    opt,
+                    opt.len()
+                )))
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, "beta"), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("│ name: alpha\r\n│ length: 5"));
+        assert!(output.contains("│ name: beta\r\n│ length: 4"));
+    }
+
+    #[test]
+    fn source_options_are_loaded_on_demand() {
+        let read: Vec<KeyEvent> = vec![KeyCode::PageDown, KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let pulled = Cell::new(0);
+        let source = OptionSource::iter((0..100_000).inspect(|_| pulled.set(pulled.get() + 1)));
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::from_source("Question", source)
+            .with_page_size(5)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(6, 6), ans);
+        assert!(pulled.get() <= 15);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("5 loaded"));
+    }
+
+    #[test]
+    fn end_loads_all_source_options() {
+        let read: Vec<KeyEvent> = vec![KeyCode::End, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let source = OptionSource::paged(|page| {
+            Ok(match page {
+                0..=9 => (page * 10..(page + 1) * 10).collect(),
+                _ => vec![],
+            })
+        });
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", vec![1000])
+            .with_source(source)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(100, 99), ans);
+    }
+
+    #[test]
+    fn channel_source_options_received_before_prompt_are_displayed() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let (sender, receiver) = channel();
+        sender.send("alpha").unwrap();
+        sender.send("beta").unwrap();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::from_source("Question", OptionSource::channel(receiver))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, "beta"), ans);
+    }
+
+    #[test]
+    fn channel_source_options_received_while_waiting_for_keys_are_displayed() {
+        let (sender, receiver) = channel();
+        sender.send("alpha").unwrap();
+
+        let keys: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut keys = keys.iter();
+        let mut sender = Some(sender);
+
+        // no key is pressed until "beta" is received, so it is only displayed
+        // if the prompt is refreshed while waiting for keys
+        let mut read = std::iter::from_fn(|| match sender.take() {
+            Some(sender) => {
+                sender.send("beta").unwrap();
+                None
+            }
+            None => keys.next(),
+        });
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::from_source("Question", OptionSource::channel(receiver))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, "beta"), ans);
+    }
+
+    #[test]
+    fn unmatched_filter_is_submitted_as_new_value() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('d'),
+            KeyCode::Char('o'),
+            KeyCode::Char('c'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Label", vec!["bug", "feature"])
+            .prompt_or_create_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(SelectOrCreate::Created(String::from("doc")), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("+ create 'doc'"));
+    }
+
+    #[test]
+    fn tab_submits_partial_match_as_new_value() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('b'), KeyCode::Tab]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Label", vec!["bug", "feature"])
+            .prompt_or_create_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(SelectOrCreate::Created(String::from("b")), ans);
+    }
+
+    #[test]
+    fn exact_match_is_not_created_again() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('b'),
+            KeyCode::Char('u'),
+            KeyCode::Char('g'),
+            KeyCode::Tab,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Label", vec!["bug", "feature"])
+            .prompt_or_create_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(SelectOrCreate::Existing(ListOption::new(0, "bug")), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(!output.contains("+ create 'bug'"));
+    }
+
+    #[test]
+    fn new_values_are_validated() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('X'),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Char('x'),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Label", vec!["bug", "feature"])
+            .with_creation_validator(&|v| {
+                Ok(match v.chars().all(|c| c.is_lowercase()) {
+                    true => Validation::Valid,
+                    false => Validation::Invalid("Labels must be lowercase".into()),
+                })
+            })
+            .prompt_or_create_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(SelectOrCreate::Created(String::from("x")), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("Labels must be lowercase"));
+    }
+
+    #[test]
+    fn empty_options_are_allowed_when_creating() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Enter, KeyCode::Char('x'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::<&str>::new("Label", vec![])
+            .prompt_or_create_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(SelectOrCreate::Created(String::from("x")), ans);
+    }
+
+    #[test]
+    fn typed_digits_form_multi_digit_indexes() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('1'), KeyCode::Char('1'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l"];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_numeric_jump(NumericJump::Move)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(10, "k"), ans);
+    }
+
+    #[test]
+    fn jump_indexes_are_relative_to_filtered_options() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('x'), KeyCode::Char('2'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec!["x1", "y", "x2", "x3"];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let render_config = RenderConfig::empty().with_option_index_prefix(IndexPrefix::Simple);
+        let mut backend = Backend::new(terminal, render_config).unwrap();
+
+        let ans = Select::new("Question", options)
+            .with_numeric_jump(NumericJump::Move)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(2, "x2"), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("2) x2"));
+    }
+
+    #[test]
+    fn complete_index_is_picked_immediately() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('3')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Question", vec!["a", "b", "c", "d"])
+            .with_numeric_jump(NumericJump::Pick)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(2, "c"), ans);
+    }
+
+    #[test]
+    fn ambiguous_index_is_picked_once_it_can_not_be_completed() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('1')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let options: Vec<usize> = (1..=12).collect();
+        let ans = Select::new("Question", options)
+            .with_numeric_jump(NumericJump::Pick)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(0, 1), ans);
+    }
+
+    #[test]
+    fn hotkey_submits_its_option() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('q')]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let options = vec!["yes", "no", "all", "quit"];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Stage this hunk?", options)
+            .with_hotkeys(&|action, _| action.chars().next())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(3, "quit"), ans);
+    }
+
+    #[test]
+    fn hotkeys_ignore_case() {
+        let read: Vec<KeyEvent> = vec![KeyEvent::new(KeyCode::Char('Q'), KeyModifiers::SHIFT)];
+        let mut read = read.iter();
+
+        let options = vec!["yes", "no", "all", "Quit"];
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Stage this hunk?", options)
+            .with_hotkeys(&|action, _| action.chars().flat_map(char::to_lowercase).next())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(3, "Quit"), ans);
+    }
+
+    #[test]
+    fn hotkeys_differing_in_case_are_conflicting() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Stage this hunk?", vec!["yes", "Yank"])
+            .with_hotkeys(&|action, _| action.chars().next())
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn hotkeys_of_disabled_options_are_ignored() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('n'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Stage this hunk?", vec!["yes", "no"])
+            .with_hotkeys(&|action, _| action.chars().next())
+            .with_disabler(&|opt, _| match *opt {
+                "no" => Some(String::new()),
+                _ => None,
+            })
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(0, "yes"), ans);
+    }
+
+    #[test]
+    fn conflicting_hotkeys_are_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Stage this hunk?", vec!["yes", "yank"])
+            .with_hotkeys(&|action, _| action.chars().next())
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn hotkeys_conflicting_with_vim_keys_are_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Stage this hunk?", vec!["yes", "jump"])
+            .with_hotkeys(&|action, _| action.chars().next())
+            .with_vim_mode(true)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn hotkeys_are_invalid_when_creating_values() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Label", vec!["bug", "feature"])
+            .with_hotkeys(&|label, _| label.chars().next())
+            .prompt_or_create_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn grid_layout_moves_between_columns() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Left,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = Select::new("Size", vec!["s", "m", "l", "xl"])
+            .with_layout(OptionLayout::Grid)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, "m"), ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> s     m     l     xl\r\n"));
+    }
+
+    #[test]
+    fn grid_layout_does_not_support_groups() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Size", vec!["s", "m", "l", "xl"])
+            .with_groups(vec![OptionGroup::new("Small", 0..2)])
+            .with_layout(OptionLayout::Grid)
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn timeout_resolves_to_option_under_starting_cursor() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::new("Question", vec![1, 2, 3])
+            .with_starting_cursor(1)
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(ListOption::new(1, 2), ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_no_option_is_loaded_yet() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let (_sender, receiver) = channel::<&str>();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::default()).unwrap();
+
+        let ans = Select::from_source("Question", OptionSource::channel(receiver))
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
 }
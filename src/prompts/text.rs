@@ -1,13 +1,16 @@
-use std::cmp::min;
+use std::{cmp::min, time::Duration};
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
+    autocompletion::TokenSuggestion,
     config::{self, get_configuration},
     error::{InquireError, InquireResult},
     formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
     input::Input,
     list_option::ListOption,
     terminal::get_default_terminal,
-    type_aliases::Suggester,
+    type_aliases::{Suggester, TokenSuggester},
     ui::{Backend, Key, KeyModifiers, RenderConfig, TextBackend},
     utils::paginate,
     validator::{ErrorMessage, StringValidator, Validation},
@@ -30,6 +33,7 @@ const DEFAULT_HELP_MESSAGE: &str = "↑↓ to move, tab to auto-complete, enter
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **Token suggester**: Custom function that returns a list of suggestions for the token under the cursor. See more on "Autocomplete" below.
 ///
 /// ## Default behaviors
 ///
@@ -49,6 +53,8 @@ const DEFAULT_HELP_MESSAGE: &str = "↑↓ to move, tab to auto-complete, enter
 ///
 /// The user is then able to select one of them by moving up and down the list, possibly further modifying a selected suggestion.
 ///
+/// For multi-token inputs, such as `deploy --env prod --region`, you can set-up a [`TokenSuggester`](crate::type_aliases::TokenSuggester) instead. It receives the full text input and the cursor position, and returns [`TokenSuggestion`](crate::autocompletion::TokenSuggestion)s containing the byte range of the input each one replaces. Selecting one of these suggestions replaces only that range, keeping the rest of the line intact and placing the cursor right after the inserted value. When both are set, the token suggester takes precedence.
+///
 /// # Example
 ///
 /// ```no_run
@@ -97,6 +103,16 @@ pub struct Text<'a> {
     /// Function that provides a list of suggestions to the user based on the current input.
     pub suggester: Option<Suggester<'a>>,
 
+    /// Function that provides a list of suggestions to the user for the token under the cursor.
+    ///
+    /// When set, it takes precedence over [suggester](Text::suggester).
+    pub token_suggester: Option<TokenSuggester<'a>>,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or fails with [`InquireError::Timeout`] when
+    /// there is none.
+    pub timeout: Option<Duration>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -133,6 +149,8 @@ impl<'a> Text<'a> {
             formatter: Self::DEFAULT_FORMATTER,
             page_size: Self::DEFAULT_PAGE_SIZE,
             suggester: None,
+            token_suggester: None,
+            timeout: None,
             render_config: get_configuration(),
         }
     }
@@ -169,6 +187,12 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the token suggester, which takes precedence over the one set via [with_suggester].
+    pub fn with_token_suggester(mut self, token_suggester: TokenSuggester<'a>) -> Self {
+        self.token_suggester = Some(token_suggester);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -204,12 +228,22 @@ impl<'a> Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub fn with_validators(mut self, validators: &[StringValidator<'a>]) -> Self {
         for validator in validators {
-            #[allow(clippy::clone_double_ref)]
-            self.validators.push(validator.clone());
+            self.validators.push(*validator);
         }
         self
     }
 
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its initial value or its default value. When it has neither,
+    /// or when the validators reject the answer, the prompt fails with
+    /// [`InquireError::Timeout`] instead. The remaining time is displayed in
+    /// the help line until the first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -252,6 +286,10 @@ impl<'a> Text<'a> {
         self,
         backend: &mut B,
     ) -> InquireResult<String> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
         TextPrompt::from(self).prompt(backend)
     }
 }
@@ -266,7 +304,8 @@ struct TextPrompt<'a> {
     validators: Vec<StringValidator<'a>>,
     error: Option<ErrorMessage>,
     suggester: Option<Suggester<'a>>,
-    suggested_options: Vec<String>,
+    token_suggester: Option<TokenSuggester<'a>>,
+    suggested_options: Vec<TokenSuggestion>,
     cursor_index: usize,
     page_size: usize,
 }
@@ -286,6 +325,7 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
             help_message: so.help_message,
             formatter: so.formatter,
             suggester: so.suggester,
+            token_suggester: so.token_suggester,
             input,
             original_input: None,
             error: None,
@@ -305,8 +345,16 @@ impl<'a> From<&'a str> for Text<'a> {
 
 impl<'a> TextPrompt<'a> {
     fn update_suggestions(&mut self) -> InquireResult<()> {
-        if let Some(suggester) = self.suggester {
-            self.suggested_options = suggester(self.input.content())?;
+        if let Some(token_suggester) = self.token_suggester {
+            let cursor = self.input.pre_cursor().len();
+            self.suggested_options = token_suggester(self.input.content(), cursor)?;
+            self.cursor_index = 0;
+        } else if let Some(suggester) = self.suggester {
+            let range = 0..self.input.content().len();
+            self.suggested_options = suggester(self.input.content())?
+                .into_iter()
+                .map(|s| TokenSuggestion::new(s, range.clone()))
+                .collect();
             self.cursor_index = 0;
         }
 
@@ -353,16 +401,21 @@ impl<'a> TextPrompt<'a> {
                 self.input = input;
             }
         } else {
-            let suggestion = self
-                .suggested_options
-                .get(self.cursor_index - 1)
-                .map(|s| &**s);
+            let suggestion = self.suggested_options.get(self.cursor_index - 1);
 
             if let Some(suggestion) = suggestion {
                 if self.original_input.is_none() {
                     self.original_input = Some(self.input.clone());
                 }
-                self.input = Input::new_with(suggestion);
+
+                // suggestions are always applied on top of the input typed by the user,
+                // so that moving between them does not accumulate replacements.
+                let original = self.original_input.as_ref().map_or("", Input::content);
+
+                if let Some((content, cursor)) = suggestion.apply(original) {
+                    let cursor = content[..cursor].graphemes(true).count();
+                    self.input = Input::new_with(&content).with_cursor(cursor);
+                }
             }
         }
     }
@@ -405,7 +458,7 @@ impl<'a> TextPrompt<'a> {
             .suggested_options
             .iter()
             .enumerate()
-            .map(|(i, val)| ListOption::new(i, val.as_ref()))
+            .map(|(i, val)| ListOption::new(i, val.value.as_ref()))
             .collect::<Vec<ListOption<&str>>>();
 
         let list_index = self.cursor_index.saturating_sub(1);
@@ -443,6 +496,16 @@ impl<'a> TextPrompt<'a> {
             match key {
                 Key::Interrupt => interrupt_prompt!(),
                 Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout if self.input.is_empty() && self.default.is_none() => {
+                    timeout_prompt!(backend, self.message)
+                }
+                Key::Timeout => match self.validate_current_answer()? {
+                    Validation::Valid => {
+                        final_answer = self.cur_answer();
+                        break;
+                    }
+                    Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                },
                 Key::Submit => match self.validate_current_answer()? {
                     Validation::Valid => {
                         final_answer = self.cur_answer();
@@ -465,11 +528,14 @@ impl<'a> TextPrompt<'a> {
 mod test {
     use super::Text;
     use crate::{
+        autocompletion::{current_token, TokenSuggestion},
+        error::InquireError,
         terminal::crossterm::CrosstermTerminal,
         ui::{Backend, RenderConfig},
         validator::{ErrorMessage, Validation},
     };
     use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
 
     fn default<'a>() -> Text<'a> {
         Text::new("Question?")
@@ -579,4 +645,157 @@ mod test {
             _ => Ok(Validation::Invalid(ErrorMessage::Default)),
         })
     );
+
+    text_test!(
+        token_suggestion_replaces_only_current_token,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("deploy --env pr").collect());
+            events.push(KeyCode::Down);
+            events.push(KeyCode::Enter);
+            events
+        },
+        "deploy --env prod",
+        Text::new("").with_token_suggester(&|input, cursor| {
+            let range = current_token(input, cursor);
+            Ok(["prod", "staging"]
+                .iter()
+                .filter(|v| v.starts_with(&input[range.clone()]))
+                .map(|v| TokenSuggestion::new(*v, range.clone()))
+                .collect())
+        })
+    );
+
+    text_test!(
+        token_suggestion_keeps_rest_of_the_line,
+        {
+            let mut events = vec![];
+            events.append(&mut text_to_events!("deploy --e prod").collect());
+            for _ in 0..5 {
+                events.push(KeyCode::Left);
+            }
+            events.push(KeyCode::Down);
+            events.push(KeyCode::Down);
+            events.push(KeyCode::Up);
+            events.push(KeyCode::Enter);
+            events
+        },
+        "deploy --env prod",
+        Text::new("").with_token_suggester(&|input, cursor| {
+            let range = current_token(input, cursor);
+            Ok(["--env", "--region"]
+                .iter()
+                .filter(|v| v.starts_with(&input[range.start..cursor]))
+                .map(|v| TokenSuggestion::new(*v, range.clone()))
+                .chain(std::iter::once(TokenSuggestion::new(
+                    "--verbose",
+                    range.clone(),
+                )))
+                .collect())
+        })
+    );
+
+    #[test]
+    fn timeout_resolves_to_default_value() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = default()
+            .with_default("anonymous")
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("anonymous", ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("[1s left]"));
+    }
+
+    #[test]
+    fn timeout_without_default_value_fails() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = default()
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+
+    #[test]
+    fn timeout_resolves_to_initial_value() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = default()
+            .with_initial_value("admin")
+            .with_default("anonymous")
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("admin", ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_validators_reject_the_answer() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = default()
+            .with_initial_value("root")
+            .with_validator(&|input: &str| match input {
+                "root" => Ok(Validation::Invalid("Pick another user".into())),
+                _ => Ok(Validation::Valid),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+
+    #[test]
+    fn first_key_stops_the_countdown() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Char('a'), KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = default()
+            .with_help_message("Your name")
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("a", ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert_eq!(1, output.matches("[Your name, 1s left]").count());
+        assert!(output.contains("[Your name]"));
+    }
 }
@@ -0,0 +1,966 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::{self, get_configuration},
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    input::Input,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    tree::{CheckState, FlatTree, TreeNode},
+    type_aliases::Filter,
+    ui::{Backend, Key, KeyModifiers, RenderConfig, TreeMultiSelectBackend},
+    utils::{paginate, Page},
+    validator::{ErrorMessage, MultiOptionValidator, Validation},
+};
+
+/// Prompt suitable for when you need the user to select many options in a hierarchy,
+/// such as feature flags or permission scopes.
+///
+/// The options are provided as a **non-empty** `Vec` of root [`TreeNode`]s, each one
+/// containing a value and the nodes nested below it. Each node is displayed with a
+/// checkbox: checking a branch checks all of the nodes below it, and a branch with
+/// only some of them checked is displayed with an indeterminate checkbox.
+///
+/// The user can toggle the highlighted node by pressing space, collapse the highlighted
+/// branch by pressing the left arrow, or move to its parent if it is already collapsed,
+/// and expand it by pressing the right arrow, or move to its first child if it is
+/// already expanded.
+///
+/// The prompt returns the checked leaves or, optionally, the minimal set of checked
+/// nodes covering the selection, in which fully checked branches replace the nodes
+/// below them. Each [`ListOption`] contains the index of the node in a depth-first
+/// traversal of the tree.
+///
+/// Customizable options:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Tree**: Root nodes displayed to the user. Must be **non-empty**.
+/// - **Default selections**: Nodes that are checked by default when the prompt is first rendered, by their index in a depth-first traversal of the tree. If any of the indices is out-of-range, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the selected options string value, joined using a comma as the separator, by default.
+/// - **Validator**: Custom validator to make sure a given submitted input pass the specified requirements.
+///   - No validators are on by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+/// - **Starting expanded**: Whether all branches are expanded when the prompt is first rendered. Default is `false`.
+/// - **Covering nodes**: Whether the answer contains the minimal set of nodes covering the selection, instead of the checked leaves. Default is `false`.
+/// - **Filter function**: Function that defines if a node matches the current filter input. The ancestors of matching nodes are kept visible and expanded until the filter is cleared, when branches are collapsed back to how the user left them.
+/// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+///
+/// The indeterminate checkbox can be customized via the `indeterminate_checkbox`
+/// property of the [`RenderConfig`].
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{tree::TreeNode, TreeMultiSelect};
+///
+/// let scopes = vec![
+///     TreeNode::new("repo").with_children(vec![
+///         TreeNode::new("repo:status"),
+///         TreeNode::new("repo:deployment"),
+///         TreeNode::new("public_repo"),
+///     ]),
+///     TreeNode::new("admin:org").with_children(vec![
+///         TreeNode::new("write:org"),
+///         TreeNode::new("read:org"),
+///     ]),
+/// ];
+///
+/// let ans = TreeMultiSelect::new("Which scopes should the token have?", scopes)
+///     .with_covering_nodes(true)
+///     .prompt();
+///
+/// match ans {
+///     Ok(scopes) => println!("Creating token with scopes {}", scopes.join(",")),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+///
+/// [`InquireError::InvalidConfiguration`]: crate::error::InquireError::InvalidConfiguration
+#[derive(Clone)]
+pub struct TreeMultiSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Root nodes displayed to the user.
+    pub tree: Vec<TreeNode<T>>,
+
+    /// Default indexes of the nodes checked, in a depth-first traversal of the tree.
+    pub default: Option<&'a [usize]>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the tree using hjkl.
+    pub vim_mode: bool,
+
+    /// Whether all branches are expanded when the prompt is first rendered.
+    pub starting_expanded: bool,
+
+    /// Whether the answer contains the minimal set of nodes covering the
+    /// selection, instead of the checked leaves.
+    pub covering_nodes: bool,
+
+    /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
+    pub keep_filter: bool,
+
+    /// Function called with the current user input to filter the nodes.
+    ///
+    /// The index received is the position of the node in a depth-first
+    /// traversal of the tree.
+    pub filter: Filter<'a, T>,
+
+    /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// Validator to apply to the user input.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub validator: Option<MultiOptionValidator<'a, T>>,
+
+    /// Time to wait for the user to press a key before the prompt is answered
+    /// with its default value, or with no options selected when there is none.
+    /// If the validator rejects that selection, the prompt fails with
+    /// [`InquireError::Timeout`] instead.
+    pub timeout: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> TreeMultiSelect<'a, T>
+where
+    T: Display,
+{
+    /// Default formatter, set to print the selected options string value, joined
+    /// using a comma as the separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::TreeMultiSelect;
+    ///
+    /// let formatter = TreeMultiSelect::<&str>::DEFAULT_FORMATTER;
+    ///
+    /// let ans = vec![ListOption::new(1, &"repo:status"), ListOption::new(5, &"write:org")];
+    /// assert_eq!(String::from("repo:status, write:org"), formatter(&ans));
+    /// ```
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|opt| opt.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    /// Default filter function, which checks if the current filter value is a
+    /// substring of the node value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TreeMultiSelect;
+    ///
+    /// let filter = TreeMultiSelect::<&str>::DEFAULT_FILTER;
+    /// assert_eq!(true,  filter("org", &"write:org",   "write:org",   5));
+    /// assert_eq!(false, filter("org", &"public_repo", "public_repo", 3));
+    /// ```
+    pub const DEFAULT_FILTER: Filter<'a, T> = &|filter, _, string_value, _| -> bool {
+        let filter = filter.to_lowercase();
+
+        string_value.to_lowercase().contains(&filter)
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
+
+    /// Default value of whether branches start expanded.
+    pub const DEFAULT_STARTING_EXPANDED: bool = false;
+
+    /// Default value of whether the answer contains the covering nodes.
+    pub const DEFAULT_COVERING_NODES: bool = false;
+
+    /// Default behavior of keeping or cleaning the user filter value.
+    pub const DEFAULT_KEEP_FILTER: bool = true;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, space to select, ←→ to collapse/expand, type to filter");
+
+    /// Creates a [TreeMultiSelect] with the provided message and root nodes, along with default configuration values.
+    pub fn new(message: &'a str, tree: Vec<TreeNode<T>>) -> Self {
+        Self {
+            message,
+            tree,
+            default: None,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_expanded: Self::DEFAULT_STARTING_EXPANDED,
+            covering_nodes: Self::DEFAULT_COVERING_NODES,
+            keep_filter: Self::DEFAULT_KEEP_FILTER,
+            filter: Self::DEFAULT_FILTER,
+            formatter: Self::DEFAULT_FORMATTER,
+            validator: None,
+            timeout: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disabled vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets whether all branches are expanded when the prompt is first rendered.
+    pub fn with_starting_expanded(mut self, starting_expanded: bool) -> Self {
+        self.starting_expanded = starting_expanded;
+        self
+    }
+
+    /// Sets whether the answer contains the minimal set of nodes covering the
+    /// selection, where fully checked branches replace the nodes below them,
+    /// instead of the checked leaves.
+    pub fn with_covering_nodes(mut self, covering_nodes: bool) -> Self {
+        self.covering_nodes = covering_nodes;
+        self
+    }
+
+    /// Sets the keep filter behavior.
+    pub fn with_keep_filter(mut self, keep_filter: bool) -> Self {
+        self.keep_filter = keep_filter;
+        self
+    }
+
+    /// Sets the filter function.
+    pub fn with_filter(mut self, filter: Filter<'a, T>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the validator to apply to the user input. You might want to use this feature
+    /// in case you need to limit the user to specific choices, such as limiting the number
+    /// of selections.
+    ///
+    /// In case of error, the message is displayed one line above the prompt.
+    pub fn with_validator(mut self, validator: MultiOptionValidator<'a, T>) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Sets the indexes of the nodes checked by default, in a depth-first
+    /// traversal of the tree. Checking a branch checks all of the nodes below it.
+    pub fn with_default(mut self, default: &'a [usize]) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting its default selection or no nodes when it has none. When the
+    /// validator rejects it, the prompt fails with [`InquireError::Timeout`]
+    /// instead. The remaining time is displayed in the help line until the
+    /// first key is pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned values of the nodes selected by the user.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|options| options.into_iter().map(|option| option.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a vector of [`ListOption`](crate::list_option::ListOption)s containing
+    /// the index of the selections, in a depth-first traversal of the tree, and the
+    /// owned objects selected by the user.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(in crate) fn prompt_with_backend<B: TreeMultiSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        TreeMultiSelectPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct TreeMultiSelectPrompt<'a, T> {
+    message: &'a str,
+    tree: FlatTree<T>,
+    string_values: Vec<String>,
+    expanded: Vec<bool>,
+    unfiltered_expanded: Option<Vec<bool>>,
+    relevant: Option<Vec<bool>>,
+    rows: Vec<usize>,
+    checked: Vec<bool>,
+    help_message: Option<&'a str>,
+    vim_mode: bool,
+    covering_nodes: bool,
+    keep_filter: bool,
+    cursor_index: usize,
+    page_size: usize,
+    input: Input,
+    filter: Filter<'a, T>,
+    formatter: MultiOptionFormatter<'a, T>,
+    validator: Option<MultiOptionValidator<'a, T>>,
+    error: Option<ErrorMessage>,
+}
+
+impl<'a, T> TreeMultiSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    fn new(tmso: TreeMultiSelect<'a, T>) -> InquireResult<Self> {
+        if tmso.tree.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let tree = FlatTree::new(tmso.tree);
+        let mut checked = vec![false; tree.len()];
+
+        for &idx in tmso.default.unwrap_or_default() {
+            if idx >= tree.len() {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Index {} is out-of-bounds for length {} of options",
+                    idx,
+                    tree.len()
+                )));
+            }
+
+            for leaf in tree.leaves(idx) {
+                checked[leaf] = true;
+            }
+        }
+
+        let expanded = vec![tmso.starting_expanded; tree.len()];
+        let rows = tree.visible(&expanded, None);
+
+        Ok(Self {
+            message: tmso.message,
+            tree,
+            string_values: Vec::new(),
+            expanded,
+            unfiltered_expanded: None,
+            relevant: None,
+            rows,
+            checked,
+            help_message: tmso.help_message,
+            vim_mode: tmso.vim_mode,
+            covering_nodes: tmso.covering_nodes,
+            keep_filter: tmso.keep_filter,
+            cursor_index: 0,
+            page_size: tmso.page_size,
+            input: Input::new(),
+            filter: tmso.filter,
+            formatter: tmso.formatter,
+            validator: tmso.validator,
+            error: None,
+        })
+    }
+
+    /// Updates which nodes are relevant to the current filter, expanding the
+    /// ancestors of the matching ones until the filter is cleared.
+    fn filter_nodes(&mut self) {
+        let filter = self.input.content();
+
+        if filter.is_empty() {
+            self.relevant = None;
+
+            if let Some(expanded) = self.unfiltered_expanded.take() {
+                self.expanded = expanded;
+            }
+            return;
+        }
+
+        // the branches expanded by the user are restored once the filter is
+        // cleared, dropping the ones only expanded to display matches
+        if self.unfiltered_expanded.is_none() {
+            self.unfiltered_expanded = Some(self.expanded.clone());
+        }
+
+        // string values are only needed when filtering, so they are built lazily
+        if self.string_values.is_empty() {
+            self.string_values = self.tree.values().iter().map(T::to_string).collect();
+        }
+
+        let matches: Vec<bool> = self
+            .tree
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (self.filter)(filter, value, &self.string_values[i], i))
+            .collect();
+        let relevant = self.tree.relevant(&matches);
+
+        for (idx, is_match) in matches.iter().enumerate() {
+            if *is_match {
+                for ancestor in self.tree.path(idx).into_iter().rev().skip(1) {
+                    self.expanded[ancestor] = true;
+                }
+            }
+        }
+
+        self.relevant = Some(relevant);
+    }
+
+    /// Updates the displayed rows, keeping the highlighted node when it is
+    /// still displayed.
+    fn update_rows(&mut self) {
+        let highlighted = self.highlighted_node();
+
+        self.rows = self.tree.visible(&self.expanded, self.relevant.as_deref());
+
+        self.cursor_index = match highlighted.and_then(|h| self.rows.iter().position(|r| *r == h)) {
+            Some(position) => position,
+            None => self.cursor_index.min(self.rows.len().saturating_sub(1)),
+        };
+    }
+
+    fn highlighted_node(&self) -> Option<usize> {
+        self.rows.get(self.cursor_index).cloned()
+    }
+
+    /// Returns the checkbox state of the node, derived from the leaves below it.
+    fn check_state(&self, idx: usize) -> CheckState {
+        let mut leaves = self.tree.leaves(idx).map(|leaf| self.checked[leaf]);
+        let first = leaves.next().unwrap_or_default();
+
+        match (first, leaves.all(|checked| checked == first)) {
+            (true, true) => CheckState::Checked,
+            (false, true) => CheckState::Unchecked,
+            (_, false) => CheckState::Indeterminate,
+        }
+    }
+
+    /// Returns the checked nodes, either the leaves or the minimal set of
+    /// nodes covering them, in depth-first order.
+    fn selected_nodes(&self) -> Vec<usize> {
+        if !self.covering_nodes {
+            return (0..self.tree.len())
+                .filter(|idx| self.tree.is_leaf(*idx) && self.checked[*idx])
+                .collect();
+        }
+
+        let mut selected = vec![];
+        let mut idx = 0;
+
+        while idx < self.tree.len() {
+            match self.check_state(idx) {
+                CheckState::Indeterminate => idx += 1,
+                state => {
+                    if state == CheckState::Checked {
+                        selected.push(idx);
+                    }
+
+                    idx = self.tree.node(idx).end;
+                }
+            }
+        }
+
+        selected
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
+        if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index = self
+                .cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index = self.cursor_index.saturating_sub(qty);
+        }
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
+        self.cursor_index = self.cursor_index.saturating_add(qty);
+
+        if self.cursor_index >= self.rows.len() {
+            self.cursor_index = if self.rows.is_empty() {
+                0
+            } else if wrap {
+                self.cursor_index % self.rows.len()
+            } else {
+                self.rows.len().saturating_sub(1)
+            }
+        }
+    }
+
+    /// Collapses the highlighted branch or, when it is already collapsed or
+    /// is a leaf, moves the cursor to its parent.
+    fn collapse(&mut self) {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return,
+        };
+
+        if !self.tree.is_leaf(node) && self.expanded[node] {
+            self.expanded[node] = false;
+            self.update_rows();
+        } else if let Some(parent) = self.tree.node(node).parent {
+            if let Some(position) = self.rows.iter().position(|r| *r == parent) {
+                self.cursor_index = position;
+            }
+        }
+    }
+
+    /// Expands the highlighted branch or, when it is already expanded, moves
+    /// the cursor to its first displayed child.
+    fn expand(&mut self) {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return,
+        };
+
+        if self.tree.is_leaf(node) {
+            return;
+        }
+
+        if !self.expanded[node] {
+            self.expanded[node] = true;
+            self.update_rows();
+        } else if let Some(&next) = self.rows.get(self.cursor_index + 1) {
+            if self.tree.node(next).parent == Some(node) {
+                self.cursor_index += 1;
+            }
+        }
+    }
+
+    /// Checks the highlighted node and all of the nodes below it, or unchecks
+    /// them when they are all checked already.
+    fn toggle_cursor_selection(&mut self) {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return,
+        };
+
+        let checked = self.check_state(node) != CheckState::Checked;
+
+        for leaf in self.tree.leaves(node) {
+            self.checked[leaf] = checked;
+        }
+
+        if !self.keep_filter {
+            self.input.clear();
+            self.filter_nodes();
+            self.update_rows();
+        }
+    }
+
+    fn on_change(&mut self, key: Key) {
+        match key {
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Home => self.move_cursor_up(usize::MAX, false),
+
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::PageDown => self.move_cursor_down(self.page_size, false),
+            Key::End => self.move_cursor_down(usize::MAX, false),
+
+            Key::Left(KeyModifiers::NONE) => self.collapse(),
+            Key::Char('h', KeyModifiers::NONE) if self.vim_mode => self.collapse(),
+            Key::Right(KeyModifiers::NONE) => self.expand(),
+            Key::Char('l', KeyModifiers::NONE) if self.vim_mode => self.expand(),
+
+            Key::Char(' ', KeyModifiers::NONE) => self.toggle_cursor_selection(),
+
+            key => {
+                let dirty = self.input.handle_key(key);
+
+                if dirty {
+                    self.filter_nodes();
+                    self.update_rows();
+                }
+            }
+        };
+    }
+
+    fn validate_current_answer(&self) -> InquireResult<Validation> {
+        if let Some(validator) = self.validator {
+            let values = self.tree.values();
+            let selected_options = self
+                .selected_nodes()
+                .into_iter()
+                .map(|idx| ListOption::new(idx, &values[idx]))
+                .collect::<Vec<ListOption<&T>>>();
+
+            let res = validator(&selected_options)?;
+            Ok(res)
+        } else {
+            Ok(Validation::Valid)
+        }
+    }
+
+    fn render<B: TreeMultiSelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        if let Some(err) = &self.error {
+            backend.render_error_message(err)?;
+        }
+
+        backend.render_tree_multiselect_prompt(prompt, &self.input)?;
+
+        let page = paginate(self.page_size, &self.rows, self.cursor_index);
+        let last = self.tree.last_visible(&self.rows);
+
+        let rows = page
+            .content
+            .iter()
+            .map(|idx| {
+                let mut row = self.tree.row(*idx, &self.expanded, &last);
+                row.checked = Some(self.check_state(*idx));
+                row
+            })
+            .collect::<Vec<_>>();
+
+        let page = Page {
+            first: page.first,
+            last: page.last,
+            content: &rows,
+            selection: page.selection,
+            total: page.total,
+        };
+
+        backend.render_tree(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: TreeMultiSelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout => match self.validate_current_answer()? {
+                    Validation::Valid => break,
+                    Validation::Invalid(_) => timeout_prompt!(backend, self.message),
+                },
+                Key::Submit => match self.validate_current_answer()? {
+                    Validation::Valid => break,
+                    Validation::Invalid(msg) => self.error = Some(msg),
+                },
+                key => self.on_change(key),
+            }
+        }
+
+        let selected = self.selected_nodes();
+        let final_answer = self.tree.into_options(&selected);
+        let refs: Vec<ListOption<&T>> = final_answer.iter().map(ListOption::as_ref).collect();
+        let formatted = (self.formatter)(&refs);
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        list_option::ListOption,
+        terminal::crossterm::CrosstermTerminal,
+        tree::TreeNode,
+        ui::{Backend, RenderConfig},
+        validator::Validation,
+        TreeMultiSelect,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
+
+    fn scopes() -> Vec<TreeNode<&'static str>> {
+        vec![
+            TreeNode::new("repo").with_children(vec![
+                TreeNode::new("repo:status"),
+                TreeNode::new("repo:deployment"),
+            ]),
+            TreeNode::new("admin:org")
+                .with_children(vec![TreeNode::new("write:org"), TreeNode::new("read:org")]),
+        ]
+    }
+
+    #[test]
+    fn checking_a_branch_checks_its_leaves() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .with_starting_expanded(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(2, "repo:deployment")], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("  ▾ [-] repo\r\n> ├─   [ ] repo:status\r\n"));
+    }
+
+    #[test]
+    fn covering_nodes_replace_fully_checked_branches() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .with_default(&[1, 5])
+            .with_covering_nodes(true)
+            .with_starting_expanded(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![ListOption::new(0, "repo"), ListOption::new(5, "read:org")],
+            ans
+        );
+    }
+
+    #[test]
+    fn filtering_keeps_ancestors_of_matches_visible() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('r'),
+            KeyCode::Char('e'),
+            KeyCode::Char('a'),
+            KeyCode::Char('d'),
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(5, "read:org")], ans);
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> ▾ [ ] admin:org\r\n  └─   [ ] read:org\r\n"));
+    }
+
+    #[test]
+    fn clearing_the_filter_collapses_branches_expanded_by_it() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('r'),
+            KeyCode::Char('e'),
+            KeyCode::Char('a'),
+            KeyCode::Char('d'),
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(1, "repo:status"),
+                ListOption::new(2, "repo:deployment"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_default_is_invalid() {
+        let mut write: Vec<u8> = Vec::new();
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .with_default(&[6])
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+    }
+
+    #[test]
+    fn timeout_resolves_to_default_selection() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .with_default(&[1])
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(1, "repo:status")], ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_default_selection_is_invalid() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeMultiSelect::new("Question", scopes())
+            .with_default(&[1])
+            .with_validator(&|a: &[ListOption<&&str>]| match a.len() {
+                1 => Ok(Validation::Invalid("Pick at least two scopes".into())),
+                _ => Ok(Validation::Valid),
+            })
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+}
@@ -0,0 +1,827 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    config::{self, get_configuration},
+    error::{InquireError, InquireResult},
+    formatter::MultiOptionFormatter,
+    input::Input,
+    list_option::ListOption,
+    terminal::get_default_terminal,
+    tree::{FlatTree, TreeNode},
+    type_aliases::Filter,
+    ui::{Backend, Key, KeyModifiers, RenderConfig, TreeSelectBackend},
+    utils::{paginate, Page},
+};
+
+/// Prompt suitable for when you need the user to select one option in a hierarchy,
+/// such as organizations, teams and repositories or categories and subcategories.
+///
+/// The options are provided as a **non-empty** `Vec` of root [`TreeNode`]s, each one
+/// containing a value and the nodes nested below it. Nodes with children are branches,
+/// which the user can expand and collapse, while the others are leaves.
+///
+/// The user can collapse the highlighted branch by pressing the left arrow, or move
+/// to its parent if it is already collapsed, and expand it by pressing the right
+/// arrow, or move to its first child if it is already expanded. Pressing enter
+/// submits the highlighted leaf, while on branches it toggles whether they are
+/// expanded, unless branch selection is enabled.
+///
+/// The prompt returns the path from the root down to the selected node, where
+/// each [`ListOption`] contains the index of the node among its siblings.
+///
+/// Like all others, this prompt also allows you to customize several aspects of it:
+///
+/// - **Prompt message**: Required when creating the prompt.
+/// - **Tree**: Root nodes displayed to the user. Must be **non-empty**.
+/// - **Help message**: Message displayed at the line below the prompt.
+/// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
+///   - Prints the path to the selected node, separated by slashes, by default.
+/// - **Page size**: Number of rows displayed at once, 7 by default.
+/// - **Starting expanded**: Whether all branches are expanded when the prompt is first rendered. Default is `false`.
+/// - **Branch selection**: Whether branches can be submitted as the answer, instead of only leaves. Default is `false`.
+/// - **Filter function**: Function that defines if a node matches the current filter input. The ancestors of matching nodes are kept visible and expanded until the filter is cleared, when branches are collapsed back to how the user left them.
+///
+/// The indentation guides and the markers of expanded and collapsed branches can
+/// be customized via the `tree` property of the [`RenderConfig`].
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::{tree::TreeNode, TreeSelect};
+///
+/// let tree = vec![
+///     TreeNode::new("acme").with_children(vec![
+///         TreeNode::new("platform").with_children(vec![
+///             TreeNode::new("inquire"),
+///             TreeNode::new("tui"),
+///         ]),
+///         TreeNode::new("infra").with_child(TreeNode::new("terraform")),
+///     ]),
+///     TreeNode::new("globex").with_child(TreeNode::new("website")),
+/// ];
+///
+/// let ans = TreeSelect::new("Which repository do you want to clone?", tree).prompt();
+///
+/// match ans {
+///     Ok(path) => println!("Cloning {}", path.join("/")),
+///     Err(_) => println!("There was an error, please try again"),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TreeSelect<'a, T> {
+    /// Message to be presented to the user.
+    pub message: &'a str,
+
+    /// Root nodes displayed to the user.
+    pub tree: Vec<TreeNode<T>>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<&'a str>,
+
+    /// Page size of the rows displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the tree using hjkl.
+    pub vim_mode: bool,
+
+    /// Whether all branches are expanded when the prompt is first rendered.
+    pub starting_expanded: bool,
+
+    /// Whether branches can be submitted as the answer.
+    pub branch_selection: bool,
+
+    /// Function called with the current user input to filter the nodes.
+    ///
+    /// The index received is the position of the node in a depth-first
+    /// traversal of the tree.
+    pub filter: Filter<'a, T>,
+
+    /// Function that formats the path to the selected node and presents it to
+    /// the user as the final rendering of the prompt.
+    pub formatter: MultiOptionFormatter<'a, T>,
+
+    /// Time to wait for the user to press a key before the prompt fails with
+    /// [`InquireError::Timeout`].
+    pub timeout: Option<Duration>,
+
+    /// RenderConfig to apply to the rendered interface.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub render_config: RenderConfig,
+}
+
+impl<'a, T> TreeSelect<'a, T>
+where
+    T: Display,
+{
+    /// Default formatter, which prints the path to the selected node
+    /// separated by slashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::TreeSelect;
+    ///
+    /// let formatter = TreeSelect::<&str>::DEFAULT_FORMATTER;
+    /// let path = vec![ListOption::new(0, &"acme"), ListOption::new(1, &"infra")];
+    /// assert_eq!(String::from("acme / infra"), formatter(&path));
+    /// ```
+    pub const DEFAULT_FORMATTER: MultiOptionFormatter<'a, T> = &|ans| {
+        ans.iter()
+            .map(|node| node.to_string())
+            .collect::<Vec<String>>()
+            .join(" / ")
+    };
+
+    /// Default filter function, which checks if the current filter value is a
+    /// substring of the node value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::TreeSelect;
+    ///
+    /// let filter = TreeSelect::<&str>::DEFAULT_FILTER;
+    /// assert_eq!(true,  filter("inq", &"inquire", "inquire", 2));
+    /// assert_eq!(false, filter("inq", &"tui",     "tui",     3));
+    /// ```
+    pub const DEFAULT_FILTER: Filter<'a, T> = &|filter, _, string_value, _| -> bool {
+        let filter = filter.to_lowercase();
+
+        string_value.to_lowercase().contains(&filter)
+    };
+
+    /// Default page size.
+    pub const DEFAULT_PAGE_SIZE: usize = config::DEFAULT_PAGE_SIZE;
+
+    /// Default value of vim mode.
+    pub const DEFAULT_VIM_MODE: bool = config::DEFAULT_VIM_MODE;
+
+    /// Default value of whether branches start expanded.
+    pub const DEFAULT_STARTING_EXPANDED: bool = false;
+
+    /// Default value of whether branches can be submitted.
+    pub const DEFAULT_BRANCH_SELECTION: bool = false;
+
+    /// Default help message.
+    pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, ←→ to collapse/expand, enter to select, type to filter");
+
+    /// Creates a [TreeSelect] with the provided message and root nodes, along with default configuration values.
+    pub fn new(message: &'a str, tree: Vec<TreeNode<T>>) -> Self {
+        Self {
+            message,
+            tree,
+            help_message: Self::DEFAULT_HELP_MESSAGE,
+            page_size: Self::DEFAULT_PAGE_SIZE,
+            vim_mode: Self::DEFAULT_VIM_MODE,
+            starting_expanded: Self::DEFAULT_STARTING_EXPANDED,
+            branch_selection: Self::DEFAULT_BRANCH_SELECTION,
+            filter: Self::DEFAULT_FILTER,
+            formatter: Self::DEFAULT_FORMATTER,
+            timeout: None,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disabled vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets whether all branches are expanded when the prompt is first rendered.
+    pub fn with_starting_expanded(mut self, starting_expanded: bool) -> Self {
+        self.starting_expanded = starting_expanded;
+        self
+    }
+
+    /// Sets whether branches can be submitted as the answer.
+    ///
+    /// When enabled, pressing enter on a branch submits it instead of
+    /// toggling whether it is expanded.
+    pub fn with_branch_selection(mut self, branch_selection: bool) -> Self {
+        self.branch_selection = branch_selection;
+        self
+    }
+
+    /// Sets the filter function.
+    pub fn with_filter(mut self, filter: Filter<'a, T>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the time to wait for the user to press a key, after which the
+    /// prompt is answered as if the user had pressed enter right away,
+    /// submitting the highlighted node. When it is a branch that can not be
+    /// selected, the prompt fails with [`InquireError::Timeout`] instead. The
+    /// remaining time is displayed in the help line until the first key is
+    /// pressed.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still suport NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned values of the nodes from the root down to the
+    /// one selected by the user.
+    pub fn prompt(self) -> InquireResult<Vec<T>> {
+        self.raw_prompt()
+            .map(|path| path.into_iter().map(|node| node.value).collect())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<Vec<T>>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the path from the root down to the node selected by the user,
+    /// where each [`ListOption`](crate::list_option::ListOption) contains the
+    /// index of the node among its siblings and its owned value.
+    pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        let terminal = get_default_terminal()?;
+        let mut backend = Backend::new(terminal, self.render_config)?;
+        self.prompt_with_backend(&mut backend)
+    }
+
+    pub(in crate) fn prompt_with_backend<B: TreeSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        if let Some(timeout) = self.timeout {
+            backend.set_timeout(timeout);
+        }
+
+        TreeSelectPrompt::new(self)?.prompt(backend)
+    }
+}
+
+struct TreeSelectPrompt<'a, T> {
+    message: &'a str,
+    tree: FlatTree<T>,
+    string_values: Vec<String>,
+    expanded: Vec<bool>,
+    unfiltered_expanded: Option<Vec<bool>>,
+    relevant: Option<Vec<bool>>,
+    rows: Vec<usize>,
+    help_message: Option<&'a str>,
+    vim_mode: bool,
+    branch_selection: bool,
+    cursor_index: usize,
+    page_size: usize,
+    input: Input,
+    filter: Filter<'a, T>,
+    formatter: MultiOptionFormatter<'a, T>,
+}
+
+impl<'a, T> TreeSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    fn new(tso: TreeSelect<'a, T>) -> InquireResult<Self> {
+        if tso.tree.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        let tree = FlatTree::new(tso.tree);
+        let expanded = vec![tso.starting_expanded; tree.len()];
+        let rows = tree.visible(&expanded, None);
+
+        Ok(Self {
+            message: tso.message,
+            tree,
+            string_values: Vec::new(),
+            expanded,
+            unfiltered_expanded: None,
+            relevant: None,
+            rows,
+            help_message: tso.help_message,
+            vim_mode: tso.vim_mode,
+            branch_selection: tso.branch_selection,
+            cursor_index: 0,
+            page_size: tso.page_size,
+            input: Input::new(),
+            filter: tso.filter,
+            formatter: tso.formatter,
+        })
+    }
+
+    /// Updates which nodes are relevant to the current filter, expanding the
+    /// ancestors of the matching ones until the filter is cleared.
+    fn filter_nodes(&mut self) {
+        let filter = self.input.content();
+
+        if filter.is_empty() {
+            self.relevant = None;
+
+            if let Some(expanded) = self.unfiltered_expanded.take() {
+                self.expanded = expanded;
+            }
+            return;
+        }
+
+        // the branches expanded by the user are restored once the filter is
+        // cleared, dropping the ones only expanded to display matches
+        if self.unfiltered_expanded.is_none() {
+            self.unfiltered_expanded = Some(self.expanded.clone());
+        }
+
+        // string values are only needed when filtering, so they are built lazily
+        if self.string_values.is_empty() {
+            self.string_values = self.tree.values().iter().map(T::to_string).collect();
+        }
+
+        let matches: Vec<bool> = self
+            .tree
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (self.filter)(filter, value, &self.string_values[i], i))
+            .collect();
+        let relevant = self.tree.relevant(&matches);
+
+        for (idx, is_match) in matches.iter().enumerate() {
+            if *is_match {
+                for ancestor in self.tree.path(idx).into_iter().rev().skip(1) {
+                    self.expanded[ancestor] = true;
+                }
+            }
+        }
+
+        self.relevant = Some(relevant);
+    }
+
+    /// Updates the displayed rows, keeping the highlighted node when it is
+    /// still displayed.
+    fn update_rows(&mut self) {
+        let highlighted = self.highlighted_node();
+
+        self.rows = self.tree.visible(&self.expanded, self.relevant.as_deref());
+
+        self.cursor_index = match highlighted.and_then(|h| self.rows.iter().position(|r| *r == h)) {
+            Some(position) => position,
+            None => self.cursor_index.min(self.rows.len().saturating_sub(1)),
+        };
+    }
+
+    fn highlighted_node(&self) -> Option<usize> {
+        self.rows.get(self.cursor_index).cloned()
+    }
+
+    fn move_cursor_up(&mut self, qty: usize, wrap: bool) {
+        if wrap {
+            let after_wrap = qty.saturating_sub(self.cursor_index);
+            self.cursor_index = self
+                .cursor_index
+                .checked_sub(qty)
+                .unwrap_or_else(|| self.rows.len().saturating_sub(after_wrap))
+        } else {
+            self.cursor_index = self.cursor_index.saturating_sub(qty);
+        }
+    }
+
+    fn move_cursor_down(&mut self, qty: usize, wrap: bool) {
+        self.cursor_index = self.cursor_index.saturating_add(qty);
+
+        if self.cursor_index >= self.rows.len() {
+            self.cursor_index = if self.rows.is_empty() {
+                0
+            } else if wrap {
+                self.cursor_index % self.rows.len()
+            } else {
+                self.rows.len().saturating_sub(1)
+            }
+        }
+    }
+
+    /// Collapses the highlighted branch or, when it is already collapsed or
+    /// is a leaf, moves the cursor to its parent.
+    fn collapse(&mut self) {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return,
+        };
+
+        if !self.tree.is_leaf(node) && self.expanded[node] {
+            self.expanded[node] = false;
+            self.update_rows();
+        } else if let Some(parent) = self.tree.node(node).parent {
+            if let Some(position) = self.rows.iter().position(|r| *r == parent) {
+                self.cursor_index = position;
+            }
+        }
+    }
+
+    /// Expands the highlighted branch or, when it is already expanded, moves
+    /// the cursor to its first displayed child.
+    fn expand(&mut self) {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return,
+        };
+
+        if self.tree.is_leaf(node) {
+            return;
+        }
+
+        if !self.expanded[node] {
+            self.expanded[node] = true;
+            self.update_rows();
+        } else if let Some(&next) = self.rows.get(self.cursor_index + 1) {
+            if self.tree.node(next).parent == Some(node) {
+                self.cursor_index += 1;
+            }
+        }
+    }
+
+    fn on_change(&mut self, key: Key) {
+        match key {
+            Key::Up(KeyModifiers::NONE) => self.move_cursor_up(1, true),
+            Key::Char('k', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_up(1, true),
+            Key::PageUp => self.move_cursor_up(self.page_size, false),
+            Key::Home => self.move_cursor_up(usize::MAX, false),
+
+            Key::Down(KeyModifiers::NONE) => self.move_cursor_down(1, true),
+            Key::Char('j', KeyModifiers::NONE) if self.vim_mode => self.move_cursor_down(1, true),
+            Key::PageDown => self.move_cursor_down(self.page_size, false),
+            Key::End => self.move_cursor_down(usize::MAX, false),
+
+            Key::Left(KeyModifiers::NONE) => self.collapse(),
+            Key::Char('h', KeyModifiers::NONE) if self.vim_mode => self.collapse(),
+            Key::Right(KeyModifiers::NONE) => self.expand(),
+            Key::Char('l', KeyModifiers::NONE) if self.vim_mode => self.expand(),
+
+            key => {
+                let dirty = self.input.handle_key(key);
+
+                if dirty {
+                    self.filter_nodes();
+                    self.update_rows();
+                }
+            }
+        };
+    }
+
+    /// Handles the submission of the highlighted node, returning whether it
+    /// is the final answer or whether it was a branch toggled instead.
+    fn submit(&mut self) -> bool {
+        let node = match self.highlighted_node() {
+            Some(node) => node,
+            None => return false,
+        };
+
+        if self.tree.is_leaf(node) || self.branch_selection {
+            return true;
+        }
+
+        self.expanded[node] = !self.expanded[node];
+        self.update_rows();
+
+        false
+    }
+
+    fn render<B: TreeSelectBackend>(&mut self, backend: &mut B) -> InquireResult<()> {
+        let prompt = &self.message;
+
+        backend.frame_setup()?;
+
+        backend.render_tree_select_prompt(prompt, &self.input)?;
+
+        let page = paginate(self.page_size, &self.rows, self.cursor_index);
+        let last = self.tree.last_visible(&self.rows);
+
+        let rows = page
+            .content
+            .iter()
+            .map(|idx| self.tree.row(*idx, &self.expanded, &last))
+            .collect::<Vec<_>>();
+
+        let page = Page {
+            first: page.first,
+            last: page.last,
+            content: &rows,
+            selection: page.selection,
+            total: page.total,
+        };
+
+        backend.render_tree(page)?;
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        backend.frame_finish()?;
+
+        Ok(())
+    }
+
+    fn prompt<B: TreeSelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        loop {
+            self.render(backend)?;
+
+            let key = backend.read_key()?;
+
+            match key {
+                Key::Interrupt => interrupt_prompt!(),
+                Key::Cancel => cancel_prompt!(backend, self.message),
+                Key::Timeout if self.submit() => break,
+                Key::Timeout => timeout_prompt!(backend, self.message),
+                Key::Submit => {
+                    if self.submit() {
+                        break;
+                    }
+                }
+                key => self.on_change(key),
+            }
+        }
+
+        // should only be reached after the highlighted node is validated on submit
+        let node = self.highlighted_node().unwrap_or_default();
+        let final_answer = self.tree.into_path(node);
+        let formatted = (self.formatter)(
+            &final_answer
+                .iter()
+                .map(ListOption::as_ref)
+                .collect::<Vec<_>>(),
+        );
+
+        finish_prompt_with_answer!(backend, self.message, &formatted, final_answer);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "crossterm")]
+mod test {
+    use crate::{
+        error::InquireError,
+        list_option::ListOption,
+        terminal::crossterm::CrosstermTerminal,
+        tree::TreeNode,
+        ui::{Backend, RenderConfig},
+        TreeSelect,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
+
+    fn tree() -> Vec<TreeNode<&'static str>> {
+        vec![
+            TreeNode::new("acme").with_children(vec![
+                TreeNode::new("platform")
+                    .with_children(vec![TreeNode::new("inquire"), TreeNode::new("tui")]),
+                TreeNode::new("infra").with_child(TreeNode::new("terraform")),
+            ]),
+            TreeNode::new("globex").with_child(TreeNode::new("website")),
+        ]
+    }
+
+    #[test]
+    fn right_and_enter_expand_branches_until_a_leaf_is_selected() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeSelect::new("Question", tree())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(0, "acme"),
+                ListOption::new(0, "platform"),
+                ListOption::new(1, "tui"),
+            ],
+            ans
+        );
+    }
+
+    #[test]
+    fn left_collapses_branch_and_then_moves_to_parent() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Left,
+            KeyCode::Left,
+            KeyCode::Left,
+            KeyCode::Left,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeSelect::new("Question", tree())
+            .with_starting_expanded(true)
+            .with_branch_selection(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(1, "globex")], ans);
+    }
+
+    #[test]
+    fn filtering_keeps_ancestors_of_matches_visible() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('t'),
+            KeyCode::Char('e'),
+            KeyCode::Char('r'),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeSelect::new("Question", tree())
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ListOption::new(0, "acme"),
+                ListOption::new(1, "infra"),
+                ListOption::new(0, "terraform"),
+            ],
+            ans
+        );
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("> ▾ acme\r\n  └─ ▾ infra\r\n     └─   terraform"));
+    }
+
+    #[test]
+    fn clearing_the_filter_collapses_branches_expanded_by_it() {
+        let read: Vec<KeyEvent> = vec![
+            KeyCode::Char('t'),
+            KeyCode::Char('e'),
+            KeyCode::Char('r'),
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ]
+        .into_iter()
+        .map(KeyEvent::from)
+        .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeSelect::new("Question", tree())
+            .with_branch_selection(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(1, "globex")], ans);
+    }
+
+    #[test]
+    fn guides_are_rendered_for_nested_nodes() {
+        let read: Vec<KeyEvent> = vec![KeyCode::Down, KeyCode::Down, KeyCode::Down, KeyCode::Enter]
+            .into_iter()
+            .map(KeyEvent::from)
+            .collect();
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        TreeSelect::new("Question", tree())
+            .with_starting_expanded(true)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        drop(backend);
+        let output = String::from_utf8(write).unwrap();
+        assert!(output.contains("  ├─ ▾ platform\r\n  │  ├─   inquire\r\n> │  └─   tui"));
+    }
+
+    #[test]
+    fn timeout_resolves_to_highlighted_branch_when_branches_can_be_selected() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeSelect::new("Question", tree())
+            .with_branch_selection(true)
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "acme")], ans);
+    }
+
+    #[test]
+    fn timeout_fails_when_highlighted_branch_can_not_be_selected() {
+        let read: Vec<KeyEvent> = vec![];
+        let mut read = read.iter();
+
+        let mut write: Vec<u8> = Vec::new();
+        let terminal = CrosstermTerminal::new_with_io(&mut write, &mut read);
+        let mut backend = Backend::new(terminal, RenderConfig::empty()).unwrap();
+
+        let ans = TreeSelect::new("Question", tree())
+            .with_timeout(Duration::from_millis(20))
+            .prompt_with_backend(&mut backend);
+
+        assert!(matches!(ans, Err(InquireError::Timeout)));
+    }
+}
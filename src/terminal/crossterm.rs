@@ -1,4 +1,7 @@
-use std::io::{stdout, Result, Stdout, Write};
+use std::{
+    io::{stdout, Result, Stdout, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
@@ -134,6 +137,40 @@ impl<'a> Terminal for CrosstermTerminal<'a> {
         }
     }
 
+    fn poll_key(&mut self, timeout: Duration) -> Result<Option<Key>> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match &mut self.io {
+                IO::Std { w: _ } => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+
+                    if !event::poll(remaining)? {
+                        return Ok(None);
+                    }
+
+                    if let event::Event::Key(key_event) = event::read()? {
+                        return Ok(Some(key_event.into()));
+                    }
+                }
+                IO::Custom { r, w: _ } => {
+                    // an exhausted stream simulates a user that does not press any key
+                    return match r.next() {
+                        Some(key) => Ok(Some((*key).into())),
+                        None => {
+                            std::thread::sleep(timeout);
+                            Ok(None)
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    fn supports_timeouts(&self) -> bool {
+        true
+    }
+
     fn flush(&mut self) -> Result<()> {
         self.get_writer().flush()
     }
@@ -1,4 +1,4 @@
-use std::{fmt::Display, io::Result};
+use std::{fmt::Display, io::Result, time::Duration};
 
 use crate::{
     error::InquireResult,
@@ -21,6 +21,7 @@ pub mod console;
 
 pub struct TerminalSize {
     pub width: u16,
+    #[allow(unused)]
     pub height: u16,
 }
 
@@ -29,6 +30,16 @@ pub trait Terminal: Sized {
     fn cursor_down(&mut self, cnt: u16) -> Result<()>;
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
     fn read_key(&mut self) -> Result<Key>;
+    /// Waits up to the given duration for a key to be pressed, returning
+    /// `None` if none was pressed in time. Terminals that can not wait for
+    /// keys with a timeout block until a key is pressed.
+    fn poll_key(&mut self, _timeout: Duration) -> Result<Option<Key>> {
+        self.read_key().map(Some)
+    }
+    /// Whether `poll_key` stops waiting once the timeout elapses.
+    fn supports_timeouts(&self) -> bool {
+        false
+    }
     fn flush(&mut self) -> Result<()>;
 
     fn get_size(&self) -> Result<TerminalSize>;
@@ -0,0 +1,373 @@
+//! Utilities used to build the hierarchies of options displayed by
+//! [`TreeSelect`](crate::TreeSelect) and [`TreeMultiSelect`](crate::TreeMultiSelect)
+//! prompts.
+
+use crate::list_option::ListOption;
+
+/// Node of a tree of options, holding a value and the nodes nested below it.
+///
+/// Nodes with no children are leaves, while the others are branches that can
+/// be expanded and collapsed by the user.
+///
+/// # Example
+///
+/// ```
+/// use inquire::tree::TreeNode;
+///
+/// let org = TreeNode::new("acme").with_children(vec![
+///     TreeNode::new("platform").with_child(TreeNode::new("inquire")),
+///     TreeNode::new("infra"),
+/// ]);
+///
+/// assert!(!org.is_leaf());
+/// assert!(org.children[1].is_leaf());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode<T> {
+    /// Value of the node.
+    pub value: T,
+
+    /// Nodes nested below this one, empty for leaves.
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a node with the given value and no children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the node.
+    pub fn with_children(mut self, children: Vec<TreeNode<T>>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Appends a child to the node.
+    pub fn with_child(mut self, child: TreeNode<T>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Whether the node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// Position of a node in a [`FlatTree`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(in crate) struct FlatNode {
+    /// Index of the parent node, `None` for roots.
+    pub parent: Option<usize>,
+
+    /// Index of the node among its siblings.
+    pub index: usize,
+
+    /// Number of ancestors of the node.
+    pub depth: usize,
+
+    /// Index following the last descendant of the node.
+    pub end: usize,
+}
+
+/// Checkbox state of a node in trees that allow multiple selections.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(in crate) enum CheckState {
+    Checked,
+    Unchecked,
+
+    /// Some, but not all, of the leaves below the node are checked.
+    Indeterminate,
+}
+
+/// Row of a tree displayed by the backend.
+pub(in crate) struct TreeRow<D> {
+    pub option: ListOption<D>,
+
+    /// For each ancestor that is not a root, from the top, whether it has
+    /// siblings displayed below it, meaning a vertical guide crosses the row.
+    pub guides: Vec<bool>,
+
+    /// Whether the node is the last one displayed among its siblings,
+    /// `None` for roots.
+    pub last: Option<bool>,
+
+    /// Whether the node is expanded, `None` for leaves.
+    pub expanded: Option<bool>,
+
+    /// Checkbox state of the node, `None` in trees that do not allow
+    /// multiple selections.
+    pub checked: Option<CheckState>,
+}
+
+/// Tree of options flattened in depth-first order, so that the descendants of
+/// each node directly follow it.
+pub(in crate) struct FlatTree<T> {
+    values: Vec<T>,
+    nodes: Vec<FlatNode>,
+}
+
+impl<T> FlatTree<T> {
+    pub fn new(roots: Vec<TreeNode<T>>) -> Self {
+        let mut tree = Self {
+            values: Vec::new(),
+            nodes: Vec::new(),
+        };
+
+        tree.push_nodes(roots, None, 0);
+
+        tree
+    }
+
+    fn push_nodes(&mut self, nodes: Vec<TreeNode<T>>, parent: Option<usize>, depth: usize) {
+        for (index, node) in nodes.into_iter().enumerate() {
+            let idx = self.nodes.len();
+
+            self.values.push(node.value);
+            self.nodes.push(FlatNode {
+                parent,
+                index,
+                depth,
+                end: idx + 1,
+            });
+
+            self.push_nodes(node.children, Some(idx), depth + 1);
+            self.nodes[idx].end = self.nodes.len();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn node(&self, idx: usize) -> FlatNode {
+        self.nodes[idx]
+    }
+
+    pub fn is_leaf(&self, idx: usize) -> bool {
+        self.nodes[idx].end == idx + 1
+    }
+
+    /// Returns the indexes of the nodes from the root down to the given one.
+    pub fn path(&self, idx: usize) -> Vec<usize> {
+        let mut path = vec![idx];
+        let mut cur = idx;
+
+        while let Some(parent) = self.nodes[cur].parent {
+            path.push(parent);
+            cur = parent;
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Consumes the tree, returning the nodes from the root down to the given
+    /// one, indexed by their position among their siblings.
+    pub fn into_path(self, idx: usize) -> Vec<ListOption<T>> {
+        let path = self.path(idx);
+        let nodes = self.nodes.clone();
+
+        self.into_options(&path)
+            .into_iter()
+            .map(|option| ListOption::new(nodes[option.index].index, option.value))
+            .collect()
+    }
+
+    /// Consumes the tree, returning the given nodes, which must be sorted,
+    /// indexed by their position in the depth-first order.
+    pub fn into_options(self, idxs: &[usize]) -> Vec<ListOption<T>> {
+        let mut idxs = idxs.iter().peekable();
+
+        self.values
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, value)| match idxs.peek() {
+                Some(&&idx) if idx == i => {
+                    idxs.next();
+                    Some(ListOption::new(i, value))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the leaves below the given node, or the node itself when it
+    /// is a leaf.
+    pub fn leaves(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        (idx..self.nodes[idx].end).filter(move |i| self.is_leaf(*i))
+    }
+
+    /// Returns which nodes match the filter or have a descendant that does,
+    /// given which nodes match it.
+    pub fn relevant(&self, matches: &[bool]) -> Vec<bool> {
+        let mut relevant = matches.to_vec();
+
+        for idx in (0..self.len()).rev() {
+            if let (true, Some(parent)) = (relevant[idx], self.nodes[idx].parent) {
+                relevant[parent] = true;
+            }
+        }
+
+        relevant
+    }
+
+    /// Returns the nodes displayed, in order, skipping the descendants of
+    /// collapsed nodes and, when filtering, the nodes that are not relevant.
+    pub fn visible(&self, expanded: &[bool], relevant: Option<&[bool]>) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut idx = 0;
+
+        while idx < self.len() {
+            let shown = relevant.map(|r| r[idx]).unwrap_or(true);
+
+            if shown {
+                visible.push(idx);
+
+                if expanded[idx] {
+                    idx += 1;
+                    continue;
+                }
+            }
+
+            idx = self.nodes[idx].end;
+        }
+
+        visible
+    }
+
+    /// Returns, for each node, whether it is the last displayed one among its
+    /// siblings.
+    pub fn last_visible(&self, visible: &[usize]) -> Vec<bool> {
+        let mut last = vec![false; self.len()];
+        let mut seen = vec![false; self.len() + 1];
+
+        for &idx in visible.iter().rev() {
+            let parent = self.nodes[idx].parent.unwrap_or(self.len());
+
+            last[idx] = !seen[parent];
+            seen[parent] = true;
+        }
+
+        last
+    }
+
+    pub fn row(&self, idx: usize, expanded: &[bool], last: &[bool]) -> TreeRow<&T> {
+        let path = self.path(idx);
+        let guides = path
+            .iter()
+            .skip(1)
+            .take(path.len().saturating_sub(2))
+            .map(|&ancestor| !last[ancestor])
+            .collect();
+
+        TreeRow {
+            option: ListOption::new(self.nodes[idx].index, &self.values[idx]),
+            guides,
+            last: self.nodes[idx].parent.map(|_| last[idx]),
+            expanded: match self.is_leaf(idx) {
+                true => None,
+                false => Some(expanded[idx]),
+            },
+            checked: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FlatTree, TreeNode};
+    use crate::list_option::ListOption;
+
+    fn tree() -> FlatTree<&'static str> {
+        FlatTree::new(vec![
+            TreeNode::new("acme").with_children(vec![
+                TreeNode::new("platform")
+                    .with_children(vec![TreeNode::new("inquire"), TreeNode::new("tui")]),
+                TreeNode::new("infra"),
+            ]),
+            TreeNode::new("globex"),
+        ])
+    }
+
+    #[test]
+    fn nodes_are_flattened_depth_first() {
+        let tree = tree();
+
+        assert_eq!(
+            &["acme", "platform", "inquire", "tui", "infra", "globex"],
+            tree.values()
+        );
+        assert_eq!(5, tree.node(0).end);
+        assert_eq!(Some(1), tree.node(3).parent);
+        assert_eq!(1, tree.node(3).index);
+        assert_eq!(2, tree.node(3).depth);
+        assert!(tree.is_leaf(4));
+        assert!(!tree.is_leaf(1));
+    }
+
+    #[test]
+    fn collapsed_nodes_hide_descendants() {
+        let tree = tree();
+        let mut expanded = vec![false; tree.len()];
+
+        assert_eq!(vec![0, 5], tree.visible(&expanded, None));
+
+        expanded[0] = true;
+        assert_eq!(vec![0, 1, 4, 5], tree.visible(&expanded, None));
+
+        expanded[1] = true;
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], tree.visible(&expanded, None));
+    }
+
+    #[test]
+    fn ancestors_of_matches_are_relevant() {
+        let tree = tree();
+        let expanded = vec![true; tree.len()];
+        let matches = vec![false, false, false, true, false, false];
+        let relevant = tree.relevant(&matches);
+
+        assert_eq!(vec![0, 1, 3], tree.visible(&expanded, Some(&relevant)));
+    }
+
+    #[test]
+    fn guides_follow_displayed_siblings() {
+        let tree = tree();
+        let expanded = vec![true; tree.len()];
+        let visible = tree.visible(&expanded, None);
+        let last = tree.last_visible(&visible);
+
+        let row = tree.row(2, &expanded, &last);
+        assert_eq!(vec![true], row.guides);
+        assert_eq!(Some(false), row.last);
+        assert_eq!(None, row.expanded);
+
+        let row = tree.row(4, &expanded, &last);
+        assert!(row.guides.is_empty());
+        assert_eq!(Some(true), row.last);
+
+        let row = tree.row(0, &expanded, &last);
+        assert_eq!(None, row.last);
+        assert_eq!(Some(true), row.expanded);
+    }
+
+    #[test]
+    fn path_contains_sibling_indexes() {
+        assert_eq!(
+            vec![
+                ListOption::new(0, "acme"),
+                ListOption::new(0, "platform"),
+                ListOption::new(1, "tui"),
+            ],
+            tree().into_path(3)
+        );
+    }
+}
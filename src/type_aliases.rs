@@ -1,6 +1,8 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use std::ops::RangeInclusive;
+
+use crate::{autocompletion::TokenSuggestion, error::CustomUserError, ui::Preview};
 
 /// Type alias to represent the function used to filter options.
 ///
@@ -38,7 +40,132 @@ use crate::error::CustomUserError;
 /// ```
 pub type Filter<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> bool;
 
+/// Type alias to represent the function used to disable options in list prompts.
+///
+/// The function receives:
+/// - Current option being evaluated, with type preserved
+/// - Index of the current option in the original list
+///
+/// The return type should be `None` when the option can be selected, or the
+/// reason why it can not be selected otherwise. Non-empty reasons are displayed
+/// right after the option, wrapped in parenthesis.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionDisabler;
+///
+/// let disabler: OptionDisabler<str> = &|env, _| match env {
+///     "prod" => Some(String::from("no permission")),
+///     _ => None,
+/// };
+/// assert_eq!(None, disabler("dev", 0));
+/// assert_eq!(Some(String::from("no permission")), disabler("prod", 1));
+/// ```
+pub type OptionDisabler<'a, T> = &'a dyn Fn(&T, usize) -> Option<String>;
+
+/// Type alias to represent the function used to assign hotkeys to options in
+/// list prompts.
+///
+/// The function receives:
+/// - Current option being evaluated, with type preserved
+/// - Index of the current option in the original list
+///
+/// The return type should be the character that selects the option when
+/// pressed, or `None` when the option has no hotkey. The first occurrence of
+/// the hotkey in the option, ignoring case, is highlighted.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionHotkey;
+///
+/// let hotkey: OptionHotkey<str> = &|action, _| action.chars().next();
+/// assert_eq!(Some('y'), hotkey("yes", 0));
+/// assert_eq!(Some('q'), hotkey("quit", 4));
+/// ```
+pub type OptionHotkey<'a, T> = &'a dyn Fn(&T, usize) -> Option<char>;
+
+/// Type alias to represent the function used to define the minimum and maximum
+/// counts of each option in [`QuantitySelect`](crate::QuantitySelect) prompts.
+///
+/// The function receives:
+/// - Current option being evaluated, with type preserved
+/// - Index of the current option in the original list
+///
+/// The return type should be the inclusive range of counts allowed for the
+/// option, where a count of zero means the option is not selected.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::QuantityLimits;
+///
+/// let limits: QuantityLimits<str> = &|runner, _| match runner {
+///     "macos" => 0..=2,
+///     _ => 0..=8,
+/// };
+/// assert_eq!(0..=2, limits("macos", 0));
+/// assert_eq!(0..=8, limits("linux", 1));
+/// ```
+pub type QuantityLimits<'a, T> = &'a dyn Fn(&T, usize) -> RangeInclusive<u32>;
+
+/// Type alias to represent the function used to build the preview of the
+/// highlighted option in list prompts.
+///
+/// The function receives the option currently highlighted, with type preserved,
+/// and should return the [`Preview`] to be displayed, which can be built from
+/// plain text or from lines of styled spans.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Previewer;
+/// use inquire::ui::Preview;
+///
+/// let previewer: Previewer<&str> = &|branch| {
+///     Ok(Preview::from(format!("Branch: {}\nBehind origin by 0 commits", branch)))
+/// };
+///
+/// assert_eq!(2, previewer(&"main")?.lines().len());
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub type Previewer<'a, T> = &'a dyn Fn(&T) -> Result<Preview, CustomUserError>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
 pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError>;
+
+/// Type alias to represent the function used to retrieve suggestions for a single
+/// token of the text input, such as an argument in a command line.
+///
+/// The function receives the current input and the cursor position, as a byte index
+/// of the input, and should return a collection of [`TokenSuggestion`]s. Each suggestion
+/// contains the value to be displayed and the byte range of the input it replaces when
+/// accepted by the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::autocompletion::{current_token, TokenSuggestion};
+/// use inquire::type_aliases::TokenSuggester;
+///
+/// let suggester: TokenSuggester = &|input, cursor| {
+///     let range = current_token(input, cursor);
+///
+///     Ok(match &input[range.clone()] {
+///         "pr" => vec![TokenSuggestion::new("prod", range)],
+///         _ => vec![],
+///     })
+/// };
+///
+/// assert_eq!(
+///     vec![TokenSuggestion::new("prod", 13..15)],
+///     suggester("deploy --env pr --region", 15)?
+/// );
+/// assert_eq!(Vec::<TokenSuggestion>::new(), suggester("deploy --env", 12)?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub type TokenSuggester<'a> =
+    &'a dyn Fn(&str, usize) -> Result<Vec<TokenSuggestion>, CustomUserError>;
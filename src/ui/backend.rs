@@ -1,19 +1,36 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{
+    fmt::Display,
+    io::Result,
+    time::{Duration, Instant},
+};
 
-use unicode_width::UnicodeWidthChar;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{
     input::Input,
     list_option::ListOption,
     terminal::{Terminal, TerminalSize},
-    ui::{IndexPrefix, Key, RenderConfig, Styled, Color, StyleSheet},
-    utils::{int_log10, Page},
-    validator::ErrorMessage,    
+    tree::{CheckState, TreeRow},
+    ui::{IndexPrefix, Key, PreviewPosition, RenderConfig, Styled},
+    utils::{int_log10, same_hotkey, OptionRow, Page, PreviewPage, Selection},
+    validator::ErrorMessage,
 };
 
+/// Minimum number of blank columns between the options of option grids.
+const GRID_COLUMN_GAP: usize = 2;
+
 pub trait CommonBackend {
     fn read_key(&mut self) -> Result<Key>;
 
+    /// Starts a countdown, after which `read_key` returns [`Key::Timeout`]
+    /// unless a key is pressed before it.
+    fn set_timeout(&mut self, timeout: Duration);
+
+    /// Makes `read_key` return [`Key::Tick`] whenever the given interval
+    /// elapses without any key pressed, so that prompts render again content
+    /// that changed in the meantime. `None` waits for keys indefinitely.
+    fn set_refresh_interval(&mut self, interval: Option<Duration>);
+
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self) -> Result<()>;
 
@@ -41,15 +58,72 @@ pub trait EditorBackend: CommonBackend {
 
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        preview: Option<PreviewPage>,
+    ) -> Result<()>;
+    fn grid_columns(&self, value_width: usize, total: usize, preview_beside: bool) -> usize;
+    fn render_option_grid<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        columns: usize,
+        value_width: usize,
+        preview: Option<PreviewPage>,
+    ) -> Result<()>;
+    fn render_loaded_indicator(&mut self, loaded: usize) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        counter: Option<&str>,
+        cur_input: &Input,
+    ) -> Result<()>;
     fn render_options<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        checked: &Selection,
+        preview: Option<PreviewPage>,
+    ) -> Result<()>;
+    fn grid_columns(&self, value_width: usize, total: usize, preview_beside: bool) -> usize;
+    fn render_option_grid<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        columns: usize,
+        value_width: usize,
+        checked: &Selection,
+        preview: Option<PreviewPage>,
+    ) -> Result<()>;
+    fn render_loaded_indicator(&mut self, loaded: usize) -> Result<()>;
+}
+
+pub trait TreeSelectBackend: CommonBackend {
+    fn render_tree_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_tree<D: Display>(&mut self, page: Page<TreeRow<D>>) -> Result<()>;
+}
+
+pub trait TreeMultiSelectBackend: CommonBackend {
+    fn render_tree_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()>;
+    fn render_tree<D: Display>(&mut self, page: Page<TreeRow<D>>) -> Result<()>;
+}
+
+pub trait QuantitySelectBackend: CommonBackend {
+    fn render_quantity_select_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_quantities<D: Display>(
         &mut self,
         page: Page<ListOption<D>>,
-        checked: &BTreeSet<usize>,
+        counts: &[u32],
+    ) -> Result<()>;
+}
+
+pub trait ReorderBackend: CommonBackend {
+    fn render_reorder_prompt(&mut self, prompt: &str) -> Result<()>;
+    fn render_reorder_options<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        moving: bool,
     ) -> Result<()>;
 }
 
@@ -60,6 +134,40 @@ pub trait CustomTypeBackend: CommonBackend {
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
+
+    /// Renders the prompt along with a live preview of the parsed value,
+    /// where `None` means the input could not be parsed.
+    fn render_prompt_with_preview(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        preview: Option<&str>,
+        position: PreviewPosition,
+    ) -> Result<()>;
+}
+
+pub trait ConfirmBackend: CustomTypeBackend {
+    fn render_confirm_toggle(
+        &mut self,
+        prompt: &str,
+        yes: &str,
+        no: &str,
+        value: bool,
+    ) -> Result<()>;
+}
+
+pub trait NumberBackend: CustomTypeBackend {
+    fn render_slider(&mut self, min: &str, max: &str, position: f64, width: usize) -> Result<()>;
+}
+
+pub trait ExpandBackend: CommonBackend {
+    fn render_expand_prompt(&mut self, prompt: &str, hint: &str) -> Result<()>;
+    fn render_expand_options<D: Display>(
+        &mut self,
+        options: &[ListOption<D>],
+        hotkeys: &[char],
+    ) -> Result<()>;
 }
 
 pub trait PasswordBackend: CommonBackend {
@@ -83,6 +191,9 @@ where
     prompt_cursor_offset: Option<usize>,
     prompt_cursor_position: Option<Position>,
     show_cursor: bool,
+    deadline: Option<Instant>,
+    refresh_interval: Option<Duration>,
+    help_message_rendered: bool,
     terminal: T,
     terminal_size: TerminalSize,
     render_config: RenderConfig,
@@ -104,6 +215,9 @@ where
             prompt_cursor_offset: None,
             prompt_cursor_position: None,
             show_cursor: false,
+            deadline: None,
+            refresh_interval: None,
+            help_message_rendered: false,
             terminal,
             render_config,
             terminal_size,
@@ -202,18 +316,20 @@ where
         Ok(())
     }
 
-    fn print_option_prefix<D: Display>(
-        &mut self,
-        idx: usize,
-        page: &Page<ListOption<D>>,
-    ) -> Result<()> {
+    fn print_option_prefix<R>(&mut self, idx: usize, page: &Page<R>) -> Result<()> {
+        self.print_cell_prefix(idx, page, page.content.len().saturating_sub(1))
+    }
+
+    /// Prints the prefix of a cell of the page, given the index of the first
+    /// cell of its last line, which holds the scroll down prefix.
+    fn print_cell_prefix<R>(&mut self, idx: usize, page: &Page<R>, last_line: usize) -> Result<()> {
         let empty_prefix = Styled::new(" ");
 
         let x = if idx == page.selection {
             self.render_config.highlighted_option_prefix
         } else if idx == 0 && !page.first {
             self.render_config.scroll_up_prefix
-        } else if (idx + 1) == page.content.len() && !page.last {
+        } else if idx == last_line && !page.last {
             self.render_config.scroll_down_prefix
         } else {
             empty_prefix
@@ -227,6 +343,528 @@ where
             .write_styled(&Styled::new(&option.value).with_style_sheet(self.render_config.option))
     }
 
+    fn print_option_value_with_hotkey<D: Display>(
+        &mut self,
+        option: &ListOption<D>,
+        hotkey: Option<char>,
+    ) -> Result<()> {
+        let hotkey = match hotkey {
+            Some(hotkey) => hotkey,
+            None => return self.print_option_value(option),
+        };
+
+        let value = option.value.to_string();
+        let style = self.render_config.option;
+        let hotkey_style = self.render_config.option_hotkey;
+
+        match value.char_indices().find(|(_, c)| same_hotkey(*c, hotkey)) {
+            Some((start, c)) => {
+                let end = start + c.len_utf8();
+
+                self.terminal
+                    .write_styled(&Styled::new(&value[..start]).with_style_sheet(style))?;
+                self.terminal.write_styled(
+                    &Styled::new(&value[start..end]).with_style_sheet(hotkey_style),
+                )?;
+                self.terminal
+                    .write_styled(&Styled::new(&value[end..]).with_style_sheet(style))
+            }
+            None => {
+                self.terminal
+                    .write_styled(&Styled::new(&value).with_style_sheet(style))?;
+                self.terminal.write(" ")?;
+                self.terminal.write_styled(
+                    &Styled::new(format!("({})", hotkey)).with_style_sheet(hotkey_style),
+                )
+            }
+        }
+    }
+
+    fn print_disabled_option_value<D: Display>(
+        &mut self,
+        option: &ListOption<D>,
+        reason: &str,
+    ) -> Result<()> {
+        let style = self.render_config.disabled_option;
+
+        self.terminal
+            .write_styled(&Styled::new(&option.value).with_style_sheet(style))?;
+
+        if !reason.is_empty() {
+            self.terminal.write(" ")?;
+            self.terminal
+                .write_styled(&Styled::new(format!("({})", reason)).with_style_sheet(style))?;
+        }
+
+        Ok(())
+    }
+
+    fn print_create_option(&mut self, input: &str) -> Result<()> {
+        let content = format!("+ create '{}'", input);
+        self.terminal
+            .write_styled(&Styled::new(content).with_style_sheet(self.render_config.create_option))
+    }
+
+    fn print_option_group_header(&mut self, title: &str) -> Result<()> {
+        self.terminal.write_styled(
+            &Styled::new(title).with_style_sheet(self.render_config.option_group_header),
+        )
+    }
+
+    fn print_option_group_separator(&mut self) -> Result<()> {
+        self.terminal
+            .write_styled(&self.render_config.option_group_separator)
+    }
+
+    /// Prints the content of an option row, after its prefix, along with its
+    /// checkbox when the checked options are given.
+    fn print_option_row<D: Display>(
+        &mut self,
+        row: &OptionRow<D>,
+        total: usize,
+        checked: Option<&Selection>,
+    ) -> Result<()> {
+        match row {
+            OptionRow::Header { title, checked: c } => {
+                if checked.is_some() {
+                    self.print_checkbox(*c)?;
+                    self.terminal.write(" ")?;
+                }
+
+                self.print_option_group_header(title)?;
+            }
+            OptionRow::Separator => self.print_option_group_separator()?,
+            OptionRow::Option {
+                option,
+                prefix_index,
+                hotkey,
+            } => {
+                if let Some(res) = self.print_option_index_prefix(*prefix_index, total) {
+                    res?;
+                    self.terminal.write(" ")?;
+                }
+
+                if let Some(checked) = checked {
+                    self.print_selection_checkbox(checked, option.index)?;
+                    self.terminal.write(" ")?;
+                }
+
+                self.print_option_value_with_hotkey(option, *hotkey)?;
+            }
+            OptionRow::Disabled {
+                option,
+                prefix_index,
+                reason,
+            } => {
+                if let Some(res) = self.print_option_index_prefix(*prefix_index, total) {
+                    res?;
+                    self.terminal.write(" ")?;
+                }
+
+                if let Some(checked) = checked {
+                    self.print_selection_checkbox(checked, option.index)?;
+                    self.terminal.write(" ")?;
+                }
+
+                self.print_disabled_option_value(option, reason)?;
+            }
+            OptionRow::Create { input } => self.print_create_option(input)?,
+        }
+
+        Ok(())
+    }
+
+    fn print_option_rows<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        checked: Option<&Selection>,
+        preview: Option<PreviewPage>,
+    ) -> Result<()> {
+        for (idx, row) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.terminal.write(" ")?;
+
+            self.print_option_row(row, page.total, checked)?;
+
+            if let Some(preview) = &preview {
+                if preview.position == PreviewPosition::Beside {
+                    self.print_preview_beside(preview, idx)?;
+                }
+            }
+
+            self.new_line()?;
+        }
+
+        if let Some(preview) = &preview {
+            self.print_preview_remainder(preview, page.content.len())?;
+        }
+
+        Ok(())
+    }
+
+    /// Width of the columns of option grids, made of the prefixes printed
+    /// before option values, the widest value and the gap between columns.
+    fn grid_column_width(&self, value_width: usize, total: usize, checkbox: bool) -> usize {
+        let config = &self.render_config;
+
+        let prefix = [
+            config.highlighted_option_prefix.content,
+            config.scroll_up_prefix.content,
+            config.scroll_down_prefix.content,
+        ]
+        .iter()
+        .map(|p| UnicodeWidthStr::width(*p))
+        .max()
+        .unwrap_or_default();
+
+        let index_prefix = match config.option_index_prefix {
+            IndexPrefix::None => 0,
+            _ => int_log10(total.saturating_add(1)) + 2,
+        };
+
+        let checkbox = match checkbox {
+            true => {
+                let selected = UnicodeWidthStr::width(config.selected_checkbox.content);
+                let unselected = UnicodeWidthStr::width(config.unselected_checkbox.content);
+
+                selected.max(unselected) + 1
+            }
+            false => 0,
+        };
+
+        prefix + 1 + index_prefix + checkbox + value_width + GRID_COLUMN_GAP
+    }
+
+    fn grid_column_count(
+        &self,
+        value_width: usize,
+        total: usize,
+        checkbox: bool,
+        preview_beside: bool,
+    ) -> usize {
+        let width = match preview_beside {
+            true => self.terminal_size.width / 2,
+            false => self.terminal_size.width,
+        } as usize;
+        let column_width = self.grid_column_width(value_width, total, checkbox);
+
+        (width.saturating_add(GRID_COLUMN_GAP) / column_width).max(1)
+    }
+
+    /// Prints the options of the page flowing from left to right into the
+    /// given number of columns.
+    fn print_option_grid<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        columns: usize,
+        value_width: usize,
+        checked: Option<&Selection>,
+        preview: Option<PreviewPage>,
+    ) -> Result<()> {
+        let column_width = self.grid_column_width(value_width, page.total, checked.is_some());
+        let last_line = page.content.len().saturating_sub(1) / columns * columns;
+        let mut lines = 0;
+
+        for (line, cells) in page.content.chunks(columns).enumerate() {
+            for (column, row) in cells.iter().enumerate() {
+                let start = self.current_line_width();
+
+                self.print_cell_prefix(line * columns + column, &page, last_line)?;
+
+                self.terminal.write(" ")?;
+
+                self.print_option_row(row, page.total, checked)?;
+
+                if column + 1 < cells.len() {
+                    let width = self.current_line_width().saturating_sub(start);
+                    let padding = column_width.saturating_sub(width).max(1);
+
+                    self.terminal.write(" ".repeat(padding))?;
+                }
+            }
+
+            if let Some(preview) = &preview {
+                if preview.position == PreviewPosition::Beside {
+                    self.print_preview_beside(preview, line)?;
+                }
+            }
+
+            self.new_line()?;
+            lines += 1;
+        }
+
+        if let Some(preview) = &preview {
+            self.print_preview_remainder(preview, lines)?;
+        }
+
+        Ok(())
+    }
+
+    /// Prints the options of a page along with their counts, which replace the
+    /// checkboxes of multi-select options and are padded to the widest one.
+    fn print_quantity_rows<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        counts: &[u32],
+    ) -> Result<()> {
+        let max_count = counts.iter().max().cloned().unwrap_or_default();
+        let width = int_log10(max_count).max(1);
+
+        for (idx, option) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.terminal.write(" ")?;
+
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.terminal.write(" ")?;
+            }
+
+            let config = self.render_config.quantity;
+            let count = counts.get(option.index).cloned().unwrap_or_default();
+            let style = match count {
+                0 => config.zero_count,
+                _ => config.count,
+            };
+            let content = format!("{:>width$}", count, width = width);
+
+            self.terminal.write_styled(&config.opening)?;
+            self.terminal
+                .write_styled(&Styled::new(content).with_style_sheet(style))?;
+            self.terminal.write_styled(&config.closing)?;
+            self.terminal.write(" ")?;
+
+            self.print_option_value(option)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    /// Prints the options of a page indexed by their current position,
+    /// highlighting the selected one as being moved when `moving` is set.
+    fn print_reorder_rows<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        moving: bool,
+    ) -> Result<()> {
+        for (idx, option) in page.content.iter().enumerate() {
+            let is_moving = moving && idx == page.selection;
+
+            match is_moving {
+                true => self
+                    .terminal
+                    .write_styled(&self.render_config.moving_option_prefix)?,
+                false => self.print_option_prefix(idx, &page)?,
+            }
+
+            self.terminal.write(" ")?;
+
+            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
+                res?;
+                self.terminal.write(" ")?;
+            }
+
+            match is_moving {
+                true => self.terminal.write_styled(
+                    &Styled::new(&option.value).with_style_sheet(self.render_config.moving_option),
+                )?,
+                false => self.print_option_value(option)?,
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn print_checkbox(&mut self, checked: bool) -> Result<()> {
+        match checked {
+            true => self
+                .terminal
+                .write_styled(&self.render_config.selected_checkbox),
+            false => self
+                .terminal
+                .write_styled(&self.render_config.unselected_checkbox),
+        }
+    }
+
+    /// Prints the checkbox of an option or, when the selection order is
+    /// recorded, its position in the selection.
+    fn print_selection_checkbox(&mut self, checked: &Selection, idx: usize) -> Result<()> {
+        match checked.ordinal(idx) {
+            Some(ordinal) => {
+                let content = format!("[{}]", ordinal);
+                let style = self.render_config.selected_checkbox.style;
+
+                self.terminal
+                    .write_styled(&Styled::new(content).with_style_sheet(style))
+            }
+            None => self.print_checkbox(checked.contains(&idx)),
+        }
+    }
+
+    fn print_check_state(&mut self, state: CheckState) -> Result<()> {
+        match state {
+            CheckState::Checked => self.print_checkbox(true),
+            CheckState::Unchecked => self.print_checkbox(false),
+            CheckState::Indeterminate => self
+                .terminal
+                .write_styled(&self.render_config.indeterminate_checkbox),
+        }
+    }
+
+    fn print_tree_rows<D: Display>(&mut self, page: Page<TreeRow<D>>) -> Result<()> {
+        for (idx, row) in page.content.iter().enumerate() {
+            self.print_option_prefix(idx, &page)?;
+
+            self.terminal.write(" ")?;
+
+            self.print_tree_guides(row)?;
+            self.print_tree_marker(row.expanded)?;
+
+            self.terminal.write(" ")?;
+
+            if let Some(state) = row.checked {
+                self.print_check_state(state)?;
+                self.terminal.write(" ")?;
+            }
+
+            self.print_option_value(&row.option)?;
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn print_tree_guides<D>(&mut self, row: &TreeRow<D>) -> Result<()> {
+        let config = self.render_config.tree;
+
+        for &vertical in &row.guides {
+            match vertical {
+                true => self.terminal.write_styled(&config.vertical_guide)?,
+                false => self.print_blank(config.vertical_guide.content)?,
+            }
+        }
+
+        match row.last {
+            Some(true) => self.terminal.write_styled(&config.last_branch_guide),
+            Some(false) => self.terminal.write_styled(&config.branch_guide),
+            None => Ok(()),
+        }
+    }
+
+    fn print_tree_marker(&mut self, expanded: Option<bool>) -> Result<()> {
+        let config = self.render_config.tree;
+
+        match expanded {
+            Some(true) => self.terminal.write_styled(&config.expanded_marker),
+            Some(false) => self.terminal.write_styled(&config.collapsed_marker),
+            None => self.print_blank(config.collapsed_marker.content),
+        }
+    }
+
+    /// Prints spaces with the same width as the given content.
+    fn print_blank(&mut self, content: &str) -> Result<()> {
+        self.terminal
+            .write(" ".repeat(UnicodeWidthStr::width(content)))
+    }
+
+    fn current_line_width(&self) -> usize {
+        let content = self.terminal.get_in_memory_content();
+        let line = content.rsplit('\n').next().unwrap_or_default();
+
+        UnicodeWidthStr::width(line)
+    }
+
+    /// Prints a line of the preview pane, truncated so that it fits in the
+    /// remaining width of the terminal.
+    fn print_preview_line(&mut self, preview: &PreviewPage, idx: usize) -> Result<()> {
+        let border = if idx == 0 && !preview.first {
+            self.render_config.scroll_up_prefix
+        } else if (idx + 1) == preview.lines.len() && !preview.last {
+            self.render_config.scroll_down_prefix
+        } else {
+            self.render_config.preview_border
+        };
+
+        self.terminal.write_styled(&border)?;
+        self.terminal.write(" ")?;
+
+        let mut available = (self.terminal_size.width as usize)
+            .saturating_sub(self.current_line_width().saturating_add(1));
+
+        for span in &preview.lines[idx] {
+            let mut content = String::new();
+
+            for c in span.content.chars() {
+                let width = UnicodeWidthChar::width(c).unwrap_or(0);
+                if width > available {
+                    available = 0;
+                    break;
+                }
+
+                available -= width;
+                content.push(c);
+            }
+
+            self.terminal
+                .write_styled(&Styled::new(content).with_style_sheet(span.style))?;
+
+            if available == 0 {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prints the line of the preview pane matching the given row of the option
+    /// list, when the preview is displayed beside it.
+    fn print_preview_beside(&mut self, preview: &PreviewPage, row: usize) -> Result<()> {
+        if row >= preview.lines.len() {
+            return Ok(());
+        }
+
+        let column = (self.terminal_size.width / 2) as usize;
+        let padding = column.saturating_sub(self.current_line_width()).max(1);
+
+        self.terminal.write(" ".repeat(padding))?;
+        self.print_preview_line(preview, row)
+    }
+
+    /// Prints the lines of the preview pane that were not printed beside the
+    /// option list, or the whole preview when it is displayed below the list.
+    fn print_preview_remainder(&mut self, preview: &PreviewPage, rows: usize) -> Result<()> {
+        let start = match preview.position {
+            PreviewPosition::Below => 0,
+            PreviewPosition::Beside => rows,
+        };
+
+        for idx in start..preview.lines.len() {
+            match preview.position {
+                PreviewPosition::Below => self.print_preview_line(preview, idx)?,
+                PreviewPosition::Beside => self.print_preview_beside(preview, idx)?,
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn print_loaded_indicator(&mut self, loaded: usize) -> Result<()> {
+        let content = format!("{} loaded", loaded);
+        self.terminal.write_styled(
+            &Styled::new(content).with_style_sheet(self.render_config.loaded_indicator),
+        )?;
+
+        self.new_line()
+    }
+
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
         let index = index.saturating_add(1);
 
@@ -256,6 +894,13 @@ where
         self.terminal.write_styled(&token)
     }
 
+    fn print_selection_counter(&mut self, counter: &str) -> Result<()> {
+        let content = format!("[{}]", counter);
+        let token = Styled::new(content).with_style_sheet(self.render_config.selection_counter);
+
+        self.terminal.write_styled(&token)
+    }
+
     fn print_prompt_with_prefix(&mut self, prefix: Styled<&str>, prompt: &str) -> Result<()> {
         self.terminal.write_styled(&prefix)?;
 
@@ -280,8 +925,7 @@ where
 
         if input.is_empty() {
             match input.placeholder() {
-                None => {}
-                Some(p) if p.is_empty() => {}
+                None | Some("") => {}
                 Some(p) => self.terminal.write_styled(
                     &Styled::new(p).with_style_sheet(self.render_config.placeholder),
                 )?,
@@ -332,6 +976,32 @@ where
         self.terminal.write("\r\n")?;
         Ok(())
     }
+
+    /// Text of the countdown of the timeout, if running, rounding
+    /// the remaining time up to whole seconds.
+    fn countdown(&self) -> Option<String> {
+        self.deadline.map(|deadline| {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+
+            format!("{}s left", seconds)
+        })
+    }
+
+    fn print_help_line(&mut self, help: Option<&str>) -> Result<()> {
+        let content = match (help, self.countdown()) {
+            (Some(help), Some(countdown)) => format!("[{}, {}]", help, countdown),
+            (Some(help), None) => format!("[{}]", help),
+            (None, Some(countdown)) => format!("[{}]", countdown),
+            (None, None) => return Ok(()),
+        };
+
+        self.terminal.write_styled(
+            &Styled::new(content).with_style_sheet(self.render_config.help_message),
+        )?;
+
+        self.new_line()
+    }
 }
 
 impl<T> CommonBackend for Backend<T>
@@ -341,11 +1011,18 @@ where
     fn frame_setup(&mut self) -> Result<()> {
         self.terminal.cursor_hide()?;
         self.terminal.flush()?;
+        self.help_message_rendered = false;
 
         self.reset_prompt()
     }
 
     fn frame_finish(&mut self) -> Result<()> {
+        // the countdown is displayed in the help line, added here to
+        // prompts that did not render a help message in this frame
+        if !self.help_message_rendered {
+            self.print_help_line(None)?;
+        }
+
         self.update_position_info();
 
         if let Some(prompt_cursor_position) = self.prompt_cursor_position {
@@ -390,7 +1067,52 @@ where
     }
 
     fn read_key(&mut self) -> Result<Key> {
-        self.terminal.read_key()
+        let countdown_tick = match self.deadline {
+            Some(_) if !self.terminal.supports_timeouts() => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "Timeouts are not supported by this terminal",
+                ));
+            }
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                if remaining == Duration::from_secs(0) {
+                    self.deadline = None;
+                    return Ok(Key::Timeout);
+                }
+
+                // waits until the displayed countdown changes, at whole seconds
+                Some(match remaining.subsec_nanos() {
+                    0 => Duration::from_secs(1),
+                    nanos => Duration::from_nanos(u64::from(nanos)),
+                })
+            }
+            None => None,
+        };
+
+        let tick = match (countdown_tick, self.refresh_interval) {
+            (Some(countdown), Some(refresh)) => countdown.min(refresh),
+            (Some(tick), None) | (None, Some(tick)) => tick,
+            (None, None) => return self.terminal.read_key(),
+        };
+
+        match self.terminal.poll_key(tick)? {
+            Some(key) => {
+                // the first key pressed stops the countdown
+                self.deadline = None;
+                Ok(key)
+            }
+            None => Ok(Key::Tick),
+        }
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) {
+        self.deadline = Some(Instant::now() + timeout);
+    }
+
+    fn set_refresh_interval(&mut self, interval: Option<Duration>) {
+        self.refresh_interval = interval;
     }
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
@@ -416,18 +1138,9 @@ where
     }
 
     fn render_help_message(&mut self, help: &str) -> Result<()> {
-        self.terminal
-            .write_styled(&Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+        self.help_message_rendered = true;
 
-        self.terminal
-            .write_styled(&Styled::new(help).with_style_sheet(self.render_config.help_message))?;
-
-        self.terminal
-            .write_styled(&Styled::new("]").with_style_sheet(self.render_config.help_message))?;
-
-        self.new_line()?;
-
-        Ok(())
+        self.print_help_line(Some(help))
     }
 }
 
@@ -487,62 +1200,169 @@ where
         self.print_prompt_with_input(prompt, None, cur_input)
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<ListOption<D>>) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
-            self.print_option_prefix(idx, &page)?;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        preview: Option<PreviewPage>,
+    ) -> Result<()> {
+        self.print_option_rows(page, None, preview)
+    }
 
-            self.terminal.write(" ")?;
+    fn grid_columns(&self, value_width: usize, total: usize, preview_beside: bool) -> usize {
+        self.grid_column_count(value_width, total, false, preview_beside)
+    }
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
-                self.terminal.write(" ")?;
-            }
+    fn render_option_grid<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        columns: usize,
+        value_width: usize,
+        preview: Option<PreviewPage>,
+    ) -> Result<()> {
+        self.print_option_grid(page, columns, value_width, None, preview)
+    }
 
-            self.print_option_value(option)?;
+    fn render_loaded_indicator(&mut self, loaded: usize) -> Result<()> {
+        self.print_loaded_indicator(loaded)
+    }
+}
 
-            self.new_line()?;
+impl<T> MultiSelectBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_multiselect_prompt(
+        &mut self,
+        prompt: &str,
+        counter: Option<&str>,
+        cur_input: &Input,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(counter) = counter {
+            self.terminal.write(" ")?;
+            self.print_selection_counter(counter)?;
         }
 
-        Ok(())
+        self.print_input(cur_input)?;
+
+        self.new_line()
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        checked: &Selection,
+        preview: Option<PreviewPage>,
+    ) -> Result<()> {
+        self.print_option_rows(page, Some(checked), preview)
+    }
+
+    fn grid_columns(&self, value_width: usize, total: usize, preview_beside: bool) -> usize {
+        self.grid_column_count(value_width, total, true, preview_beside)
+    }
+
+    fn render_option_grid<D: Display>(
+        &mut self,
+        page: Page<OptionRow<D>>,
+        columns: usize,
+        value_width: usize,
+        checked: &Selection,
+        preview: Option<PreviewPage>,
+    ) -> Result<()> {
+        self.print_option_grid(page, columns, value_width, Some(checked), preview)
+    }
+
+    fn render_loaded_indicator(&mut self, loaded: usize) -> Result<()> {
+        self.print_loaded_indicator(loaded)
     }
 }
 
-impl<T> MultiSelectBackend for Backend<T>
+impl<T> TreeSelectBackend for Backend<T>
 where
     T: Terminal,
 {
-    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+    fn render_tree_select_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
         self.print_prompt_with_input(prompt, None, cur_input)
     }
 
-    fn render_options<D: Display>(
+    fn render_tree<D: Display>(&mut self, page: Page<TreeRow<D>>) -> Result<()> {
+        self.print_tree_rows(page)
+    }
+}
+
+impl<T> TreeMultiSelectBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_tree_multiselect_prompt(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        self.print_prompt_with_input(prompt, None, cur_input)
+    }
+
+    fn render_tree<D: Display>(&mut self, page: Page<TreeRow<D>>) -> Result<()> {
+        self.print_tree_rows(page)
+    }
+}
+
+impl<T> QuantitySelectBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_quantity_select_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
+
+    fn render_quantities<D: Display>(
         &mut self,
         page: Page<ListOption<D>>,
-        checked: &BTreeSet<usize>,
+        counts: &[u32],
     ) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
-            self.print_option_prefix(idx, &page)?;
+        self.print_quantity_rows(page, counts)
+    }
+}
 
-            self.terminal.write(" ")?;
+impl<T> ReorderBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_reorder_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.new_line()
+    }
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
-                self.terminal.write(" ")?;
-            }
+    fn render_reorder_options<D: Display>(
+        &mut self,
+        page: Page<ListOption<D>>,
+        moving: bool,
+    ) -> Result<()> {
+        self.print_reorder_rows(page, moving)
+    }
+}
 
-            match checked.contains(&option.index) {
-                true => self
-                    .terminal
-                    .write_styled(&self.render_config.selected_checkbox)?,
-                false => self
-                    .terminal
-                    .write_styled(&self.render_config.unselected_checkbox)?,
-            }
+impl<T> ExpandBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_expand_prompt(&mut self, prompt: &str, hint: &str) -> Result<()> {
+        self.print_prompt(prompt)?;
+        self.terminal.write(" ")?;
+        self.print_default_value(hint)?;
+        self.new_line()
+    }
 
-            self.terminal.write(" ")?;
+    fn render_expand_options<D: Display>(
+        &mut self,
+        options: &[ListOption<D>],
+        hotkeys: &[char],
+    ) -> Result<()> {
+        for (option, hotkey) in options.iter().zip(hotkeys) {
+            let hotkey = Styled::new(hotkey).with_style_sheet(self.render_config.option_hotkey);
 
+            self.terminal.write("  ")?;
+            self.terminal.write_styled(&hotkey)?;
+            self.terminal.write(") ")?;
             self.print_option_value(option)?;
-
             self.new_line()?;
         }
 
@@ -560,8 +1380,8 @@ pub mod date {
 
     use super::{Backend, CommonBackend};
 
-        pub type Accessor<'a> = &'a dyn Fn (chrono::NaiveDate) -> Vec<String>;
-        
+    pub type Accessor<'a> = &'a dyn Fn(chrono::NaiveDate) -> Vec<String>;
+
     pub trait DateSelectBackend<'a>: CommonBackend {
         fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()>;
 
@@ -575,7 +1395,7 @@ pub mod date {
             selected_date: chrono::NaiveDate,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
-                        items: &Option<Accessor<'a>>,
+            items: &Option<Accessor<'a>>,
         ) -> Result<()>;
     }
 
@@ -650,17 +1470,17 @@ pub mod date {
                 }
             }
 
-	    let mut max = 0;
-	    if let Some(ref accessor) = items {		
-		let temp = get_start_date(month, year);
-		let mut amounts = Vec::new();
-		for _ in 0..42 {
-		    amounts.push(accessor(date_it).len());
-		    println!("{}", accessor(date_it).len());
-		}
-		
-		max = *amounts.iter().max().unwrap();		
-	    }
+            let mut max = 0;
+            if let Some(ref accessor) = items {
+                let temp = get_start_date(month, year);
+                let mut amounts = Vec::new();
+                for _ in 0..42 {
+                    amounts.push(accessor(date_it).len());
+                    println!("{}", accessor(date_it).len());
+                }
+
+                max = *amounts.iter().max().unwrap();
+            }
             for _ in 0..6 {
                 write_prefix!()?;
 
@@ -702,15 +1522,19 @@ pub mod date {
                     }
 
                     if let Some(ref accessor) = items {
-			let l = accessor(date_it).len();
+                        let l = accessor(date_it).len();
                         if l > 0 {
-			    let ratio = (l as f64)/(max as f64);
-			    println!("{}", ratio);
-			    let r = (255f64 * ratio + 108f64 * (1f64-ratio)).ceil();
-			    let g = (255f64 * ratio + 173f64 * (1f64-ratio)).ceil();
-			    let b = (255f64 * ratio + 80f64 * (1f64-ratio)).ceil();			    
-                            style_sheet = style_sheet.with_fg(super::Color::Rgb {r: r as u8, g: g as u8, b: b as u8});
-                        }			
+                            let ratio = (l as f64) / (max as f64);
+                            println!("{}", ratio);
+                            let r = (255f64 * ratio + 108f64 * (1f64 - ratio)).ceil();
+                            let g = (255f64 * ratio + 173f64 * (1f64 - ratio)).ceil();
+                            let b = (255f64 * ratio + 80f64 * (1f64 - ratio)).ceil();
+                            style_sheet = style_sheet.with_fg(crate::ui::Color::Rgb {
+                                r: r as u8,
+                                g: g as u8,
+                                b: b as u8,
+                            });
+                        }
                     }
                     let token = Styled::new(date).with_style_sheet(style_sheet);
                     self.terminal.write_styled(&token)?;
@@ -720,9 +1544,9 @@ pub mod date {
                 self.new_line()?;
             }
             if let Some(ref accessor) = items {
-                for i in accessor(selected_date) {                                      
+                for i in accessor(selected_date) {
                     self.terminal.write(i)?;
-		    self.terminal.write("\n");
+                    self.terminal.write("\n");
                     self.new_line()?;
                 }
             }
@@ -743,6 +1567,105 @@ where
     ) -> Result<()> {
         self.print_prompt_with_input(prompt, default, cur_input)
     }
+
+    fn render_prompt_with_preview(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        preview: Option<&str>,
+        position: PreviewPosition,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.terminal.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.print_input(cur_input)?;
+
+        match position {
+            PreviewPosition::Below => {
+                self.new_line()?;
+                self.terminal.write("  ")?;
+            }
+            PreviewPosition::Beside => self.terminal.write(" ")?,
+        }
+
+        let value_preview = self.render_config.value_preview;
+        match preview {
+            Some(value) => {
+                let content = format!("→ {}", value);
+                self.terminal
+                    .write_styled(&Styled::new(content).with_style_sheet(value_preview))?;
+            }
+            None => {
+                self.terminal
+                    .write_styled(&Styled::new("→ ").with_style_sheet(value_preview))?;
+                self.terminal
+                    .write_styled(&self.render_config.invalid_value_preview)?;
+            }
+        }
+
+        self.new_line()
+    }
+}
+
+impl<T> ConfirmBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_confirm_toggle(
+        &mut self,
+        prompt: &str,
+        yes: &str,
+        no: &str,
+        value: bool,
+    ) -> Result<()> {
+        let choice_style = |active: bool| match active {
+            true => self.render_config.active_choice,
+            false => self.render_config.inactive_choice,
+        };
+        let yes = Styled::new(yes).with_style_sheet(choice_style(value));
+        let no = Styled::new(no).with_style_sheet(choice_style(!value));
+
+        self.print_prompt(prompt)?;
+        self.terminal.write(" ")?;
+        self.terminal.write_styled(&yes)?;
+        self.terminal.write(" / ")?;
+        self.terminal.write_styled(&no)?;
+        self.new_line()?;
+
+        Ok(())
+    }
+}
+
+impl<T> NumberBackend for Backend<T>
+where
+    T: Terminal,
+{
+    fn render_slider(&mut self, min: &str, max: &str, position: f64, width: usize) -> Result<()> {
+        let slider = self.render_config.slider;
+        let handle = (position.clamp(0.0, 1.0) * (width - 1) as f64).round() as usize;
+
+        self.terminal
+            .write_styled(&Styled::new(min).with_style_sheet(slider.bounds))?;
+        self.terminal.write(" ")?;
+
+        for _ in 0..handle {
+            self.terminal.write_styled(&slider.filled)?;
+        }
+        self.terminal.write_styled(&slider.handle)?;
+        for _ in handle + 1..width {
+            self.terminal.write_styled(&slider.empty)?;
+        }
+
+        self.terminal.write(" ")?;
+        self.terminal
+            .write_styled(&Styled::new(max).with_style_sheet(slider.bounds))?;
+        self.new_line()
+    }
 }
 
 impl<T> PasswordBackend for Backend<T>
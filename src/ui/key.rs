@@ -28,6 +28,13 @@ pub enum Key {
     Left(KeyModifiers),
     Right(KeyModifiers),
     Char(char, KeyModifiers),
+    /// No key was pressed while a timeout countdown is running or while the
+    /// prompt waits for options from a source, returned whenever the
+    /// countdown changes or the refresh interval elapses so that prompts
+    /// render again.
+    Tick,
+    /// The timeout of the prompt elapsed before any key was pressed.
+    Timeout,
     #[deprecated(note = "Please implement the proper matcher for your key on key.rs")]
     Any,
 }
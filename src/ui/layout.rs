@@ -0,0 +1,11 @@
+/// Layout of the options displayed by list prompts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum OptionLayout {
+    /// Options are displayed one per row.
+    List,
+
+    /// Options flow from left to right into as many columns as fit the
+    /// terminal width, suitable for short options. Each line of the grid
+    /// counts as one row of the page.
+    Grid,
+}
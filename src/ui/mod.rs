@@ -3,6 +3,8 @@
 mod backend;
 mod color;
 mod key;
+mod layout;
+mod preview;
 mod render_config;
 mod style;
 
@@ -10,5 +12,7 @@ pub(in crate) use backend::*;
 pub(in crate) use key::{Key, KeyModifiers};
 
 pub use color::Color;
+pub use layout::OptionLayout;
+pub use preview::{Preview, PreviewPosition};
 pub use render_config::*;
 pub use style::{Attributes, StyleSheet, Styled};
@@ -0,0 +1,82 @@
+use super::Styled;
+
+/// Multi-line content displayed in the preview pane of [Select](crate::Select)
+/// and [`MultiSelect`](crate::MultiSelect) prompts, describing the option
+/// currently highlighted.
+///
+/// Each line is composed of a sequence of [`Styled`] spans, allowing parts of
+/// the preview to be colored or emphasized.
+///
+/// # Example
+///
+/// ```
+/// use inquire::ui::{Color, Preview, Styled};
+///
+/// let preview = Preview::from("Author: Jane\nDate: 2021-10-01")
+///     .with_line(vec![
+///         Styled::new(String::from("+ added line")).with_fg(Color::DarkGreen),
+///     ]);
+///
+/// assert_eq!(3, preview.lines().len());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Preview {
+    lines: Vec<Vec<Styled<String>>>,
+}
+
+impl Preview {
+    /// Creates an empty preview.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a line composed of the given styled spans.
+    pub fn with_line<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator<Item = Styled<String>>,
+    {
+        self.lines.push(spans.into_iter().collect());
+        self
+    }
+
+    /// Appends each line of the given text, with no style applied.
+    pub fn with_text(mut self, text: &str) -> Self {
+        for line in text.lines() {
+            self.lines.push(vec![Styled::new(line.to_string())]);
+        }
+        self
+    }
+
+    /// Lines of the preview, each one composed of styled spans.
+    pub fn lines(&self) -> &[Vec<Styled<String>>] {
+        &self.lines
+    }
+}
+
+impl From<&str> for Preview {
+    fn from(text: &str) -> Self {
+        Self::new().with_text(text)
+    }
+}
+
+impl From<String> for Preview {
+    fn from(text: String) -> Self {
+        Self::new().with_text(&text)
+    }
+}
+
+/// Position of the preview pane in relation to the option list, or of the
+/// live preview of [CustomType](crate::CustomType) prompts in relation to
+/// the input.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PreviewPosition {
+    /// The preview is rendered below the option list, or on the line below
+    /// the input.
+    Below,
+
+    /// The preview is rendered beside the option list, taking the right half
+    /// of the terminal. Lines too long for the available width are truncated.
+    ///
+    /// Live previews are rendered on the same line, after the input.
+    Beside,
+}
@@ -1,6 +1,6 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -97,6 +97,13 @@ pub struct RenderConfig {
     /// and the option value or the checkbox.
     pub scroll_up_prefix: Styled<&'static str>,
 
+    /// Prefix for the highlighted option while it is being moved by the user,
+    /// replacing the highlighted option prefix, in Reorder prompts.
+    ///
+    /// Note: a space character will be added to separate the prefix
+    /// and the option value.
+    pub moving_option_prefix: Styled<&'static str>,
+
     /// Prefix for the option listed at the bottom of the page, when it is possible
     /// to scroll down.
     ///
@@ -109,6 +116,10 @@ pub struct RenderConfig {
     /// Note: a space character will be added to separate the checkbox
     /// from a possible prefix, and to separate the checkbox from the
     /// option value to the right.
+    ///
+    /// When MultiSelect prompts record the selection order, the checkbox is
+    /// replaced by the position of the option in the selection, e.g. [1],
+    /// rendered with the style of this checkbox.
     pub selected_checkbox: Styled<&'static str>,
 
     /// Unselected checkbox in multi-select options.
@@ -118,6 +129,14 @@ pub struct RenderConfig {
     /// option value to the right.
     pub unselected_checkbox: Styled<&'static str>,
 
+    /// Checkbox of tree nodes in multi-select prompts where some, but not
+    /// all, of the nodes below them are selected.
+    ///
+    /// Note: a space character will be added to separate the checkbox
+    /// from a possible prefix, and to separate the checkbox from the
+    /// option value to the right.
+    pub indeterminate_checkbox: Styled<&'static str>,
+
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
@@ -127,6 +146,89 @@ pub struct RenderConfig {
     /// a separator from the prefix.
     pub option: StyleSheet,
 
+    /// Style sheet for disabled options, applied to both the option value
+    /// and the reason why it is disabled.
+    ///
+    /// Note: non-empty reasons are displayed wrapped in parenthesis, e.g. (deprecated),
+    /// with a non-styled space character added before them as a separator.
+    pub disabled_option: StyleSheet,
+
+    /// Style sheet of the row that creates a new value from the filter input,
+    /// displayed by Select prompts that accept new values.
+    ///
+    /// Note: the row is formatted as `+ create '{}'` with the filter input.
+    pub create_option: StyleSheet,
+
+    /// Style sheet of the hotkey highlighted inside options.
+    ///
+    /// Note: hotkeys not contained in the option are displayed after it, wrapped
+    /// in parenthesis, e.g. (x), with a non-styled space character added before
+    /// them as a separator.
+    pub option_hotkey: StyleSheet,
+
+    /// Style sheet of the option being moved by the user in Reorder prompts.
+    pub moving_option: StyleSheet,
+
+    /// Style sheet for the titles of option group headers.
+    ///
+    /// Note: a non-styled space character is added before the title as
+    /// a separator from the prefix.
+    pub option_group_header: StyleSheet,
+
+    /// Separator row displayed before option groups that have no title.
+    ///
+    /// Note: a non-styled space character is added before the separator as
+    /// a separator from the prefix.
+    pub option_group_separator: Styled<&'static str>,
+
+    /// Style sheet of the indicator of how many options were loaded so far,
+    /// displayed below option lists while their source is not exhausted.
+    pub loaded_indicator: StyleSheet,
+
+    /// Style sheet of the counter of selected options, displayed after the
+    /// prompt message of MultiSelect prompts that limit how many options can
+    /// be selected.
+    ///
+    /// Note: the counter is displayed wrapped in brackets, e.g. [3/5 selected],
+    /// with a non-styled space character added before it as a separator.
+    pub selection_counter: StyleSheet,
+
+    /// Style sheet of the choice currently selected in the "Yes / No" switch
+    /// of Confirm prompts in toggle mode.
+    pub active_choice: StyleSheet,
+
+    /// Style sheet of the choice not selected in the "Yes / No" switch of
+    /// Confirm prompts in toggle mode.
+    pub inactive_choice: StyleSheet,
+
+    /// Border displayed at the left of each line of the preview pane.
+    ///
+    /// Note: a non-styled space character is added after the border as
+    /// a separator from the preview content.
+    pub preview_border: Styled<&'static str>,
+
+    /// Style sheet of the live preview of the value parsed from the input of
+    /// CustomType prompts.
+    ///
+    /// Note: the preview is formatted as `→ {}` with the formatted value.
+    pub value_preview: StyleSheet,
+
+    /// Hint displayed in place of the live preview of CustomType prompts when
+    /// the input can not be parsed.
+    ///
+    /// Note: the hint is displayed after an arrow styled by `value_preview`,
+    /// e.g. `→ invalid`.
+    pub invalid_value_preview: Styled<&'static str>,
+
+    /// Render configuration for tree prompts.
+    pub tree: tree::TreeRenderConfig,
+
+    /// Render configuration for the slider bar of number prompts.
+    pub slider: slider::SliderRenderConfig,
+
+    /// Render configuration for the counts of QuantitySelect prompts.
+    pub quantity: quantity::QuantityRenderConfig,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -158,11 +260,29 @@ impl RenderConfig {
             password_mask: '*',
             highlighted_option_prefix: Styled::new(">"),
             scroll_up_prefix: Styled::new("^"),
+            moving_option_prefix: Styled::new("↕"),
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
+            indeterminate_checkbox: Styled::new("[-]"),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
+            disabled_option: StyleSheet::empty(),
+            create_option: StyleSheet::empty(),
+            option_hotkey: StyleSheet::empty(),
+            moving_option: StyleSheet::empty(),
+            option_group_header: StyleSheet::empty(),
+            option_group_separator: Styled::new("──────────"),
+            loaded_indicator: StyleSheet::empty(),
+            selection_counter: StyleSheet::empty(),
+            active_choice: StyleSheet::empty(),
+            inactive_choice: StyleSheet::empty(),
+            preview_border: Styled::new("│"),
+            value_preview: StyleSheet::empty(),
+            invalid_value_preview: Styled::new("invalid"),
+            tree: tree::TreeRenderConfig::empty(),
+            slider: slider::SliderRenderConfig::empty(),
+            quantity: quantity::QuantityRenderConfig::empty(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -188,11 +308,37 @@ impl RenderConfig {
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
             highlighted_option_prefix: Styled::new(">").with_fg(Color::LightCyan),
             scroll_up_prefix: Styled::new("^"),
+            moving_option_prefix: Styled::new("↕").with_fg(Color::LightYellow),
             scroll_down_prefix: Styled::new("v"),
             selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
             unselected_checkbox: Styled::new("[ ]"),
+            indeterminate_checkbox: Styled::new("[-]").with_fg(Color::LightYellow),
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
+            disabled_option: StyleSheet::new().with_fg(Color::DarkGrey),
+            create_option: StyleSheet::new().with_fg(Color::LightGreen),
+            option_hotkey: StyleSheet::new()
+                .with_fg(Color::LightYellow)
+                .with_attr(Attributes::BOLD),
+            moving_option: StyleSheet::new()
+                .with_fg(Color::LightYellow)
+                .with_attr(Attributes::BOLD),
+            option_group_header: StyleSheet::empty().with_attr(Attributes::BOLD),
+            option_group_separator: Styled::new("──────────").with_fg(Color::DarkGrey),
+            loaded_indicator: StyleSheet::new().with_fg(Color::DarkGrey),
+            selection_counter: StyleSheet::new().with_fg(Color::DarkGrey),
+            active_choice: StyleSheet::new()
+                .with_fg(Color::LightCyan)
+                .with_attr(Attributes::BOLD),
+            inactive_choice: StyleSheet::new().with_fg(Color::DarkGrey),
+            preview_border: Styled::new("│").with_fg(Color::DarkGrey),
+            value_preview: StyleSheet::new().with_fg(Color::DarkCyan),
+            invalid_value_preview: Styled::new("invalid")
+                .with_fg(Color::DarkGrey)
+                .with_attr(Attributes::ITALIC),
+            tree: tree::TreeRenderConfig::default_colored(),
+            slider: slider::SliderRenderConfig::default_colored(),
+            quantity: quantity::QuantityRenderConfig::default_colored(),
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -253,6 +399,12 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the styled component for prefixes of options being moved.
+    pub fn with_moving_option_prefix(mut self, moving_option_prefix: Styled<&'static str>) -> Self {
+        self.moving_option_prefix = moving_option_prefix;
+        self
+    }
+
     /// Sets the styled component for prefixes in scroll-down indicators.
     pub fn with_scroll_down_prefix(mut self, scroll_down_prefix: Styled<&'static str>) -> Self {
         self.scroll_down_prefix = scroll_down_prefix;
@@ -271,6 +423,15 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the styled component for indeterminate checkboxes.
+    pub fn with_indeterminate_checkbox(
+        mut self,
+        indeterminate_checkbox: Styled<&'static str>,
+    ) -> Self {
+        self.indeterminate_checkbox = indeterminate_checkbox;
+        self
+    }
+
     /// Sets the index prefix for option lists.
     pub fn with_option_index_prefix(mut self, index_prefix: IndexPrefix) -> Self {
         self.option_index_prefix = index_prefix;
@@ -283,6 +444,91 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the style sheet for disabled options.
+    pub fn with_disabled_option(mut self, disabled_option: StyleSheet) -> Self {
+        self.disabled_option = disabled_option;
+        self
+    }
+
+    /// Sets the style sheet of the row that creates a new value.
+    pub fn with_create_option(mut self, create_option: StyleSheet) -> Self {
+        self.create_option = create_option;
+        self
+    }
+
+    /// Sets the style sheet of the hotkeys highlighted inside options.
+    pub fn with_option_hotkey(mut self, option_hotkey: StyleSheet) -> Self {
+        self.option_hotkey = option_hotkey;
+        self
+    }
+
+    /// Sets the style sheet of the option being moved.
+    pub fn with_moving_option(mut self, moving_option: StyleSheet) -> Self {
+        self.moving_option = moving_option;
+        self
+    }
+
+    /// Sets the style sheet for option group headers.
+    pub fn with_option_group_header(mut self, option_group_header: StyleSheet) -> Self {
+        self.option_group_header = option_group_header;
+        self
+    }
+
+    /// Sets the styled component for option group separators.
+    pub fn with_option_group_separator(
+        mut self,
+        option_group_separator: Styled<&'static str>,
+    ) -> Self {
+        self.option_group_separator = option_group_separator;
+        self
+    }
+
+    /// Sets the style sheet of the loaded options indicator.
+    pub fn with_loaded_indicator(mut self, loaded_indicator: StyleSheet) -> Self {
+        self.loaded_indicator = loaded_indicator;
+        self
+    }
+
+    /// Sets the style sheet of the counter of selected options.
+    pub fn with_selection_counter(mut self, selection_counter: StyleSheet) -> Self {
+        self.selection_counter = selection_counter;
+        self
+    }
+
+    /// Sets the style sheet of the selected choice of Confirm prompts in toggle mode.
+    pub fn with_active_choice(mut self, active_choice: StyleSheet) -> Self {
+        self.active_choice = active_choice;
+        self
+    }
+
+    /// Sets the style sheet of the unselected choice of Confirm prompts in toggle mode.
+    pub fn with_inactive_choice(mut self, inactive_choice: StyleSheet) -> Self {
+        self.inactive_choice = inactive_choice;
+        self
+    }
+
+    /// Sets the border of the preview pane.
+    pub fn with_preview_border(mut self, preview_border: Styled<&'static str>) -> Self {
+        self.preview_border = preview_border;
+        self
+    }
+
+    /// Sets the style sheet of the live preview of CustomType prompts.
+    pub fn with_value_preview(mut self, value_preview: StyleSheet) -> Self {
+        self.value_preview = value_preview;
+        self
+    }
+
+    /// Sets the hint displayed when the input of CustomType prompts with a
+    /// live preview can not be parsed.
+    pub fn with_invalid_value_preview(
+        mut self,
+        invalid_value_preview: Styled<&'static str>,
+    ) -> Self {
+        self.invalid_value_preview = invalid_value_preview;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -292,6 +538,24 @@ impl RenderConfig {
         self
     }
 
+    /// Sets the render configuration for tree prompts.
+    pub fn with_tree_config(mut self, tree: tree::TreeRenderConfig) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Sets the render configuration for the slider bar of number prompts.
+    pub fn with_slider_config(mut self, slider: slider::SliderRenderConfig) -> Self {
+        self.slider = slider;
+        self
+    }
+
+    /// Sets the render configuration for the counts of QuantitySelect prompts.
+    pub fn with_quantity_config(mut self, quantity: quantity::QuantityRenderConfig) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
     #[cfg(feature = "date")]
     /// Sets the render configuration for calendars.
     pub fn with_calendar_config(mut self, calendar: calendar::CalendarRenderConfig) -> Self {
@@ -416,6 +680,97 @@ impl ErrorMessageRenderConfig {
     }
 }
 
+pub mod tree {
+    //! Module containing additional render config for tree prompts.
+
+    use super::{Color, Styled};
+
+    /// Render configuration for the indentation guides and expand markers
+    /// of tree prompts.
+    ///
+    /// A node is rendered as its guides, its marker and its value, e.g.
+    /// `│  └─ ▸ platform`.
+    #[derive(Copy, Clone, Debug)]
+    pub struct TreeRenderConfig {
+        /// Guide displayed before nodes followed by other siblings.
+        pub branch_guide: Styled<&'static str>,
+
+        /// Guide displayed before the last node among its siblings.
+        pub last_branch_guide: Styled<&'static str>,
+
+        /// Guide displayed below nodes followed by other siblings, crossing
+        /// the rows of their descendants.
+        ///
+        /// Note: below nodes with no following siblings, spaces with the same
+        /// width are displayed instead.
+        pub vertical_guide: Styled<&'static str>,
+
+        /// Marker displayed before expanded nodes.
+        pub expanded_marker: Styled<&'static str>,
+
+        /// Marker displayed before collapsed nodes.
+        ///
+        /// Note: leaves are displayed with spaces with the same width instead,
+        /// followed by a non-styled space character as a separator from the
+        /// value, as are markers.
+        pub collapsed_marker: Styled<&'static str>,
+    }
+
+    impl TreeRenderConfig {
+        /// Render configuration in which no colors or attributes are applied.
+        pub fn empty() -> Self {
+            Self {
+                branch_guide: Styled::new("├─ "),
+                last_branch_guide: Styled::new("└─ "),
+                vertical_guide: Styled::new("│  "),
+                expanded_marker: Styled::new("▾"),
+                collapsed_marker: Styled::new("▸"),
+            }
+        }
+
+        /// Render configuration where default colors and attributes are applied.
+        pub fn default_colored() -> Self {
+            Self {
+                branch_guide: Styled::new("├─ ").with_fg(Color::DarkGrey),
+                last_branch_guide: Styled::new("└─ ").with_fg(Color::DarkGrey),
+                vertical_guide: Styled::new("│  ").with_fg(Color::DarkGrey),
+                expanded_marker: Styled::new("▾").with_fg(Color::LightCyan),
+                collapsed_marker: Styled::new("▸").with_fg(Color::LightCyan),
+            }
+        }
+
+        /// Sets the guides displayed before nodes, the latter being used
+        /// for the last node among its siblings.
+        pub fn with_branch_guides(
+            mut self,
+            branch_guide: Styled<&'static str>,
+            last_branch_guide: Styled<&'static str>,
+        ) -> Self {
+            self.branch_guide = branch_guide;
+            self.last_branch_guide = last_branch_guide;
+            self
+        }
+
+        /// Sets the vertical guide crossing the descendants of nodes
+        /// followed by other siblings.
+        pub fn with_vertical_guide(mut self, vertical_guide: Styled<&'static str>) -> Self {
+            self.vertical_guide = vertical_guide;
+            self
+        }
+
+        /// Sets the markers displayed before expanded and collapsed nodes.
+        pub fn with_markers(
+            mut self,
+            expanded_marker: Styled<&'static str>,
+            collapsed_marker: Styled<&'static str>,
+        ) -> Self {
+            self.expanded_marker = expanded_marker;
+            self.collapsed_marker = collapsed_marker;
+            self
+        }
+    }
+}
+
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
@@ -494,3 +849,141 @@ pub mod calendar {
         }
     }
 }
+
+pub mod slider {
+    //! Module containing additional render config for number prompts.
+
+    use super::{Color, StyleSheet, Styled};
+
+    /// Render configuration for the slider bar of number prompts.
+    ///
+    /// The slider is rendered as its bounds around a bar, e.g.
+    /// `0 ━━━━●────── 100`.
+    #[derive(Copy, Clone, Debug)]
+    pub struct SliderRenderConfig {
+        /// Segment displayed for each column of the bar before the handle.
+        pub filled: Styled<&'static str>,
+
+        /// Segment displayed for each column of the bar after the handle.
+        pub empty: Styled<&'static str>,
+
+        /// Handle displayed at the position of the current value.
+        pub handle: Styled<&'static str>,
+
+        /// Style sheet of the minimum and maximum values displayed around the bar.
+        pub bounds: StyleSheet,
+    }
+
+    impl SliderRenderConfig {
+        /// Render configuration in which no colors or attributes are applied.
+        pub fn empty() -> Self {
+            Self {
+                filled: Styled::new("━"),
+                empty: Styled::new("─"),
+                handle: Styled::new("●"),
+                bounds: StyleSheet::empty(),
+            }
+        }
+
+        /// Render configuration where default colors and attributes are applied.
+        pub fn default_colored() -> Self {
+            Self {
+                filled: Styled::new("━").with_fg(Color::LightCyan),
+                empty: Styled::new("─").with_fg(Color::DarkGrey),
+                handle: Styled::new("●").with_fg(Color::LightCyan),
+                bounds: StyleSheet::new().with_fg(Color::DarkGrey),
+            }
+        }
+
+        /// Sets the segments displayed before and after the handle.
+        pub fn with_segments(
+            mut self,
+            filled: Styled<&'static str>,
+            empty: Styled<&'static str>,
+        ) -> Self {
+            self.filled = filled;
+            self.empty = empty;
+            self
+        }
+
+        /// Sets the handle displayed at the position of the current value.
+        pub fn with_handle(mut self, handle: Styled<&'static str>) -> Self {
+            self.handle = handle;
+            self
+        }
+
+        /// Sets the style sheet of the minimum and maximum values.
+        pub fn with_bounds(mut self, bounds: StyleSheet) -> Self {
+            self.bounds = bounds;
+            self
+        }
+    }
+}
+
+pub mod quantity {
+    //! Module containing additional render config for quantity prompts.
+
+    use super::{Color, StyleSheet, Styled};
+
+    /// Render configuration for the counts of QuantitySelect prompts.
+    ///
+    /// A count is rendered between its delimiters, padded with spaces to the
+    /// width of the largest count, e.g. `[ 2]`.
+    #[derive(Copy, Clone, Debug)]
+    pub struct QuantityRenderConfig {
+        /// Delimiter displayed before counts.
+        pub opening: Styled<&'static str>,
+
+        /// Delimiter displayed after counts.
+        ///
+        /// Note: a space character will be added to separate the delimiter
+        /// from the option value.
+        pub closing: Styled<&'static str>,
+
+        /// Style sheet of counts greater than zero.
+        pub count: StyleSheet,
+
+        /// Style sheet of counts equal to zero, whose options are not selected.
+        pub zero_count: StyleSheet,
+    }
+
+    impl QuantityRenderConfig {
+        /// Render configuration in which no colors or attributes are applied.
+        pub fn empty() -> Self {
+            Self {
+                opening: Styled::new("["),
+                closing: Styled::new("]"),
+                count: StyleSheet::empty(),
+                zero_count: StyleSheet::empty(),
+            }
+        }
+
+        /// Render configuration where default colors and attributes are applied.
+        pub fn default_colored() -> Self {
+            Self {
+                opening: Styled::new("["),
+                closing: Styled::new("]"),
+                count: StyleSheet::new().with_fg(Color::LightGreen),
+                zero_count: StyleSheet::empty(),
+            }
+        }
+
+        /// Sets the delimiters displayed before and after counts.
+        pub fn with_delimiters(
+            mut self,
+            opening: Styled<&'static str>,
+            closing: Styled<&'static str>,
+        ) -> Self {
+            self.opening = opening;
+            self.closing = closing;
+            self
+        }
+
+        /// Sets the style sheets of counts greater than zero and equal to zero.
+        pub fn with_counts(mut self, count: StyleSheet, zero_count: StyleSheet) -> Self {
+            self.count = count;
+            self.zero_count = zero_count;
+            self
+        }
+    }
+}
@@ -1,5 +1,15 @@
 // sorry for this file
 
+use std::{
+    collections::{BTreeSet, HashMap},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    list_option::{ListOption, OptionGroup},
+    ui::{Preview, PreviewPosition, Styled},
+};
+
 pub struct Page<'a, T> {
     pub first: bool,
     pub last: bool,
@@ -8,9 +18,312 @@ pub struct Page<'a, T> {
     pub total: usize,
 }
 
-pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<T> {
-    let (start, end, cursor) = if choices.len() <= page_size {
-        (0, choices.len(), sel)
+/// Lines of the preview pane visible at once, after scrolling.
+pub struct PreviewPage<'a> {
+    pub lines: &'a [Vec<Styled<String>>],
+    pub first: bool,
+    pub last: bool,
+    pub position: PreviewPosition,
+}
+
+/// Returns the lines of the preview visible within the given height, starting
+/// at the given scroll offset, which is clamped so that the page is never
+/// partially empty.
+pub fn paginate_preview(
+    preview: &Preview,
+    height: usize,
+    offset: usize,
+    position: PreviewPosition,
+) -> PreviewPage<'_> {
+    let lines = preview.lines();
+    let start = offset.min(lines.len().saturating_sub(height));
+    let end = start.saturating_add(height).min(lines.len());
+
+    PreviewPage {
+        lines: &lines[start..end],
+        first: start == 0,
+        last: end == lines.len(),
+        position,
+    }
+}
+
+/// Row displayed in option lists of Select and MultiSelect prompts.
+pub enum OptionRow<'a, D> {
+    Header {
+        title: &'a str,
+        checked: bool,
+    },
+    Separator,
+
+    /// Option that can be highlighted, along with the index displayed in its
+    /// prefix when enabled by the render config and its hotkey, if any.
+    Option {
+        option: ListOption<D>,
+        prefix_index: usize,
+        hotkey: Option<char>,
+    },
+    Disabled {
+        option: ListOption<D>,
+        prefix_index: usize,
+        reason: &'a str,
+    },
+    Create {
+        input: &'a str,
+    },
+}
+
+/// Index typed by the user to jump to an option, accumulating the digits typed
+/// in quick succession.
+#[derive(Default)]
+pub struct IndexJump {
+    typed: Option<(usize, Instant)>,
+}
+
+impl IndexJump {
+    /// Maximum interval between two digits of the same index.
+    pub const TIMEOUT: Duration = Duration::from_millis(1000);
+
+    /// Appends the digit to the index being typed or, when the previous digit
+    /// is too old or the result is out of range, starts a new one. Complete
+    /// indexes are not kept, as no digit can be appended to them.
+    ///
+    /// Returns the typed 1-based index, unless it is out of range.
+    pub fn push(&mut self, digit: u32, len: usize, now: Instant) -> Option<usize> {
+        let digit = digit as usize;
+        let index = match self.typed {
+            Some((typed, at))
+                if now.duration_since(at) < Self::TIMEOUT
+                    && typed.saturating_mul(10).saturating_add(digit) <= len =>
+            {
+                typed * 10 + digit
+            }
+            _ => digit,
+        };
+
+        if index == 0 || index > len {
+            self.typed = None;
+            return None;
+        }
+
+        self.typed = match Self::is_complete(index, len) {
+            true => None,
+            false => Some((index, now)),
+        };
+        Some(index)
+    }
+
+    /// Instant after which no more digits can be appended to the index being
+    /// typed, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.typed.map(|(_, at)| at + Self::TIMEOUT)
+    }
+
+    /// Returns the index being typed once no more digits can be appended to
+    /// it, clearing it.
+    pub fn expire(&mut self, now: Instant) -> Option<usize> {
+        match self.typed {
+            Some((index, at)) if now.duration_since(at) >= Self::TIMEOUT => {
+                self.typed = None;
+                Some(index)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether no other index in range starts with the given one, meaning
+    /// that typing more digits can not change it.
+    pub fn is_complete(index: usize, len: usize) -> bool {
+        index.saturating_mul(10) > len
+    }
+
+    pub fn reset(&mut self) {
+        self.typed = None;
+    }
+}
+
+/// Options checked in prompts that allow multiple selections, optionally
+/// recording the order in which they were picked.
+pub struct Selection {
+    checked: BTreeSet<usize>,
+
+    /// Position, starting from 1, in which each checked option was picked,
+    /// when the order is recorded.
+    ordinals: Option<HashMap<usize, usize>>,
+}
+
+impl Selection {
+    pub fn new(record_order: bool) -> Self {
+        Self {
+            checked: BTreeSet::new(),
+            ordinals: match record_order {
+                true => Some(HashMap::new()),
+                false => None,
+            },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.checked.len()
+    }
+
+    pub fn contains(&self, idx: &usize) -> bool {
+        self.checked.contains(idx)
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &usize> {
+        self.checked.iter()
+    }
+
+    pub fn insert(&mut self, idx: usize) {
+        if self.checked.insert(idx) {
+            if let Some(ordinals) = &mut self.ordinals {
+                ordinals.insert(idx, ordinals.len() + 1);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, idx: &usize) {
+        if self.checked.remove(idx) {
+            if let Some(ordinals) = &mut self.ordinals {
+                if let Some(removed) = ordinals.remove(idx) {
+                    // options picked afterwards move up one position
+                    for ordinal in ordinals.values_mut().filter(|o| **o > removed) {
+                        *ordinal -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the position, starting from 1, in which the option was picked,
+    /// when the order is recorded and the option is checked.
+    pub fn ordinal(&self, idx: usize) -> Option<usize> {
+        self.ordinals
+            .as_ref()
+            .and_then(|ordinals| ordinals.get(&idx).copied())
+    }
+
+    /// Sorts the given options in the order they were picked, when it is
+    /// recorded, keeping them as they are otherwise.
+    pub fn sort_by_order<T>(&self, options: &mut [ListOption<T>]) {
+        if self.ordinals.is_some() {
+            options.sort_by_key(|option| self.ordinal(option.index));
+        }
+    }
+}
+
+impl Extend<usize> for Selection {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for idx in iter {
+            self.insert(idx);
+        }
+    }
+}
+
+/// Reference to a row displayed in option lists, pointing either to a group
+/// (header or separator) or to an option, by their indexes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowIndex {
+    Group(usize),
+    Option(usize),
+
+    /// Row creating a new value from the filter input, only displayed by
+    /// Select prompts that accept new values.
+    Create,
+}
+
+/// Builds the list of rows to be displayed, adding the groups' header or
+/// separator rows before their first visible option. Groups whose options
+/// are all filtered out are not displayed.
+///
+/// Groups must be sorted and must not overlap.
+pub fn group_rows(groups: &[OptionGroup], filtered_options: &[usize]) -> Vec<RowIndex> {
+    let mut rows = Vec::with_capacity(filtered_options.len() + groups.len());
+    append_group_rows(&mut rows, groups, filtered_options);
+
+    rows
+}
+
+/// Appends the rows of options filtered after the ones already in `rows`,
+/// just like [group_rows] would build them for all the options at once.
+///
+/// Groups must be sorted and must not overlap.
+pub fn append_group_rows(
+    rows: &mut Vec<RowIndex>,
+    groups: &[OptionGroup],
+    filtered_options: &[usize],
+) {
+    let group_of = |idx: usize| {
+        let candidate = groups.partition_point(|g| g.range.end <= idx);
+
+        groups
+            .get(candidate)
+            .filter(|g| g.range.contains(&idx))
+            .map(|_| candidate)
+    };
+
+    let mut current_group = rows
+        .iter()
+        .rev()
+        .find_map(|r| match r {
+            RowIndex::Option(idx) => Some(group_of(*idx)),
+            _ => None,
+        })
+        .flatten();
+
+    for &idx in filtered_options {
+        let group = group_of(idx);
+
+        if let Some(g) = group {
+            if current_group != group && (groups[g].title.is_some() || !rows.is_empty()) {
+                rows.push(RowIndex::Group(g));
+            }
+        }
+
+        current_group = group;
+        rows.push(RowIndex::Option(idx));
+    }
+}
+
+/// Folds the case of a hotkey, as hotkeys are matched ignoring case so that
+/// they keep working with caps lock on.
+pub fn fold_hotkey(key: char) -> char {
+    let mut lower = key.to_lowercase();
+
+    match (lower.next(), lower.next()) {
+        (Some(c), None) => c,
+        _ => key,
+    }
+}
+
+/// Whether the two keys are the same hotkey, ignoring case.
+pub fn same_hotkey(a: char, b: char) -> bool {
+    fold_hotkey(a) == fold_hotkey(b)
+}
+
+/// Whether the option is enabled, according to the reasons why options are
+/// disabled, which are only computed when a disabler is set.
+pub fn is_enabled(disabled: &[Option<String>], idx: usize) -> bool {
+    !matches!(disabled.get(idx), Some(Some(_)))
+}
+
+pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<'_, T> {
+    let (start, end, cursor) = page_bounds(page_size, choices.len(), sel);
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: &choices[start..end],
+        selection: cursor,
+        total: choices.len(),
+    }
+}
+
+/// Returns the range of the page of a list with the given length, along with
+/// the position of the selection in the page.
+fn page_bounds(page_size: usize, len: usize, sel: usize) -> (usize, usize, usize) {
+    if len <= page_size {
+        (0, len, sel)
     } else if sel < page_size / 2 {
         // if we are in the first half page
         let start = 0;
@@ -18,10 +331,10 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<T> {
         let cursor = sel;
 
         (start, end, cursor)
-    } else if choices.len() - sel - 1 < page_size / 2 {
+    } else if len - sel - 1 < page_size / 2 {
         // if we are in the last half page
-        let start = choices.len() - page_size;
-        let end = choices.len();
+        let start = len - page_size;
+        let end = len;
         let cursor = sel - start;
 
         (start, end, cursor)
@@ -35,17 +348,103 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: usize) -> Page<T> {
         let cursor = page_size / 2;
 
         (start, end, cursor)
-    };
+    }
+}
+
+/// Builds the page of a grid in which the given choices flow into the given
+/// number of columns, paginating by lines of the grid.
+pub fn paginate_grid<T>(
+    page_size: usize,
+    choices: &[T],
+    sel: usize,
+    columns: usize,
+) -> Page<'_, T> {
+    let columns = columns.max(1);
+    let lines = (choices.len() + columns - 1) / columns;
+    let (first_line, end_line, _) = page_bounds(page_size, lines, sel / columns);
+
+    let start = first_line.saturating_mul(columns);
+    let end = end_line.saturating_mul(columns).min(choices.len());
 
     Page {
-        first: start == 0,
-        last: end == choices.len(),
+        first: first_line == 0,
+        last: end_line == lines,
         content: &choices[start..end],
-        selection: cursor,
+        selection: sel.saturating_sub(start),
         total: choices.len(),
     }
 }
 
+/// Paginates the choices just like [paginate], but moves the page up when
+/// possible so that the closest header above the selection is still visible.
+pub fn paginate_with_headers<T, F>(
+    page_size: usize,
+    choices: &[T],
+    sel: usize,
+    is_header: F,
+) -> Page<'_, T>
+where
+    F: Fn(&T) -> bool,
+{
+    let page = paginate(page_size, choices, sel);
+    let start = sel.saturating_sub(page.selection);
+
+    let header = choices
+        .get(..=sel)
+        .and_then(|above| above.iter().rposition(&is_header));
+
+    match header {
+        Some(header) if header < start && sel - header < page_size => {
+            let end = header.saturating_add(page_size).min(choices.len());
+
+            Page {
+                first: header == 0,
+                last: end == choices.len(),
+                content: &choices[header..end],
+                selection: sel - header,
+                total: choices.len(),
+            }
+        }
+        _ => page,
+    }
+}
+
+/// Returns the index of the closest row to `from`, including itself, that is
+/// selectable, looking first in the direction of the movement.
+///
+/// When nothing is found in that direction, the search wraps around the list
+/// if `wrap` is set, otherwise it goes back in the opposite direction.
+pub fn closest_selectable<F>(
+    len: usize,
+    from: usize,
+    up: bool,
+    wrap: bool,
+    selectable: F,
+) -> Option<usize>
+where
+    F: Fn(usize) -> bool,
+{
+    if len == 0 {
+        return None;
+    }
+
+    let from = from.min(len - 1);
+
+    let primary: Box<dyn Iterator<Item = usize>> = match up {
+        true => Box::new((0..=from).rev()),
+        false => Box::new(from..len),
+    };
+
+    let secondary: Box<dyn Iterator<Item = usize>> = match (up, wrap) {
+        (true, true) => Box::new((from + 1..len).rev()),
+        (true, false) => Box::new(from + 1..len),
+        (false, true) => Box::new(0..from),
+        (false, false) => Box::new((0..from).rev()),
+    };
+
+    primary.chain(secondary).find(|i| selectable(*i))
+}
+
 pub fn int_log10<T>(mut i: T) -> usize
 where
     T: std::ops::DivAssign + std::cmp::PartialOrd + From<u8> + Copy,
@@ -67,9 +466,14 @@ mod test {
     #![allow(clippy::bool_assert_comparison)]
 
     use crate::{
-        list_option::ListOption,
-        utils::{int_log10, paginate},
+        list_option::{ListOption, OptionGroup},
+        ui::{Preview, PreviewPosition},
+        utils::{
+            append_group_rows, closest_selectable, group_rows, int_log10, paginate, paginate_grid,
+            paginate_preview, paginate_with_headers, IndexJump, RowIndex, Selection,
+        },
     };
+    use std::time::{Duration, Instant};
 
     #[test]
     fn int_log10_works() {
@@ -153,4 +557,194 @@ mod test {
         assert_eq!(true, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn paginate_with_headers_keeps_header_visible() {
+        let choices = vec!["h1", "1", "2", "h2", "3", "4", "5", "6"];
+        let is_header = |c: &&str| c.starts_with('h');
+
+        let page = paginate_with_headers(4, &choices, 6, is_header);
+
+        assert_eq!(choices[3..7], page.content[..]);
+        assert_eq!(3usize, page.selection);
+        assert_eq!(false, page.first);
+        assert_eq!(false, page.last);
+    }
+
+    #[test]
+    fn paginate_with_headers_prefers_selection_over_header() {
+        let choices = vec!["h1", "1", "2", "3", "4", "5", "6", "7"];
+        let is_header = |c: &&str| c.starts_with('h');
+
+        let page = paginate_with_headers(3, &choices, 5, is_header);
+
+        assert_eq!(choices[4..7], page.content[..]);
+        assert_eq!(1usize, page.selection);
+    }
+
+    #[test]
+    fn closest_selectable_skips_rows() {
+        let selectable = [false, true, true, false, true, false];
+        let f = |i: usize| selectable[i];
+
+        assert_eq!(Some(1), closest_selectable(6, 0, false, true, f));
+        assert_eq!(Some(4), closest_selectable(6, 3, false, true, f));
+        assert_eq!(Some(2), closest_selectable(6, 3, true, true, f));
+        assert_eq!(Some(4), closest_selectable(6, 0, true, true, f));
+        assert_eq!(Some(1), closest_selectable(6, 0, true, false, f));
+        assert_eq!(Some(1), closest_selectable(6, 5, false, true, f));
+        assert_eq!(Some(4), closest_selectable(6, 5, false, false, f));
+        assert_eq!(None, closest_selectable(6, 0, false, true, |_| false));
+        assert_eq!(None, closest_selectable(0, 0, false, true, f));
+    }
+
+    #[test]
+    fn appended_group_rows_continue_the_last_group() {
+        let groups = vec![OptionGroup::new("a", 0..3), OptionGroup::separator(3..5)];
+
+        let mut rows = group_rows(&groups, &[0, 1]);
+        append_group_rows(&mut rows, &groups, &[2, 3]);
+        append_group_rows(&mut rows, &groups, &[4]);
+
+        assert_eq!(group_rows(&groups, &[0, 1, 2, 3, 4]), rows);
+    }
+
+    #[test]
+    fn group_rows_hides_empty_groups() {
+        let groups = vec![
+            OptionGroup::new("a", 0..2),
+            OptionGroup::new("b", 2..4),
+            OptionGroup::separator(4..6),
+        ];
+
+        let rows = group_rows(&groups, &[0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            vec![
+                RowIndex::Group(0),
+                RowIndex::Option(0),
+                RowIndex::Option(1),
+                RowIndex::Group(1),
+                RowIndex::Option(2),
+                RowIndex::Option(3),
+                RowIndex::Group(2),
+                RowIndex::Option(4),
+                RowIndex::Option(5),
+                RowIndex::Option(6),
+            ],
+            rows
+        );
+
+        let rows = group_rows(&groups, &[3, 6]);
+        assert_eq!(
+            vec![RowIndex::Group(1), RowIndex::Option(3), RowIndex::Option(6)],
+            rows
+        );
+
+        let rows = group_rows(&groups, &[5]);
+        assert_eq!(vec![RowIndex::Option(5)], rows);
+    }
+
+    #[test]
+    fn paginate_preview_clamps_offset() {
+        let preview = Preview::from("1\n2\n3\n4\n5");
+
+        let page = paginate_preview(&preview, 2, 1, PreviewPosition::Below);
+        assert_eq!(2, page.lines.len());
+        assert_eq!("2", page.lines[0][0].content);
+        assert_eq!(false, page.first);
+        assert_eq!(false, page.last);
+
+        let page = paginate_preview(&preview, 2, 10, PreviewPosition::Below);
+        assert_eq!("4", page.lines[0][0].content);
+        assert_eq!(true, page.last);
+
+        let page = paginate_preview(&preview, 10, 3, PreviewPosition::Below);
+        assert_eq!(5, page.lines.len());
+        assert_eq!(true, page.first);
+        assert_eq!(true, page.last);
+    }
+
+    #[test]
+    fn index_jump_accumulates_digits_until_timeout() {
+        let mut jump = IndexJump::default();
+        let start = Instant::now();
+
+        assert_eq!(Some(1), jump.push(1, 25, start));
+        assert_eq!(
+            Some(12),
+            jump.push(2, 25, start + Duration::from_millis(300))
+        );
+        assert_eq!(
+            Some(3),
+            jump.push(3, 25, start + Duration::from_millis(600))
+        );
+        assert_eq!(Some(2), jump.push(2, 25, start + Duration::from_secs(5)));
+        assert_eq!(None, jump.push(0, 25, start + Duration::from_secs(10)));
+
+        assert!(!IndexJump::is_complete(2, 25));
+        assert!(IndexJump::is_complete(3, 25));
+    }
+
+    #[test]
+    fn index_jump_expires_ambiguous_indexes() {
+        let mut jump = IndexJump::default();
+        let start = Instant::now();
+
+        assert_eq!(Some(1), jump.push(1, 25, start));
+        assert_eq!(Some(start + IndexJump::TIMEOUT), jump.deadline());
+        assert_eq!(None, jump.expire(start + Duration::from_millis(300)));
+        assert_eq!(Some(1), jump.expire(start + IndexJump::TIMEOUT));
+        assert_eq!(None, jump.expire(start + Duration::from_secs(5)));
+
+        assert_eq!(Some(7), jump.push(7, 25, start));
+        assert_eq!(None, jump.deadline());
+        assert_eq!(None, jump.expire(start + Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn paginate_grid_by_lines() {
+        let choices: Vec<usize> = (0..10).collect();
+
+        let page = paginate_grid(2, &choices, 7, 3);
+
+        assert_eq!(choices[3..9], page.content[..]);
+        assert_eq!(4, page.selection);
+        assert_eq!(false, page.first);
+        assert_eq!(false, page.last);
+        assert_eq!(10, page.total);
+
+        let page = paginate_grid(2, &choices, 9, 3);
+
+        assert_eq!(choices[6..10], page.content[..]);
+        assert_eq!(3, page.selection);
+        assert_eq!(true, page.last);
+    }
+
+    #[test]
+    fn selection_records_pick_order() {
+        let mut selection = Selection::new(true);
+
+        selection.extend(vec![4, 1, 7]);
+        selection.remove(&1);
+        selection.insert(1);
+        selection.insert(4);
+
+        assert_eq!(Some(1), selection.ordinal(4));
+        assert_eq!(Some(2), selection.ordinal(7));
+        assert_eq!(Some(3), selection.ordinal(1));
+        assert_eq!(None, selection.ordinal(0));
+
+        let mut options = vec![
+            ListOption::new(1, "b"),
+            ListOption::new(4, "e"),
+            ListOption::new(7, "h"),
+        ];
+        selection.sort_by_order(&mut options);
+
+        assert_eq!(
+            vec![4, 7, 1],
+            options.iter().map(|o| o.index).collect::<Vec<_>>()
+        );
+        assert_eq!(None, Selection::new(false).ordinal(4));
+    }
 }
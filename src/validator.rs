@@ -158,10 +158,41 @@ pub type DateValidator<'a> = &'a dyn Fn(chrono::NaiveDate) -> Result<Validation,
 pub type MultiOptionValidator<'a, T> =
     &'a dyn Fn(&[ListOption<&T>]) -> Result<Validation, CustomUserError>;
 
+/// Type alias for validators used in [`CustomType`](crate::CustomType) and
+/// [`Confirm`](crate::Confirm) prompts, receiving the value parsed from the
+/// user input.
+///
+/// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
+///
+/// If the input is not valid, your validator should return `Ok(Validation::Invalid(ErrorMessage))`,
+/// where the content of `ErrorMessage` is recommended to be a string whose content will be displayed
+/// to the user as an error message. It is also recommended that this value gives a helpful feedback to the user.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{CustomTypeValidator, Validation};
+///
+/// let validator: CustomTypeValidator<u16> = &|port| match port {
+///     1024..=65535 => Ok(Validation::Valid),
+///     _ => Ok(Validation::Invalid(
+///         "The port must be between 1024 and 65535".into(),
+///     )),
+/// };
+///
+/// assert_eq!(Validation::Valid, validator(&8080)?);
+/// assert_eq!(
+///     Validation::Invalid("The port must be between 1024 and 65535".into()),
+///     validator(&80)?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+pub type CustomTypeValidator<'a, T> = &'a dyn Fn(&T) -> Result<Validation, CustomUserError>;
+
 /// Custom trait to call correct method to retrieve input length.
 ///
 /// The method can vary depending on the type of input.
-
+///
 /// String inputs should count the number of graphemes, via
 /// `.graphemes(true).count()`, instead of the number of bytes
 /// via `.len()`. While simple slices should keep using `.len()`